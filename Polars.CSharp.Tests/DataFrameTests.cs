@@ -667,4 +667,795 @@ public void Test_DataFrame_Explode_Eager()
     Assert.Equal("1,2", exploded.GetValue<string>(1, "nums"));
     Assert.Equal("3",   exploded.GetValue<string>(2, "nums"));
 }
-}
\ No newline at end of file
+
+    [Fact]
+    public void Test_Sort_MultiKey_Directions_And_Nulls()
+    {
+        using var g = new Series("g", ["b", "a", "b", "a", "a"]);
+        using var v = new Series("v", [1, 2, 3, 0, 5], [true, true, true, false, true]);
+        using var df = new DataFrame(g, v);
+
+        // g 升序, v 降序; null 默认排在最前
+        using var sorted = df.Sort([Col("g"), Col("v")], [false, true]);
+        Assert.Equal("a", sorted.GetValue<string>(0, "g"));
+        Assert.Null(sorted.GetValue<int?>(0, "v"));
+        Assert.Equal(5, sorted.GetValue<int>(1, "v"));
+        Assert.Equal(2, sorted.GetValue<int>(2, "v"));
+        Assert.Equal(3, sorted.GetValue<int>(3, "v"));
+        Assert.Equal(1, sorted.GetValue<int>(4, "v"));
+
+        // nullsLast
+        using var nullsLast = df.Sort([Col("g"), Col("v")], [false, true], nullsLast: true);
+        Assert.Equal(5, nullsLast.GetValue<int>(0, "v"));
+        Assert.Null(nullsLast.GetValue<int?>(2, "v"));
+
+        // 单个方向作用于所有列 (Lazy)
+        using var lazy = df.Lazy().Sort([Col("g"), Col("v")], [true], nullsLast: true).Collect();
+        Assert.Equal("b", lazy.GetValue<string>(0, "g"));
+        Assert.Equal(3, lazy.GetValue<int>(0, "v"));
+
+        // 方向数量和列数不符 -> 报错
+        Assert.ThrowsAny<Exception>(() => df.Sort([Col("g"), Col("v")], [true, false, true]));
+    }
+
+    [Fact]
+    public void Test_Join_Options_Suffix_Validate_Coalesce_Nulls_Order()
+    {
+        using var lk = new Series("k", [3, 1, 0, 2], [true, true, false, true]);
+        using var lv = new Series("v", ["c", "a", "n", "b"]);
+        using var left = new DataFrame(lk, lv);
+        using var rk = new Series("k2", [2, 1, 0, 3], [true, true, false, true]);
+        using var rv = new Series("v", ["B", "A", "N", "C"]);
+        using var right = new DataFrame(rk, rv);
+
+        // 1. 自定义 suffix + 保持左表顺序; null 键默认不匹配
+        using var res = left.Join(right, [Col("k")], [Col("k2")], JoinType.Inner,
+            suffix: "_r", maintainOrder: JoinMaintainOrder.Left);
+        Assert.Equal(3, res.Height);
+        Assert.Equal(new[] { "k", "v", "v_r" }, res.Columns);
+        Assert.Equal(3, res.GetValue<int>(0, "k"));
+        Assert.Equal("C", res.GetValue<string>(0, "v_r"));
+        Assert.Equal("B", res.GetValue<string>(2, "v_r"));
+
+        // 2. null 键相等 + 保留两侧键列
+        using var keep = left.Join(right, [Col("k")], [Col("k2")], JoinType.Inner,
+            coalesce: JoinCoalesce.KeepColumns, nullsEqual: true, maintainOrder: JoinMaintainOrder.Left);
+        Assert.Equal(4, keep.Height);
+        Assert.Contains("k2", keep.Columns);
+        Assert.Equal("N", keep.GetValue<string>(2, "v_right"));
+
+        // 3. 右侧键重复时 1:1 校验失败 (Lazy 同样支持)
+        using var dupKey = new Series("k2", [1, 1]);
+        using var dupVal = new Series("w", [1, 2]);
+        using var dup = new DataFrame(dupKey, dupVal);
+        Assert.ThrowsAny<Exception>(() =>
+            left.Join(dup, [Col("k")], [Col("k2")], validate: JoinValidation.OneToOne));
+        Assert.ThrowsAny<Exception>(() =>
+            left.Lazy().Join(dup.Lazy(), [Col("k")], [Col("k2")], validate: JoinValidation.OneToOne).Collect());
+        using var manyToOne = left.Join(dup, [Col("k")], [Col("k2")], validate: JoinValidation.OneToMany);
+        Assert.Equal(2, manyToOne.Height);
+    }
+
+    [Fact]
+    public void Test_JoinWhere_Range_Predicates()
+    {
+        using var id = new Series("id", [1, 2]);
+        using var start = new Series("start", [0, 10]);
+        using var end = new Series("end", [5, 20]);
+        using var intervals = new DataFrame(id, start, end);
+
+        using var rid = new Series("id", [7, 8, 9]);
+        using var t = new Series("t", [3, 12, 7]);
+        using var points = new DataFrame(rid, t);
+
+        // start <= t < end; 右表重名的 id 列加上后缀
+        using var res = intervals
+            .JoinWhere(points, [Col("t") >= Col("start"), Col("t") < Col("end")], suffix: "_r")
+            .Sort(Col("id"));
+        Assert.Equal(2, res.Height);
+        Assert.Equal(7, res.GetValue<int>(0, "id_r"));
+        Assert.Equal(3, res.GetValue<int>(0, "t"));
+        Assert.Equal(12, res.GetValue<int>(1, "t"));
+
+        // Lazy 版本结果一致, 默认后缀为 "_right"
+        using var lazy = intervals.Lazy()
+            .JoinWhere(points.Lazy(), [Col("t") >= Col("start"), Col("t") < Col("end")])
+            .Collect();
+        Assert.Equal(2, lazy.Height);
+        Assert.Contains("id_right", lazy.Columns);
+    }
+
+    [Fact]
+    public void Test_JoinAsOf_Tolerance_Kinds_And_Strategies()
+    {
+        // 1. 整数容差: 30 最近的是 25 (差 5 > 2) -> null
+        using var lt = new Series("t", [10L, 20L, 30L]);
+        using var left = new DataFrame(lt);
+        using var rt = new Series("t", [8L, 19L, 25L]);
+        using var rv = new Series("v", ["a", "b", "c"]);
+        using var right = new DataFrame(rt, rv);
+
+        using var intTol = left.JoinAsOf(right, Col("t"), Col("t"), tolerance: "2");
+        Assert.Equal("a", intTol.GetValue<string>(0, "v"));
+        Assert.Equal("b", intTol.GetValue<string>(1, "v"));
+        Assert.Null(intTol.GetValue<string>(2, "v"));
+
+        // 没有容差时 nearest 能匹配到所有行
+        using var nearest = left.JoinAsOf(right, Col("t"), Col("t"), strategy: "nearest");
+        Assert.Equal("c", nearest.GetValue<string>(2, "v"));
+
+        // forward + 容差: 往后找 2 以内都没有
+        using var forward = left.JoinAsOf(right, Col("t"), Col("t"), tolerance: "2", strategy: "forward");
+        Assert.Null(forward.GetValue<string>(0, "v"));
+
+        // 2. 浮点容差
+        using var lf = new Series("t", [1.0, 2.0]);
+        using var leftF = new DataFrame(lf);
+        using var rf = new Series("t", [0.5, 1.9]);
+        using var rfv = new Series("v", ["a", "b"]);
+        using var rightF = new DataFrame(rf, rfv);
+        using var floatTol = leftF.JoinAsOf(rightF, Col("t"), Col("t"), tolerance: "0.2");
+        Assert.Null(floatTol.GetValue<string>(0, "v"));
+        Assert.Equal("b", floatTol.GetValue<string>(1, "v"));
+
+        // 3. 时间字符串容差
+        var baseTime = new DateTime(2024, 1, 1, 9, 0, 0);
+        using var ld = new Series("t", [baseTime.AddMinutes(10), baseTime.AddMinutes(30)]);
+        using var leftD = new DataFrame(ld);
+        using var rd = new Series("t", [baseTime.AddMinutes(8), baseTime.AddMinutes(25)]);
+        using var rdv = new Series("v", ["a", "b"]);
+        using var rightD = new DataFrame(rd, rdv);
+        using var durTol = leftD.JoinAsOf(rightD, Col("t"), Col("t"), tolerance: "2m");
+        Assert.Equal("a", durTol.GetValue<string>(0, "v"));
+        Assert.Null(durTol.GetValue<string>(1, "v"));
+
+        // 4. 无法识别的策略 / 容差直接报错，不会静默回退
+        Assert.ThrowsAny<Exception>(() => left.JoinAsOf(right, Col("t"), Col("t"), strategy: "sideways"));
+        Assert.ThrowsAny<Exception>(() => leftD.JoinAsOf(rightD, Col("t"), Col("t"), tolerance: "abc"));
+    }
+
+    [Fact]
+    public void Test_GroupByDynamic_Windows_By_And_Boundaries()
+    {
+        var start = new DateTime(2024, 1, 1, 9, 0, 0);
+        using var t = new Series("t", new[] { 0, 1, 2, 5, 6, 9 }.Select(m => start.AddMinutes(m)).ToArray());
+        using var g = new Series("g", ["a", "a", "b", "a", "b", "a"]);
+        using var v = new Series("v", [1L, 2L, 3L, 4L, 5L, 6L]);
+        using var df = new DataFrame(t, g, v);
+
+        // 1. 5 分钟滚动窗口: [9:00, 9:05) 和 [9:05, 9:10)
+        using var res = df.GroupByDynamic(Col("t"), "5m", [Col("v").Sum().Alias("s")]);
+        Assert.Equal(2, res.Height);
+        Assert.Equal(start, res.GetValue<DateTime>(0, "t"));
+        Assert.Equal(6L, res.GetValue<long>(0, "s"));
+        Assert.Equal(15L, res.GetValue<long>(1, "s"));
+
+        // 2. 额外分组键 + 窗口边界列
+        using var byRes = df.GroupByDynamic(Col("t"), "5m", [Col("v").Sum().Alias("s")],
+                by: [Col("g")], includeBoundaries: true)
+            .Sort([Col("g"), Col("t")], [false]);
+        Assert.Equal(4, byRes.Height);
+        Assert.Contains("_lower_boundary", byRes.Columns);
+        Assert.Contains("_upper_boundary", byRes.Columns);
+        Assert.Equal("a", byRes.GetValue<string>(1, "g"));
+        Assert.Equal(10L, byRes.GetValue<long>(1, "s"));
+
+        // 3. Lazy 版本, period 比 every 长 (重叠窗口)
+        using var lazy = df.Lazy()
+            .GroupByDynamic(Col("t"), "5m", [Col("v").Count().Alias("n")], period: "10m")
+            .Collect();
+        Assert.Equal(6L, Convert.ToInt64(lazy[0, "n"]));
+
+        // 4. 非法的 label 报错
+        Assert.ThrowsAny<Exception>(() => df.GroupByDynamic(Col("t"), "5m", [Col("v").Sum()], label: "middle"));
+        // 非法的 closed 也报错，不再回退成 left
+        Assert.ThrowsAny<Exception>(() => df.GroupByDynamic(Col("t"), "5m", [Col("v").Sum()], closed: "middle"));
+    }
+
+    [Fact]
+    public void Test_Rolling_GroupBy_Closed_And_By()
+    {
+        using var t = new Series("t", [1L, 2L, 3L, 5L, 6L]);
+        using var g = new Series("g", ["a", "b", "a", "a", "b"]);
+        using var v = new Series("v", [1L, 2L, 3L, 4L, 5L]);
+        using var df = new DataFrame(t, g, v);
+
+        // 每行回看 (t - 2, t]
+        using var res = df.Rolling(Col("t"), "2i", [Col("v").Sum().Alias("s")]);
+        Assert.Equal(5, res.Height);
+        Assert.Equal(new long[] { 1, 3, 5, 4, 9 }, Enumerable.Range(0, 5).Select(i => res.GetValue<long>(i, "s")));
+
+        // 两端闭区间 [t - 2, t]
+        using var both = df.Rolling(Col("t"), "2i", [Col("v").Sum().Alias("s")], closed: "both");
+        Assert.Equal(6L, both.GetValue<long>(2, "s"));
+        Assert.Equal(7L, both.GetValue<long>(3, "s"));
+        Assert.ThrowsAny<Exception>(() => df.Rolling(Col("t"), "2i", [Col("v").Sum()], closed: "open"));
+
+        // 按 g 分组后各自滚动 (Lazy)
+        using var byRes = df.Lazy()
+            .Rolling(Col("t"), "2i", [Col("v").Sum().Alias("s")], by: [Col("g")])
+            .Collect()
+            .Sort([Col("g"), Col("t")], [false]);
+        Assert.Equal(3L, byRes.GetValue<long>(1, "s")); // a @ t=3: 只有它自己
+        Assert.Equal(5L, byRes.GetValue<long>(4, "s")); // b @ t=6
+    }
+
+    [Fact]
+    public void Test_GroupBy_MaintainOrder_Head_Tail_And_PartitionBy()
+    {
+        using var g = new Series("g", ["b", "a", "b", "c", "a", "b"]);
+        using var v = new Series("v", [1, 2, 3, 4, 5, 6]);
+        using var df = new DataFrame(g, v);
+
+        // 1. maintainOrder: 分组顺序和首次出现的顺序一致
+        using var agg = df.GroupBy([Col("g")], maintainOrder: true).Agg(Col("v").Sum().Alias("s"));
+        Assert.Equal(new[] { "b", "a", "c" }, Enumerable.Range(0, 3).Select(i => agg.GetValue<string>(i, "g")));
+        Assert.Equal(10, agg.GetValue<int>(0, "s"));
+
+        // 2. 每组的前 / 后 n 行
+        using var head = df.GroupBy([Col("g")], maintainOrder: true).Head(2);
+        Assert.Equal(5, head.Height);
+        Assert.Equal(1, head.GetValue<int>(0, "v"));
+        Assert.Equal(3, head.GetValue<int>(1, "v"));
+
+        using var tail = df.GroupBy([Col("g")], maintainOrder: true).Tail(1);
+        Assert.Equal(3, tail.Height);
+        Assert.Equal(6, tail.GetValue<int>(0, "v"));
+
+        using var lazyHead = df.Lazy().GroupBy([Col("g")], maintainOrder: true).Head(1).Collect();
+        Assert.Equal(3, lazyHead.Height);
+
+        // 3. PartitionBy: 第 i 个键对应第 i 个分区
+        var (keys, frames) = df.PartitionBy(["g"], includeKey: false);
+        using (keys)
+        {
+            Assert.Equal(3, frames.Length);
+            Assert.Equal("b", keys.GetValue<string>(0, "g"));
+            Assert.Equal(3, frames[0].Height);
+            Assert.Equal(new[] { "v" }, frames[0].Columns);
+            Assert.Equal(4, frames[2].GetValue<int>(0, "v"));
+        }
+        foreach (var f in frames) f.Dispose();
+
+        // 没有键列 -> 报错
+        Assert.ThrowsAny<Exception>(() => df.PartitionBy([]));
+    }
+    [Fact]
+    public void Test_Unique_Keep_Strategies_Duplicates_And_HashRows()
+    {
+        using var k = new Series("k", ["a", "b", "a", "c", "b"]);
+        using var v = new Series("v", [1, 2, 3, 4, 5]);
+        using var df = new DataFrame(k, v);
+
+        // 1. 保留策略 + 保持顺序
+        using var first = df.Unique(["k"], UniqueKeepStrategy.First, maintainOrder: true);
+        Assert.Equal(3, first.Height);
+        Assert.Equal(new[] { 1, 2, 4 }, Enumerable.Range(0, 3).Select(i => first.GetValue<int>(i, "v")));
+
+        using var last = df.Unique(["k"], UniqueKeepStrategy.Last, maintainOrder: true);
+        Assert.Equal(new[] { 3, 4, 5 }, Enumerable.Range(0, 3).Select(i => last.GetValue<int>(i, "v")));
+
+        // None: 丢弃所有出现过重复的键
+        using var none = df.UniqueStable(["k"], UniqueKeepStrategy.None);
+        Assert.Equal(1, none.Height);
+        Assert.Equal("c", none.GetValue<string>(0, "k"));
+
+        using var lazyFirst = df.Lazy().Unique(["k"], UniqueKeepStrategy.First, maintainOrder: true).Collect();
+        Assert.Equal(3, lazyFirst.Height);
+        Assert.Equal(2, lazyFirst.GetValue<int>(1, "v"));
+
+        // 2. 重复 / 唯一掩码
+        using var dup = df.IsDuplicated(["k"]);
+        Assert.Equal(new[] { true, true, true, false, true }, Enumerable.Range(0, 5).Select(i => dup.GetValue<bool>(i)));
+        using var uniq = df.IsUnique(["k"]);
+        Assert.Equal(new[] { false, false, false, true, false }, Enumerable.Range(0, 5).Select(i => uniq.GetValue<bool>(i)));
+
+        // 3. 不同行数
+        Assert.Equal(3UL, df.NUnique(["k"]));
+        Assert.Equal(5UL, df.NUnique());
+
+        // 4. 行哈希: 相同的行哈希相同
+        using var dk = df.Select(Col("k"));
+        using var h = dk.HashRows(42);
+        Assert.Equal(5, h.Length);
+        using var hdf = new DataFrame(h);
+        Assert.Equal(3UL, hdf.NUnique());
+    }
+    [Fact]
+    public void Test_RowIndex_Slice_Reverse_Shift_Gather()
+    {
+        using var v = new Series("v", [10, 20, 30, 40, 50]);
+        using var df = new DataFrame(v);
+
+        // 1. 行号列放在最前面
+        using var indexed = df.WithRowIndex("idx", offset: 1);
+        Assert.Equal(new[] { "idx", "v" }, indexed.Columns);
+        Assert.Equal(1L, indexed.GetValue<long>(0, "idx"));
+        Assert.Equal(5L, indexed.GetValue<long>(4, "idx"));
+
+        // 2. Slice: 负偏移从末尾算起
+        using var sliced = df.Slice(1, 2);
+        Assert.Equal(new[] { 20, 30 }, Enumerable.Range(0, 2).Select(i => sliced.GetValue<int>(i, "v")));
+        using var tail = df.Slice(-2, 10);
+        Assert.Equal(new[] { 40, 50 }, Enumerable.Range(0, 2).Select(i => tail.GetValue<int>(i, "v")));
+
+        // 3. Reverse / Shift
+        using var reversed = df.Reverse();
+        Assert.Equal(50, reversed.GetValue<int>(0, "v"));
+        using var shifted = df.Shift(2);
+        Assert.Null(shifted.GetValue<int?>(1, "v"));
+        Assert.Equal(10, shifted.GetValue<int>(2, "v"));
+        using var back = df.Shift(-1);
+        Assert.Equal(20, back.GetValue<int>(0, "v"));
+        Assert.Null(back.GetValue<int?>(4, "v"));
+
+        // 4. Gather / GatherEvery
+        using var gathered = df.Gather(4, 0, 4);
+        Assert.Equal(new[] { 50, 10, 50 }, Enumerable.Range(0, 3).Select(i => gathered.GetValue<int>(i, "v")));
+        Assert.ThrowsAny<Exception>(() => df.Gather(5));
+
+        using var every = df.GatherEvery(2, offset: 1);
+        Assert.Equal(new[] { 20, 40 }, Enumerable.Range(0, 2).Select(i => every.GetValue<int>(i, "v")));
+        Assert.ThrowsAny<Exception>(() => df.GatherEvery(0));
+
+        // 5. Lazy 版本结果一致
+        using var lazy = df.Lazy().WithRowIndex("idx").Reverse().Slice(0, 3).GatherEvery(2).Collect();
+        Assert.Equal(2, lazy.Height);
+        Assert.Equal(4L, lazy.GetValue<long>(0, "idx"));
+        Assert.Equal(30, lazy.GetValue<int>(1, "v"));
+        Assert.ThrowsAny<Exception>(() => df.Lazy().GatherEvery(0));
+    }
+    [Fact]
+    public void Test_Frame_FillNull_FillNan_Interpolate()
+    {
+        using var a = new Series("a", [1, 0, 0, 4], [true, false, false, true]);
+        using var f = new Series("f", [1.0, double.NaN, 0.0, 4.0], [true, true, false, true]);
+        using var s = new Series("s", ["x", null, "y", null]);
+        using var df = new DataFrame(a, f, s);
+
+        // 1. 按值填充，只作用于指定列
+        using var byValue = df.FillNull(Lit(0), "a");
+        Assert.Equal(0, byValue.GetValue<int>(1, "a"));
+        Assert.Null(byValue.GetValue<string>(1, "s"));
+
+        // 2. 前向填充 + limit
+        using var forward = df.FillNull(FillNullStrategy.Forward, limit: 1);
+        Assert.Equal(1, forward.GetValue<int>(1, "a"));
+        Assert.Null(forward.GetValue<int?>(2, "a"));
+        Assert.Equal("x", forward.GetValue<string>(1, "s"));
+
+        // Mean 只作用于数值列，字符串列保持原样
+        using var mean = df.FillNull(FillNullStrategy.Mean);
+        Assert.Equal(2, mean.GetValue<int>(1, "a"));
+        Assert.Null(mean.GetValue<string>(1, "s"));
+
+        // 3. FillNan 只替换 NaN，不碰 null
+        using var noNan = df.FillNan(Lit(0.0));
+        Assert.Equal(0.0, noNan.GetValue<double>(1, "f"));
+        Assert.Null(noNan.GetValue<double?>(2, "f"));
+
+        // 4. 插值
+        using var linear = df.Interpolate(InterpolationMethod.Linear, "a");
+        Assert.Equal(new[] { 1.0, 2.0, 3.0, 4.0 }, Enumerable.Range(0, 4).Select(i => linear.GetValue<double>(i, "a")));
+        using var nearest = df.Interpolate(InterpolationMethod.Nearest, "a");
+        Assert.Equal(new[] { 1, 1, 4, 4 }, Enumerable.Range(0, 4).Select(i => nearest.GetValue<int>(i, "a")));
+        Assert.ThrowsAny<Exception>(() => df.Interpolate((InterpolationMethod)99, "a"));
+
+        // 5. Lazy 版本
+        using var lazy = df.Lazy().FillNull(FillNullStrategy.Backward).FillNan(Lit(-1.0), "f").Collect();
+        Assert.Equal(4, lazy.GetValue<int>(1, "a"));
+        Assert.Equal(-1.0, lazy.GetValue<double>(1, "f"));
+        Assert.Equal("y", lazy.GetValue<string>(1, "s"));
+    }
+    [Fact]
+    public void Test_Describe_Percentiles_And_Mixed_Columns()
+    {
+        using var v = new Series("v", [1, 2, 3, 4, 5]);
+        using var s = new Series("s", ["a", "b", "c", "d", "e"]);
+        using var df = new DataFrame(v, s);
+
+        // 百分位保持输入顺序，重复的只保留第一次出现的
+        using var summary = df.Describe(0.5, 0.1, 0.5);
+        Assert.Equal(new[] { "statistic", "v", "s" }, summary.Columns);
+        Assert.Equal(
+            new[] { "count", "null_count", "mean", "std", "min", "50%", "10%", "max" },
+            Enumerable.Range(0, (int)summary.Height).Select(i => summary.GetValue<string>(i, "statistic")));
+        Assert.Equal(3.0, summary.GetValue<double>(5, "v"));
+
+        // 字符串列: min/max 按字典序，mean 为 null
+        Assert.Equal("a", summary.GetValue<string>(4, "s"));
+        Assert.Equal("e", summary.GetValue<string>(7, "s"));
+        Assert.Null(summary.GetValue<string>(2, "s"));
+
+        // Lazy 版本默认 25/50/75
+        using var lazySummary = df.Lazy().Describe();
+        Assert.Equal(9, lazySummary.Height);
+
+        Assert.ThrowsAny<Exception>(() => df.Describe(1.5));
+    }
+    [Fact]
+    public void Test_Transpose_ToDummies_Upsample()
+    {
+        // 1. Transpose: 原列名保存为 "col"，用 name 列的值作为新列名
+        using var name = new Series("name", ["x", "y"]);
+        using var a = new Series("a", [1L, 2L]);
+        using var b = new Series("b", [3L, 4L]);
+        using var df = new DataFrame(name, a, b);
+
+        using var t = df.Transpose(keepNamesAs: "col", headerColumn: "name");
+        Assert.Equal(new[] { "col", "x", "y" }, t.Columns);
+        Assert.Equal("b", t.GetValue<string>(1, "col"));
+        Assert.Equal(2L, t.GetValue<long>(0, "y"));
+
+        using var numeric = df.Drop(["name"]);
+        using var tDefault = numeric.Transpose();
+        Assert.Equal(new[] { "column_0", "column_1" }, tDefault.Columns);
+        using var tNamed = numeric.Transpose(columnNames: ["p", "q"]);
+        Assert.Equal(4L, tNamed.GetValue<long>(1, "q"));
+
+        // 2. ToDummies: null 也会得到一列
+        using var g = new Series("g", ["u", "v", null, "u"]);
+        using var cat = new DataFrame(g);
+        using var dummies = cat.ToDummies();
+        Assert.Equal(new[] { "g_null", "g_u", "g_v" }, dummies.Columns);
+        Assert.Equal(1, dummies.GetValue<int>(3, "g_u"));
+
+        using var dropped = cat.ToDummies(separator: ":", dropFirst: true, dropNulls: true);
+        Assert.Equal(new[] { "g:v" }, dropped.Columns);
+
+        // 3. Upsample: 按组补齐缺失的日期，补出来的行带上所在组的键
+        using var k = new Series("k", ["a", "a", "b"]);
+        using var v = new Series("v", [1L, 3L, 5L]);
+        using var day = new Series("t", [new DateOnly(2024, 1, 1), new DateOnly(2024, 1, 3), new DateOnly(2024, 1, 1)]);
+        using var ts = new DataFrame(k, v, day);
+
+        using var up = ts.Upsample("t", "1d", by: ["k"]);
+        Assert.Equal(4, up.Height);
+        Assert.Equal(new DateOnly(2024, 1, 2), up.GetValue<DateOnly>(1, "t"));
+        Assert.Equal("a", up.GetValue<string>(1, "k"));
+        Assert.Null(up.GetValue<long?>(1, "v"));
+
+        // 分组键里有 null: (a, null) 组补出来的行仍然是 (a, null)，不会被上一组的键填上
+        using var k1 = new Series("k1", ["a", "a", "a", "a"]);
+        using var k2 = new Series("k2", new long?[] { 1, 1, null, null });
+        using var v2 = new Series("v", [1L, 2L, 3L, 4L]);
+        using var day2 = new Series("t", [new DateOnly(2024, 1, 1), new DateOnly(2024, 1, 3), new DateOnly(2024, 1, 1), new DateOnly(2024, 1, 3)]);
+        using var nullKeys = new DataFrame(k1, k2, v2, day2);
+
+        using var upNull = nullKeys.Upsample("t", "1d", by: ["k1", "k2"]);
+        Assert.Equal(6, upNull.Height);
+        Assert.Equal(1L, upNull.GetValue<long?>(1, "k2"));
+        Assert.Equal(new DateOnly(2024, 1, 2), upNull.GetValue<DateOnly>(4, "t"));
+        Assert.Equal("a", upNull.GetValue<string>(4, "k1"));
+        Assert.Null(upNull.GetValue<long?>(4, "k2"));
+        Assert.Null(upNull.GetValue<long?>(4, "v"));
+
+        // 不分组时时间列必须整体有序
+        Assert.ThrowsAny<Exception>(() => ts.Upsample("t", "1d"));
+    }
+    [Fact]
+    public void Test_Pivot_Len_Counts_Rows_Per_Cell()
+    {
+        // (a, x) 有两行 (其中一行 v 为 null)，(b, y) 没有行
+        using var k = new Series("k", ["a", "a", "a", "b"]);
+        using var c = new Series("c", ["x", "x", "y", "x"]);
+        using var v = new Series("v", [1L, 0L, 3L, 4L], [true, false, true, true]);
+        using var df = new DataFrame(k, c, v);
+
+        // Len 统计每个单元格的行数 (包括 null)，Count 只统计非空值；没有行的单元格为 null
+        using var len = df.Pivot(["k"], ["c"], ["v"], PivotAgg.Len, maintainOrder: true);
+        Assert.Equal(2L, len.GetValue<long>(0, "x"));
+        Assert.Equal(1L, len.GetValue<long>(0, "y"));
+        Assert.Equal(1L, len.GetValue<long>(1, "x"));
+        Assert.Null(len.GetValue<long?>(1, "y"));
+
+        using var count = df.Pivot(["k"], ["c"], ["v"], PivotAgg.Count, maintainOrder: true);
+        Assert.Equal(1L, count.GetValue<long>(0, "x"));
+
+        // Lazy 版本在同一份数据上结果一致
+        foreach (var agg in new[] { PivotAgg.Len, PivotAgg.Count, PivotAgg.Sum, PivotAgg.First })
+        {
+            using var eager = df.Pivot(["k"], ["c"], ["v"], agg, maintainOrder: true);
+            using var lazy = df.Lazy()
+                .Pivot("c", ["x", "y"], ["k"], ["v"], agg, maintainOrder: true)
+                .Collect();
+            Assert.Equal(eager.Columns, lazy.Columns);
+            for (int i = 0; i < 2; i++)
+            {
+                Assert.Equal(eager.GetValue<long?>(i, "x"), lazy.GetValue<long?>(i, "x"));
+                Assert.Equal(eager.GetValue<long?>(i, "y"), lazy.GetValue<long?>(i, "y"));
+            }
+            Assert.Null(lazy.GetValue<long?>(1, "y"));
+        }
+    }
+    [Fact]
+    public void Test_Rows_Packed_And_Json_Records()
+    {
+        var content = "i,s,naive,aware\n1,a,2023-11-14 22:13:20,2023-11-14T22:13:20+00:00\n,b,2023-11-15 00:00:00,2023-11-15T00:00:00+00:00\n";
+        using var csv = new DisposableFile(content, ".csv");
+        using var df = DataFrame.ReadCsv(csv.Path);
+
+        // 1. 批量取行: 类型化的值，null 透传
+        var rows = df.Rows();
+        Assert.Equal(2, rows.Length);
+        Assert.Equal(1L, rows[0][0]);
+        Assert.Equal("a", rows[0][1]);
+        Assert.Null(rows[1][0]);
+
+        // 无时区 -> Unspecified，带时区 -> Utc
+        var naive = Assert.IsType<DateTime>(rows[0][2]);
+        Assert.Equal(DateTimeKind.Unspecified, naive.Kind);
+        Assert.Equal(new DateTime(2023, 11, 14, 22, 13, 20), naive);
+        var aware = Assert.IsType<DateTime>(rows[0][3]);
+        Assert.Equal(DateTimeKind.Utc, aware.Kind);
+        Assert.Equal(new DateTime(2023, 11, 14, 22, 13, 20, DateTimeKind.Utc), aware);
+
+        // 与单元格读取保持一致
+        Assert.Equal(df.GetDatetime("naive", 0)!.Value.Kind, naive.Kind);
+        using var awareCol = df.Column("aware");
+        Assert.Equal(DateTimeKind.Utc, awareCol.GetValue<DateTime>(0).Kind);
+        Assert.Equal(aware, awareCol.GetValue<DateTime>(0));
+
+        // 2. 单行 + 负索引
+        var last = df.Row(-1);
+        Assert.Equal("b", last[1]);
+        Assert.ThrowsAny<Exception>(() => df.Row(5));
+
+        // 3. 区间
+        var tail = df.Rows(1, 5);
+        Assert.Single(tail);
+
+        // 4. JSON 记录
+        using var doc = System.Text.Json.JsonDocument.Parse(df.RowsToJson(-1));
+        var record = doc.RootElement[0];
+        Assert.Equal(System.Text.Json.JsonValueKind.Null, record.GetProperty("i").ValueKind);
+        Assert.Equal("b", record.GetProperty("s").GetString());
+
+        // 5. Decimal 不经过 double，精确返回 decimal；超出 decimal 范围时返回字符串
+        using var text = new Series("d", ["12345678901234567.891", "-0.5", "1234567890123456789012345678901234.5"]);
+        using var dec = new DataFrame(text).WithColumns(Col("d").Cast(DataType.Decimal(38, 3)));
+        var decRows = dec.Rows();
+        Assert.Equal(12345678901234567.891m, Assert.IsType<decimal>(decRows[0][0]));
+        Assert.Equal(-0.5m, Assert.IsType<decimal>(decRows[1][0]));
+        Assert.Equal("1234567890123456789012345678901234.500", Assert.IsType<string>(decRows[2][0]));
+    }
+    [Fact]
+    public void Test_Typed_Scalar_Accessors()
+    {
+        using var s = new Series("s", ["ab", null]);
+        using var d = new Series("d", [1.5, 0.0], [true, false]);
+        using var b = new Series("b", [true, false]);
+        using var day = new Series("day", [new DateOnly(2024, 2, 29), new DateOnly(1969, 12, 31)]);
+        using var tod = new Series("tod", [new TimeOnly(13, 45, 30), TimeOnly.MinValue]);
+        using var dur = new Series("dur", [TimeSpan.FromMinutes(90), TimeSpan.Zero]);
+        using var ts = new Series("ts", [new DateTime(2024, 1, 2, 3, 4, 5), DateTime.UnixEpoch]);
+        using var raw = new DataFrame(s, d, b, day, tod, dur, ts);
+
+        using var df = raw.WithColumns(
+            Col("s").Cast(DataType.Binary).Alias("bin"),
+            Col("s").Cast(DataType.Categorical).Alias("cat"),
+            Col("d").Cast(DataType.Decimal(10, 2)).Alias("dec"),
+            AsStruct(Col("s"), Col("d")).Alias("st"));
+
+        // 1. 基础类型
+        Assert.True(df.GetBool("b", 0));
+        Assert.False(df.GetBool("b", 1));
+        Assert.Equal(new DateOnly(2024, 2, 29), df.GetDate("day", 0));
+        Assert.Equal(new DateOnly(1969, 12, 31), df.GetDate("day", 1));
+        Assert.Equal(new TimeOnly(13, 45, 30), df.GetTime("tod", 0));
+        Assert.Equal(TimeSpan.FromMinutes(90), df.GetDuration("dur", 0));
+        Assert.Equal(new DateTime(2024, 1, 2, 3, 4, 5), df.GetDatetime("ts", 0));
+        var withZone = df.GetDatetimeWithZone("ts", 0);
+        Assert.NotNull(withZone);
+        Assert.Null(withZone.Value.TimeZone);
+        Assert.Equal(DateTimeKind.Unspecified, withZone.Value.Value.Kind);
+
+        // 2. Decimal / Binary / Categorical / 嵌套
+        Assert.Equal(1.50m, df.GetDecimal("dec", 0));
+        Assert.Equal(1.5, df.GetDouble("dec", 0)); // Decimal 也可以按浮点读取
+        Assert.Equal(new byte[] { 97, 98 }, df.GetBinary("bin", 0));
+        Assert.Equal("ab", df.GetCategorical("cat", 0));
+        var nested = df.GetNested("st", 0);
+        Assert.NotNull(nested);
+        Assert.Equal("ab", nested.Value.GetProperty("s").GetString());
+        Assert.Equal(1.5, nested.Value.GetProperty("d").GetDouble());
+
+        // 3. null、类型不匹配、列不存在、越界 -> null
+        Assert.Null(df.GetBinary("bin", 1));
+        Assert.Null(df.GetCategorical("cat", 1));
+        Assert.Null(df.GetDecimal("dec", 1));
+        Assert.Null(df.GetBool("s", 0));
+        Assert.Null(df.GetDate("ts", 0));
+        Assert.Null(df.GetNested("s", 0));
+        Assert.Null(df.GetBool("missing", 0));
+        Assert.Null(df.GetBool("b", 10));
+
+        // 4. Series 的 getter 与 DataFrame 一致: 整数 / Decimal 可以按浮点读取，无时区的 datetime 为 Unspecified
+        using var decCol = df.Column("dec");
+        Assert.Equal(1.5, decCol.GetValue<double>(0));
+        using var ints = new Series("n", [3L]);
+        Assert.Equal(3.0, ints.GetValue<double>(0));
+        using var tsCol = df.Column("ts");
+        var seriesTs = tsCol.GetValue<DateTime>(0);
+        Assert.Equal(new DateTime(2024, 1, 2, 3, 4, 5), seriesTs);
+        Assert.Equal(DateTimeKind.Unspecified, seriesTs.Kind);
+    }
+    [Fact]
+    public void Test_Frame_And_Series_Equality_Reports()
+    {
+        using var a = new Series("a", [1.0, 2.0]);
+        using var b = new Series("b", [3L, 4L]);
+        using var left = new DataFrame(a, b);
+
+        // 1. 相等 / 浮点容差
+        using var same = left.Clone();
+        Assert.True(left.FrameEqual(same).IsEqual);
+        left.AssertFrameEqual(same);
+
+        using var a2 = new Series("a", [1.0, 2.0000001]);
+        using var close = new DataFrame(a2, b);
+        Assert.True(left.FrameEqual(close).IsEqual);
+        Assert.False(left.FrameEqual(close, checkExact: true).IsEqual);
+
+        // 2. 值不同: 报告列、行和两侧的值
+        using var a3 = new Series("a", [1.0, 2.5]);
+        using var diff = new DataFrame(a3, b);
+        var report = left.FrameEqual(diff);
+        Assert.False(report.IsEqual);
+        Assert.Equal("value", report.Kind);
+        Assert.Equal("a", report.Column);
+        Assert.Equal(1L, report.Row);
+        Assert.Equal("2.0", report.Left);
+        Assert.Equal("2.5", report.Right);
+        Assert.Equal(1L, report.MismatchCount);
+        var ex = Assert.Throws<PolarsAssertionException>(() => left.AssertFrameEqual(diff));
+        Assert.Equal("value", ex.Report.Kind);
+
+        // 3. dtype / 列顺序 / 行顺序 / 列名 / 形状
+        using var bInt = new Series("a", [1L, 2L]);
+        using var ints = new DataFrame(bInt, b);
+        var dtypeReport = left.FrameEqual(ints);
+        Assert.Equal("dtype", dtypeReport.Kind);
+        Assert.Equal(new DtypeMismatch("a", "f64", "i64"), dtypeReport.DtypeMismatches.Single());
+        Assert.True(left.FrameEqual(ints, checkDtypes: false).IsEqual);
+
+        using var swapped = left.Select(Col("b"), Col("a"));
+        Assert.Equal("column_order", left.FrameEqual(swapped).Kind);
+        Assert.True(left.FrameEqual(swapped, checkColumnOrder: false).IsEqual);
+
+        using var reversed = left.Reverse();
+        Assert.Equal("value", left.FrameEqual(reversed).Kind);
+        Assert.True(left.FrameEqual(reversed, checkRowOrder: false).IsEqual);
+
+        using var renamed = left.Rename("b", "c");
+        var colsReport = left.FrameEqual(renamed);
+        Assert.Equal("columns", colsReport.Kind);
+        Assert.Equal(new[] { "b" }, colsReport.OnlyInLeft);
+        Assert.Equal(new[] { "c" }, colsReport.OnlyInRight);
+
+        using var head = left.Head(1);
+        Assert.Equal("shape", left.FrameEqual(head).Kind);
+
+        // 4. 不检查 dtype 时，无法转成浮点的字符串按字符串比较，报告原始值
+        using var text = new Series("a", ["1.0", "x"]);
+        using var texts = new DataFrame(text, b);
+        var textReport = left.FrameEqual(texts, checkDtypes: false);
+        Assert.Equal("value", textReport.Kind);
+        Assert.Equal(1L, textReport.Row);
+        Assert.Equal("\"x\"", textReport.Right);
+
+        // 不能转换到同一类型的列 (Int64 vs String) 同样按字符串比较，不会报错
+        using var iCol = new Series("a", [1L, 2L]);
+        using var sCol = new Series("a", ["1", "2"]);
+        using var xCol = new Series("a", ["1", "x"]);
+        using var intFrame = new DataFrame(iCol);
+        using var strFrame = new DataFrame(sCol);
+        using var badFrame = new DataFrame(xCol);
+        Assert.True(intFrame.FrameEqual(strFrame, checkDtypes: false).IsEqual);
+        var mixedReport = intFrame.FrameEqual(badFrame, checkDtypes: false);
+        Assert.Equal("value", mixedReport.Kind);
+        Assert.Equal(1L, mixedReport.Row);
+        Assert.Equal("2", mixedReport.Left);
+
+        // List / Struct 内部的浮点同样使用容差
+        using var nestedLeft = new DisposableFile("[{\"l\": [1.0, 2.0], \"s\": {\"x\": 1.0}}]", ".json");
+        using var nestedRight = new DisposableFile("[{\"l\": [1.0, 2.0000001], \"s\": {\"x\": 1.0000001}}]", ".json");
+        using var nl = DataFrame.ReadJson(nestedLeft.Path);
+        using var nr = DataFrame.ReadJson(nestedRight.Path);
+        Assert.True(nl.FrameEqual(nr).IsEqual);
+        Assert.Equal("l", nl.FrameEqual(nr, checkExact: true).Column);
+        using var structLeft = nl.Select(Col("s"));
+        using var structRight = nr.Select(Col("s"));
+        Assert.True(structLeft.FrameEqual(structRight).IsEqual);
+        Assert.Equal("s", structLeft.FrameEqual(structRight, checkExact: true).Column);
+
+        // 5. Series: 名字、值
+        using var s1 = new Series("x", [1, 2, 3]);
+        using var s2 = new Series("y", [1, 2, 3]);
+        Assert.Equal("name", s1.SeriesEqual(s2).Kind);
+        Assert.True(s1.SeriesEqual(s2, checkNames: false).IsEqual);
+        using var s3 = new Series("x", [3, 2, 1]);
+        Assert.True(s1.SeriesEqual(s3, checkOrder: false).IsEqual);
+        Assert.Throws<PolarsAssertionException>(() => s1.AssertSeriesEqual(s3));
+    }
+    [Fact]
+    public void Test_EstimatedSize_Chunks_Rechunk_ShrinkToFit()
+    {
+        using var a = new Series("a", [1L, 2L]);
+        using var b = new Series("b", [3L, 4L]);
+        using var df = new DataFrame(a, b);
+        using var more = df.Clone();
+
+        Assert.Equal(1, df.NChunks);
+        var size = df.EstimatedSize;
+        Assert.True(size >= 32); // 2 列 x 2 行 x 8 字节
+
+        // VStack 只追加 chunk
+        df.VStack(more);
+        Assert.Equal(4, df.Height);
+        Assert.Equal(2, df.NChunks);
+        Assert.True(df.EstimatedSize > size);
+
+        using var col = df.Column("a");
+        Assert.Equal(2, col.NChunks);
+        Assert.Equal(1, col.Rechunk().NChunks);
+        Assert.Equal(2, df.NChunks); // Series 是独立的副本
+
+        // Rechunk / ShrinkToFit 原地生效，数据不变
+        Assert.Same(df, df.Rechunk());
+        Assert.Equal(1, df.NChunks);
+        df.ShrinkToFit();
+        Assert.Equal(4, df.Height);
+        Assert.Equal(2L, df.GetValue<long>(3, "a"));
+        Assert.True(col.ShrinkToFit().EstimatedSize > 0);
+    }
+    [Fact]
+    public void Test_InPlace_Insert_Replace_HStack_VStack_And_DropMany()
+    {
+        using var a = new Series("a", [1L, 2L]);
+        using var b = new Series("b", [3L, 4L]);
+        using var df = new DataFrame(a, b);
+
+        // 1. InsertColumn: 原地插入，返回自身；传入的 Series 依然可用
+        using var z = new Series("z", [0L, 0L]);
+        Assert.Same(df, df.InsertColumn(0, z));
+        Assert.Equal(new[] { "z", "a", "b" }, df.Columns);
+        Assert.Equal(2, z.Length);
+
+        Assert.ThrowsAny<Exception>(() => df.InsertColumn(0, a));  // 重名
+        using var shortCol = new Series("s", [1L]);
+        Assert.ThrowsAny<Exception>(() => df.InsertColumn(0, shortCol)); // 长度不符
+        using var c = new Series("c", [5L, 6L]);
+        Assert.ThrowsAny<Exception>(() => df.InsertColumn(9, c)); // 越界
+
+        // 2. ReplaceColumn: 保留位置和列名
+        using var replacement = new Series("other", [7L, 8L]);
+        df.ReplaceColumn("a", replacement);
+        Assert.Equal(new[] { "z", "a", "b" }, df.Columns);
+        Assert.Equal(8L, df.GetValue<long>(1, "a"));
+        Assert.ThrowsAny<Exception>(() => df.ReplaceColumn("missing", replacement));
+
+        // 3. HStack
+        df.HStack(c);
+        Assert.Equal(new[] { "z", "a", "b", "c" }, df.Columns);
+        Assert.ThrowsAny<Exception>(() => df.HStack(shortCol));
+
+        // 4. VStack / Extend: 追加自身也安全
+        df.VStack(df);
+        Assert.Equal(4, df.Height);
+        using var extra = df.Head(1);
+        df.Extend(extra);
+        Assert.Equal(5, df.Height);
+        Assert.Equal(0L, df.GetValue<long>(4, "z"));
+
+        using var badSchema = df.Select(Col("z"), Col("a").Cast(DataType.String), Col("b"), Col("c"));
+        Assert.ThrowsAny<Exception>(() => df.VStack(badSchema));
+
+        // 5. 一次删除多列
+        using var dropped = df.Drop(["z", "c"]);
+        Assert.Equal(new[] { "a", "b" }, dropped.Columns);
+        Assert.ThrowsAny<Exception>(() => df.Drop(["z", "missing"]));
+        using var lenient = df.Drop(["z", "missing"], strict: false);
+        Assert.Equal(new[] { "a", "b", "c" }, lenient.Columns);
+    }
+}
@@ -112,5 +112,350 @@ namespace Polars.CSharp.Tests
             var strType = dfString.Schema["birthday"];
             Assert.Equal(DataTypeKind.String,strType.Kind);
         }
+        [Fact]
+        public void Test_CloudOptions_Scan_And_Sink()
+        {
+            using var s = new Series("a", [1, 2, 3]);
+            using var df = new DataFrame(s);
+            using var src = new DisposableFile(".parquet");
+            df.WriteParquet(src.Path);
+
+            using var opts = CloudOptions.S3(
+                endpoint: "http://127.0.0.1:9",
+                region: "us-east-1",
+                accessKeyId: "key",
+                secretAccessKey: "secret",
+                pathStyle: true,
+                allowHttp: true,
+                maxRetries: 0);
+
+            // 本地路径不受云配置影响
+            using var lf = LazyFrame.ScanParquet(src.Path, opts);
+            using var dst = new DisposableFile(".parquet");
+            lf.SinkParquet(dst.Path, opts);
+            using var back = DataFrame.ReadParquet(dst.Path);
+            Assert.Equal(3, back.Height);
+
+            // s3:// 走自定义 endpoint，连不上时应该报错而不是崩溃
+            Assert.ThrowsAny<Exception>(() =>
+            {
+                using var remote = LazyFrame.ScanParquet("s3://bucket/data.parquet", opts);
+                using var _ = remote.Collect();
+            });
+        }
+        [Fact]
+        public void Test_ScanDelta_Checkpoint_Partitions_TimeTravel()
+        {
+            var table = System.IO.Path.Combine(System.IO.Path.GetTempPath(), $"delta_{Guid.NewGuid()}");
+            var log = System.IO.Path.Combine(table, "_delta_log");
+            Directory.CreateDirectory(log);
+            try
+            {
+                // 数据文件: 分区列 region 不在文件里，由日志里的 partitionValues 补回
+                void WritePart(string relPath, long[] ids, double[] amounts)
+                {
+                    var full = System.IO.Path.Combine(table, relPath);
+                    Directory.CreateDirectory(System.IO.Path.GetDirectoryName(full)!);
+                    using var id = new Series("id", ids);
+                    using var amount = new Series("amount", amounts);
+                    using var part = new DataFrame(id, amount);
+                    part.WriteParquet(full);
+                }
+                WritePart("region=eu/part-0.parquet", [1, 2], [10.0, 20.0]);
+                WritePart("region=us/part-0.parquet", [3], [30.0]);
+                WritePart("region=eu/part-1.parquet", [4], [40.0]);
+
+                var schemaString = System.Text.Json.JsonSerializer.Serialize(new
+                {
+                    type = "struct",
+                    fields = new object[]
+                    {
+                        new { name = "id", type = "long", nullable = true, metadata = new { } },
+                        new { name = "amount", type = "double", nullable = true, metadata = new { } },
+                        new { name = "region", type = "string", nullable = true, metadata = new { } },
+                    }
+                });
+                var metaData = new { id = "t", format = new { provider = "parquet" }, schemaString, partitionColumns = new[] { "region" } };
+                object Add(string path, string region) =>
+                    new { add = new { path, partitionValues = new Dictionary<string, string> { ["region"] = region }, size = 0, modificationTime = 0, dataChange = true } };
+                void WriteCommit(long version, params object[] actions) =>
+                    File.WriteAllLines(
+                        System.IO.Path.Combine(log, $"{version:D20}.json"),
+                        actions.Select(a => System.Text.Json.JsonSerializer.Serialize(a)));
+
+                WriteCommit(0,
+                    new { protocol = new { minReaderVersion = 1, minWriterVersion = 2 } },
+                    new { metaData },
+                    Add("region=eu/part-0.parquet", "eu"));
+                WriteCommit(1, Add("region=us/part-0.parquet", "us"));
+                WriteCommit(2,
+                    new { remove = new { path = "region=eu/part-0.parquet", dataChange = true } },
+                    Add("region=eu/part-1.parquet", "eu"));
+
+                // version 1 的 checkpoint (parquet): partitionValues 是 map，即 List<Struct<key, value>>
+                var checkpointJson = System.Text.Json.JsonSerializer.Serialize(new object[]
+                {
+                    new { add = new { path = "region=eu/part-0.parquet", partitionValues = new[] { new { key = "region", value = "eu" } } }, metaData = (object?)null },
+                    new { add = new { path = "region=us/part-0.parquet", partitionValues = new[] { new { key = "region", value = "us" } } }, metaData = (object?)null },
+                    new { add = (object?)null, metaData = (object)new { schemaString, partitionColumns = new[] { "region" } } },
+                });
+                using (var cpJson = new DisposableFile(checkpointJson, ".json"))
+                using (var checkpoint = DataFrame.ReadJson(cpJson.Path))
+                {
+                    checkpoint.WriteParquet(System.IO.Path.Combine(log, $"{1:D20}.checkpoint.parquet"));
+                }
+                // 日志清理后 checkpoint 之前的 commit 不再存在
+                File.Delete(System.IO.Path.Combine(log, $"{0:D20}.json"));
+
+                // 最新版本: checkpoint + commit 2
+                using var latest = LazyFrame.ScanDelta(table).Collect().Sort(Polars.Col("id"));
+                Assert.Equal(new[] { "id", "amount", "region" }, latest.Columns);
+                Assert.Equal(2, latest.Height);
+                Assert.Equal(3L, latest.GetValue<long>(0, "id"));
+                Assert.Equal("us", latest.GetValue<string>(0, "region"));
+                Assert.Equal(4L, latest.GetValue<long>(1, "id"));
+                Assert.Equal("eu", latest.GetValue<string>(1, "region"));
+
+                // time travel 到 checkpoint 所在版本
+                using var v1 = LazyFrame.ScanDelta(table, 1).Collect().Sort(Polars.Col("id"));
+                Assert.Equal(3, v1.Height);
+                Assert.Equal(20.0, v1.GetValue<double>(1, "amount"));
+                Assert.Equal("eu", v1.GetValue<string>(1, "region"));
+
+                // version 0 的 commit 已被清理
+                Assert.ThrowsAny<Exception>(() => LazyFrame.ScanDelta(table, 0));
+            }
+            finally
+            {
+                Directory.Delete(table, true);
+            }
+        }
+
+        [Fact]
+        public void Test_ScanDelta_Missing_Columns_And_Temporal_Partitions()
+        {
+            var table = System.IO.Path.Combine(System.IO.Path.GetTempPath(), $"delta_{Guid.NewGuid()}");
+            var log = System.IO.Path.Combine(table, "_delta_log");
+            Directory.CreateDirectory(log);
+            try
+            {
+                // 文件里只有 id；extra 是后来加的列，任何文件里都没有
+                using (var id = new Series("id", new long[] { 1, 2 }))
+                using (var part = new DataFrame(id))
+                {
+                    part.WriteParquet(System.IO.Path.Combine(table, "part-0.parquet"));
+                }
+
+                var schemaString = System.Text.Json.JsonSerializer.Serialize(new
+                {
+                    type = "struct",
+                    fields = new object[]
+                    {
+                        new { name = "id", type = "long", nullable = true, metadata = new { } },
+                        new { name = "extra", type = "double", nullable = true, metadata = new { } },
+                        new { name = "d", type = "date", nullable = true, metadata = new { } },
+                        new { name = "ts", type = "timestamp", nullable = true, metadata = new { } },
+                        new { name = "ntz", type = "timestamp_ntz", nullable = true, metadata = new { } },
+                    }
+                });
+                var metaData = new { id = "t", format = new { provider = "parquet" }, schemaString, partitionColumns = new[] { "d", "ts", "ntz" } };
+                void WriteCommit(long version, params object[] actions) =>
+                    File.WriteAllLines(
+                        System.IO.Path.Combine(log, $"{version:D20}.json"),
+                        actions.Select(a => System.Text.Json.JsonSerializer.Serialize(a)));
+                object Add(string d) => new
+                {
+                    add = new
+                    {
+                        path = "part-0.parquet",
+                        partitionValues = new Dictionary<string, string>
+                        {
+                            ["d"] = d,
+                            ["ts"] = "2024-03-05T10:20:30.123456Z",
+                            ["ntz"] = "2024-03-05 10:20:30",
+                        },
+                        size = 0, modificationTime = 0, dataChange = true
+                    }
+                };
+
+                WriteCommit(0,
+                    new { protocol = new { minReaderVersion = 1, minWriterVersion = 2 } },
+                    new { metaData },
+                    Add("2024-03-05"));
+
+                using var df = LazyFrame.ScanDelta(table).Collect();
+                Assert.Equal(new[] { "id", "extra", "d", "ts", "ntz" }, df.Columns);
+                Assert.Equal(DataTypeKind.Float64, df.Schema["extra"].Kind);
+                Assert.Null(df.GetValue<double?>(0, "extra"));
+                Assert.Equal(new DateOnly(2024, 3, 5), df.GetValue<DateOnly>(0, "d"));
+                Assert.Equal(new DateTime(2024, 3, 5, 10, 20, 30).AddTicks(1_234_560), df.GetValue<DateTime>(0, "ts"));
+                Assert.Equal(new DateTime(2024, 3, 5, 10, 20, 30), df.GetValue<DateTime>(1, "ntz"));
+
+                // 不符合 Delta 格式的日期分区值直接报错
+                WriteCommit(1,
+                    new { remove = new { path = "part-0.parquet", dataChange = true } },
+                    Add("05/03/2024"));
+                Assert.ThrowsAny<Exception>(() => LazyFrame.ScanDelta(table));
+            }
+            finally
+            {
+                Directory.Delete(table, true);
+            }
+        }
+
+        [Fact]
+        public void Test_Avro_RoundTrip_Projection_Compression()
+        {
+            using var s1 = new Series("id", [1, 2, 3]);
+            using var s2 = new Series("name", ["a", "b", "c"]);
+            using var s3 = new Series("score", [1.5, 2.5, 3.5]);
+            using var df = new DataFrame(s1, s2, s3);
+
+            foreach (var compression in new[] { AvroCompression.None, AvroCompression.Deflate, AvroCompression.Snappy })
+            {
+                using var f = new DisposableFile(".avro");
+                df.WriteAvro(f.Path, compression);
+
+                using var full = DataFrame.ReadAvro(f.Path);
+                Assert.Equal(3, full.Height);
+                Assert.Equal(3, full.Width);
+                Assert.Equal("b", full.GetValue<string>(1, "name"));
+                Assert.Equal(3.5, full.GetValue<double>(2, "score"));
+
+                // 投影 + 行数限制
+                using var part = DataFrame.ReadAvro(f.Path, columns: ["score", "id"], nRows: 2);
+                Assert.Equal(2, part.Height);
+                Assert.Equal(2, part.Width);
+                Assert.Equal(2, part.GetValue<int>(1, "id"));
+            }
+        }
+
+        // calamine 只能读，这里手工拼一个最小的 xlsx (只有一个 sheet，字符串用 inlineStr)
+        private static void WriteMinimalXlsx(string path, string sheetData)
+        {
+            var parts = new Dictionary<string, string>
+            {
+                ["[Content_Types].xml"] =
+                    "<?xml version=\"1.0\" encoding=\"UTF-8\"?><Types xmlns=\"http://schemas.openxmlformats.org/package/2006/content-types\">" +
+                    "<Default Extension=\"rels\" ContentType=\"application/vnd.openxmlformats-package.relationships+xml\"/>" +
+                    "<Default Extension=\"xml\" ContentType=\"application/xml\"/>" +
+                    "<Override PartName=\"/xl/workbook.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml\"/>" +
+                    "<Override PartName=\"/xl/worksheets/sheet1.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml\"/></Types>",
+                ["_rels/.rels"] =
+                    "<?xml version=\"1.0\" encoding=\"UTF-8\"?><Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">" +
+                    "<Relationship Id=\"rId1\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument\" Target=\"xl/workbook.xml\"/></Relationships>",
+                ["xl/workbook.xml"] =
+                    "<?xml version=\"1.0\" encoding=\"UTF-8\"?><workbook xmlns=\"http://schemas.openxmlformats.org/spreadsheetml/2006/main\" " +
+                    "xmlns:r=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships\"><sheets><sheet name=\"Data\" sheetId=\"1\" r:id=\"rId1\"/></sheets></workbook>",
+                ["xl/_rels/workbook.xml.rels"] =
+                    "<?xml version=\"1.0\" encoding=\"UTF-8\"?><Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">" +
+                    "<Relationship Id=\"rId1\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet\" Target=\"worksheets/sheet1.xml\"/></Relationships>",
+                ["xl/worksheets/sheet1.xml"] =
+                    "<?xml version=\"1.0\" encoding=\"UTF-8\"?><worksheet xmlns=\"http://schemas.openxmlformats.org/spreadsheetml/2006/main\"><sheetData>" +
+                    sheetData + "</sheetData></worksheet>",
+            };
+
+            using var zip = System.IO.Compression.ZipFile.Open(path, System.IO.Compression.ZipArchiveMode.Create);
+            foreach (var (name, xml) in parts)
+            {
+                using var writer = new StreamWriter(zip.CreateEntry(name).Open());
+                writer.Write(xml);
+            }
+        }
+
+        [Fact]
+        public void Test_ReadExcel_Sheet_Range_And_Schema()
+        {
+            using var f = new DisposableFile(".xlsx");
+            WriteMinimalXlsx(f.Path,
+                "<row r=\"1\"><c r=\"A1\" t=\"inlineStr\"><is><t>name</t></is></c><c r=\"B1\" t=\"inlineStr\"><is><t>qty</t></is></c>" +
+                "<c r=\"C1\" t=\"inlineStr\"><is><t>price</t></is></c><c r=\"D1\" t=\"inlineStr\"><is><t>ok</t></is></c></row>" +
+                "<row r=\"2\"><c r=\"A2\" t=\"inlineStr\"><is><t>apple</t></is></c><c r=\"B2\"><v>3</v></c><c r=\"C2\"><v>1.5</v></c><c r=\"D2\" t=\"b\"><v>1</v></c></row>" +
+                "<row r=\"3\"><c r=\"A3\" t=\"inlineStr\"><is><t>pear</t></is></c><c r=\"B3\"><v>5</v></c><c r=\"C3\"><v>2</v></c><c r=\"D3\" t=\"b\"><v>0</v></c></row>");
+
+            // 1. 整张表 (按名字选 sheet)
+            using var df = DataFrame.ReadExcel(f.Path, sheetName: "Data");
+            Assert.Equal(2, df.Height);
+            Assert.Equal(4, df.Width);
+            Assert.Equal("pear", df.GetValue<string>(1, "name"));
+            Assert.Equal(1.5, df.GetValue<double>(0, "price"));
+            Assert.True(df.GetValue<bool>(0, "ok"));
+
+            // 2. 区域 + Schema 覆盖 (绝对引用也可以)
+            using var part = DataFrame.ReadExcel(
+                f.Path,
+                cellRange: "$B$1:C3",
+                schema: new Dictionary<string, DataType> { ["qty"] = DataType.Int64 });
+            Assert.Equal(2, part.Width);
+            Assert.Equal(5L, part.GetValue<long>(1, "qty"));
+
+            // 3. 超出 XFD 的列 / 会溢出的列名 -> 报错，而不是让宿主进程崩溃
+            Assert.ThrowsAny<Exception>(() => DataFrame.ReadExcel(f.Path, cellRange: "XFE1:XFE2"));
+            Assert.ThrowsAny<Exception>(() => DataFrame.ReadExcel(f.Path, cellRange: "AAAAAAAAAAAAAAA1:B2"));
+        }
+
+        [Fact]
+        public void Test_Sqlite_RoundTrip_Modes()
+        {
+            using var db = new DisposableFile(".db");
+
+            using var id = new Series("id", [1, 2, 3]);
+            using var name = new Series("name", ["a", null, "c"]);
+            using var score = new Series("score", [1.5, 2.5, 3.5]);
+            using var flag = new Series("flag", [true, false, true]);
+            using var ts = new Series("ts", [new DateTime(2024, 1, 1, 8, 30, 0), new DateTime(2024, 1, 2), new DateTime(2024, 1, 3)]);
+            using var df = new DataFrame(id, name, score, flag, ts);
+
+            // 1. 新建 + 读回，声明类型决定列类型
+            df.WriteSqlite(db.Path, "items");
+            using var read = DataFrame.ReadSqlite(db.Path, "SELECT * FROM items ORDER BY id");
+            Assert.Equal(3, read.Height);
+            Assert.Equal(DataTypeKind.Int64, read.Schema["id"].Kind);
+            Assert.Equal(DataTypeKind.Boolean, read.Schema["flag"].Kind);
+            Assert.Equal(DataTypeKind.Datetime, read.Schema["ts"].Kind);
+            Assert.Null(read.GetValue<string>(1, "name"));
+            Assert.Equal(2.5, read.GetValue<double>(1, "score"));
+            Assert.Equal(new DateTime(2024, 1, 1, 8, 30, 0), read.GetValue<DateTime>(0, "ts"));
+
+            // 2. Create 模式下表已存在 -> 报错; Append 追加; Replace 覆盖
+            Assert.ThrowsAny<Exception>(() => df.WriteSqlite(db.Path, "items"));
+            df.WriteSqlite(db.Path, "items", SqliteWriteMode.Append);
+            using var appended = DataFrame.ReadSqlite(db.Path, "SELECT COUNT(*) AS n FROM items");
+            Assert.Equal(6L, appended.GetValue<long>(0, "n"));
+
+            df.WriteSqlite(db.Path, "items", SqliteWriteMode.Replace);
+            using var replaced = DataFrame.ReadSqlite(db.Path, "SELECT COUNT(*) AS n FROM items");
+            Assert.Equal(3L, replaced.GetValue<long>(0, "n"));
+
+            // 3. 没有列的 DataFrame 直接报错，不会生成 CREATE TABLE x ()
+            using var empty = df.Drop(["id", "name", "score", "flag", "ts"]);
+            Assert.Equal(0, empty.Width);
+            Assert.ThrowsAny<Exception>(() => empty.WriteSqlite(db.Path, "empty"));
+        }
+
+        [Fact]
+        public void Test_Sqlite_Integer_Timestamp_Overflow_Falls_Back()
+        {
+            using var db = new DisposableFile(".db");
+
+            // 先建一张 TIMESTAMP 列的表，再追加整数 (SQLite 按 unix 秒存储)
+            using var dates = new Series("ts", [new DateTime(2024, 1, 1)]);
+            using var dfDates = new DataFrame(dates);
+            dfDates.WriteSqlite(db.Path, "events");
+
+            using var seconds = new Series("ts", [1_700_000_000L, long.MaxValue]);
+            using var dfSeconds = new DataFrame(seconds);
+            dfSeconds.WriteSqlite(db.Path, "events", SqliteWriteMode.Append);
+
+            // 正常范围的整数转成 Datetime
+            using var ok = DataFrame.ReadSqlite(db.Path, "SELECT ts FROM events WHERE ts = 1700000000");
+            Assert.Equal(DataTypeKind.Datetime, ok.Schema["ts"].Kind);
+            Assert.Equal(DateTimeOffset.FromUnixTimeSeconds(1_700_000_000).UtcDateTime, ok.GetValue<DateTime>(0, "ts"));
+
+            // 乘到微秒会溢出 -> 回退为原始整数，而不是让宿主进程崩溃
+            using var overflow = DataFrame.ReadSqlite(db.Path, "SELECT ts FROM events WHERE typeof(ts) = 'integer'");
+            Assert.Equal(DataTypeKind.Int64, overflow.Schema["ts"].Kind);
+            Assert.Equal(long.MaxValue, overflow.GetValue<long>(1, "ts"));
+        }
     }
-}
\ No newline at end of file
+}
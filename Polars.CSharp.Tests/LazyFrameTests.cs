@@ -1,3 +1,5 @@
+using Apache.Arrow;
+using Apache.Arrow.Memory;
 using static Polars.CSharp.Polars;
 
 namespace Polars.CSharp.Tests;
@@ -396,4 +398,98 @@ HR,50";
         Assert.Equal("b", res.GetValue<string>(1, "expanded"));
         Assert.Equal("c", res.GetValue<string>(2, "expanded"));
     }
-}
\ No newline at end of file
+    [Fact]
+    public void Test_LazyFrame_SinkBatches()
+    {
+        using var s = new Series("x", Enumerable.Range(0, 1000).ToArray());
+        using var df = new DataFrame(s);
+        using var lf = df.Lazy();
+        using var filtered = lf.Filter(Col("x") >= Lit(500));
+
+        long rows = 0;
+        filtered.SinkBatches(batch =>
+        {
+            using (batch) rows += batch.Height;
+            return true;
+        });
+        Assert.Equal(500, rows);
+
+        // Arrow 版本
+        long arrowRows = 0;
+        filtered.SinkBatchesArrow(batch =>
+        {
+            using (batch) arrowRows += batch.Length;
+            return true;
+        });
+        Assert.Equal(500, arrowRows);
+
+        // 返回 false 提前停止，不算错误
+        int calls = 0;
+        filtered.SinkBatches(batch =>
+        {
+            batch.Dispose();
+            calls++;
+            return false;
+        });
+        Assert.Equal(1, calls);
+
+        // 回调里的异常在 sink 返回后重新抛出
+        Assert.Throws<InvalidOperationException>(() =>
+            filtered.SinkBatches(batch =>
+            {
+                batch.Dispose();
+                throw new InvalidOperationException("boom");
+            })
+        );
+
+        // 原 LazyFrame 仍然可用
+        using var all = filtered.Collect();
+        Assert.Equal(500, all.Height);
+    }
+    [Fact]
+    public void Test_LazyFrame_ScanCallback()
+    {
+        var schema = new Dictionary<string, DataType>
+        {
+            ["id"] = DataType.Int32,
+            ["value"] = DataType.Float64,
+        };
+        int[][] chunks = [[1, 2, 3], [4, 5]];
+        var requests = new List<ScanRequest>();
+
+        RecordBatch? Next(ScanRequest req)
+        {
+            requests.Add(req);
+            if (req.BatchIndex >= chunks.Length) return null;
+            var ids = chunks[req.BatchIndex];
+            return new RecordBatch.Builder(new NativeMemoryAllocator())
+                .Append("id", false, col => col.Int32(a => a.AppendRange(ids)))
+                .Append("value", false, col => col.Double(a => a.AppendRange(ids.Select(i => i * 1.5))))
+                .Build();
+        }
+
+        using var lf = LazyFrame.ScanCallback(schema, Next);
+        using var df = lf.Collect();
+        Assert.Equal(5, df.Height);
+        Assert.Equal(7.5, df.GetValue<double>(4, "value"));
+
+        // 下推提示只是提示: 这里忽略投影，仍然返回全部列
+        requests.Clear();
+        using var res = lf.Filter(Col("id") > Lit(2)).Select(Col("value")).Collect();
+        Assert.Equal(3, res.Height);
+        Assert.Equal(new[] { "value" }, res.Columns);
+        Assert.Equal(0, requests[0].BatchIndex);
+        Assert.Contains("value", requests[0].Columns!);
+
+        // batch 与声明的 schema 不一致时报错
+        using var badLf = LazyFrame.ScanCallback(schema, req =>
+            req.BatchIndex > 0 ? null :
+            new RecordBatch.Builder(new NativeMemoryAllocator())
+                .Append("id", false, col => col.Int64(a => a.AppendRange([1L, 2L])))
+                .Append("value", false, col => col.Double(a => a.AppendRange([1.0, 2.0])))
+                .Build()
+        );
+        var ex = Assert.ThrowsAny<Exception>(() => badLf.Collect());
+        Assert.Contains("declared schema", ex.Message);
+    }
+}
@@ -0,0 +1,54 @@
+using Polars.Native;
+
+namespace Polars.CSharp;
+
+/// <summary>
+/// Object store settings for lazy scans and sinks on cloud URIs (e.g. s3://bucket/key.parquet).
+/// The options are copied into each query, so one instance can be reused and disposed afterwards.
+/// </summary>
+public class CloudOptions : IDisposable
+{
+    internal CloudOptionsHandle Handle { get; }
+
+    private CloudOptions(CloudOptionsHandle handle)
+    {
+        Handle = handle;
+    }
+
+    /// <summary>
+    /// Options for AWS S3 or an S3-compatible store such as MinIO.
+    /// Arguments left null fall back to the AWS environment variables / defaults.
+    /// </summary>
+    /// <param name="endpoint">Custom endpoint, e.g. "http://localhost:9000" for MinIO.</param>
+    /// <param name="region">Bucket region.</param>
+    /// <param name="accessKeyId">Access key id.</param>
+    /// <param name="secretAccessKey">Secret access key.</param>
+    /// <param name="sessionToken">Session token for temporary credentials.</param>
+    /// <param name="pathStyle">Use path-style requests (http://host/bucket/key), usually required by MinIO.</param>
+    /// <param name="allowHttp">Allow a non-TLS endpoint.</param>
+    /// <param name="maxRetries">Number of retries for failed requests.</param>
+    /// <returns></returns>
+    public static CloudOptions S3(
+        string? endpoint = null,
+        string? region = null,
+        string? accessKeyId = null,
+        string? secretAccessKey = null,
+        string? sessionToken = null,
+        bool pathStyle = false,
+        bool allowHttp = false,
+        ulong maxRetries = 2)
+    {
+        return new CloudOptions(PolarsWrapper.NewS3CloudOptions(
+            endpoint, region, accessKeyId, secretAccessKey, sessionToken,
+            pathStyle, allowHttp, maxRetries
+        ));
+    }
+
+    /// <summary>
+    /// Release the native options.
+    /// </summary>
+    public void Dispose()
+    {
+        Handle.Dispose();
+    }
+}
@@ -128,6 +128,58 @@ public class DataFrame : IDisposable
         return new DataFrame(PolarsWrapper.ReadIpc(path));
     }
 
+    /// <summary>
+    /// Read Avro File
+    /// </summary>
+    /// <param name="path">Path to the Avro file.</param>
+    /// <param name="columns">Optional subset of columns to read.</param>
+    /// <param name="nRows">Optional maximum number of rows to read.</param>
+    /// <returns></returns>
+    public static DataFrame ReadAvro(string path, string[]? columns = null, ulong? nRows = null)
+    {
+        return new DataFrame(PolarsWrapper.ReadAvro(path, columns, nRows));
+    }
+    /// <summary>
+    /// Reads a sheet of an Excel (xlsx/xlsm/xlsb/xls) or ODS spreadsheet into a DataFrame.
+    /// </summary>
+    /// <param name="path">Path to the spreadsheet.</param>
+    /// <param name="sheetName">Sheet to read. When null, <paramref name="sheetIndex"/> is used.</param>
+    /// <param name="sheetIndex">Zero-based sheet index, used when no sheet name is given.</param>
+    /// <param name="hasHeader">Whether the first row (after skipped rows) holds column names.</param>
+    /// <param name="skipRows">Number of rows to skip before the header.</param>
+    /// <param name="cellRange">Optional cell range such as "B2:F100".</param>
+    /// <param name="schema">Optional per-column type overrides.</param>
+    /// <param name="tryParseDates">Whether date cells become Date/Datetime columns instead of serial numbers.</param>
+    /// <returns>A new DataFrame.</returns>
+    public static DataFrame ReadExcel(
+        string path,
+        string? sheetName = null,
+        ulong sheetIndex = 0,
+        bool hasHeader = true,
+        ulong skipRows = 0,
+        string? cellRange = null,
+        Dictionary<string, DataType>? schema = null,
+        bool tryParseDates = true)
+    {
+        var schemaHandles = schema?.ToDictionary(
+            kv => kv.Key,
+            kv => kv.Value.Handle
+        );
+
+        return new DataFrame(PolarsWrapper.ReadExcel(
+            path, sheetName, sheetIndex, hasHeader, skipRows, cellRange, schemaHandles, tryParseDates));
+    }
+    /// <summary>
+    /// Runs a query against a SQLite database file and returns the result.
+    /// Column types follow the declared SQLite types, falling back to the stored values.
+    /// </summary>
+    /// <param name="dbPath">Path to the SQLite database file.</param>
+    /// <param name="query">SQL query to execute.</param>
+    /// <returns>A new DataFrame.</returns>
+    public static DataFrame ReadSqlite(string dbPath, string query)
+    {
+        return new DataFrame(PolarsWrapper.ReadSqlite(dbPath, query));
+    }
     /// <summary>
     /// Create DataFrame from Arrow RecordBatch
     /// </summary>
@@ -186,6 +238,33 @@ public class DataFrame : IDisposable
     /// Return DataFrame Columns' Name
     /// </summary>
     public string[] Columns => PolarsWrapper.GetColumnNames(Handle); //
+    /// <summary>
+    /// Estimated heap size of the DataFrame in bytes.
+    /// This is an upper bound: buffers shared between columns or frames are counted more than once.
+    /// Useful for <see cref="GC.AddMemoryPressure(long)"/>.
+    /// </summary>
+    public long EstimatedSize => PolarsWrapper.DataFrameEstimatedSize(Handle);
+    /// <summary>
+    /// Maximum number of chunks over all columns.
+    /// A high value (e.g. after many concats) means <see cref="Rechunk"/> may speed up later operations.
+    /// </summary>
+    public long NChunks => PolarsWrapper.DataFrameNChunks(Handle);
+    /// <summary>
+    /// Merge the chunks of every column into a single contiguous chunk (in place).
+    /// </summary>
+    public DataFrame Rechunk()
+    {
+        PolarsWrapper.DataFrameRechunk(Handle);
+        return this;
+    }
+    /// <summary>
+    /// Release unused capacity of every column (in place).
+    /// </summary>
+    public DataFrame ShrinkToFit()
+    {
+        PolarsWrapper.DataFrameShrinkToFit(Handle);
+        return this;
+    }
 
     // ==========================================
     // Scalar Access (Direct)
@@ -226,6 +305,44 @@ public class DataFrame : IDisposable
         }
     }
 
+    /// <summary>
+    /// Get all values of one row in a single native call.
+    /// Values are typed (long, ulong, double, decimal, string, bool, byte[], DateOnly, DateTime, TimeSpan, TimeOnly),
+    /// nested types (List, Array, Struct) are returned as <see cref="System.Text.Json.JsonElement"/>.
+    /// Decimals beyond the range of <see cref="decimal"/> are returned as their string representation.
+    /// Timezone-aware datetimes are returned in UTC (<see cref="DateTimeKind.Utc"/>), naive ones as <see cref="DateTimeKind.Unspecified"/>.
+    /// </summary>
+    /// <param name="index">Row index, negative values count from the end</param>
+    /// <returns></returns>
+    public object?[] Row(long index)
+    {
+        //
+        return PolarsWrapper.GetRow(Handle, index);
+    }
+    /// <summary>
+    /// Get a range of rows in a single native call, row-major. See <see cref="Row(long)"/> for the value types.
+    /// Much faster than reading cell by cell, e.g. for UI grids.
+    /// </summary>
+    /// <param name="offset">First row, negative values count from the end</param>
+    /// <param name="length">Number of rows, defaults to all remaining rows</param>
+    /// <returns></returns>
+    public object?[][] Rows(long offset = 0, ulong? length = null)
+    {
+        //
+        return PolarsWrapper.GetRows(Handle, offset, length ?? (ulong)Height);
+    }
+    /// <summary>
+    /// Get a range of rows as JSON records: [{"col": value, ...}, ...]
+    /// </summary>
+    /// <param name="offset">First row, negative values count from the end</param>
+    /// <param name="length">Number of rows, defaults to all remaining rows</param>
+    /// <returns></returns>
+    public string RowsToJson(long offset = 0, ulong? length = null)
+    {
+        //
+        return PolarsWrapper.GetRowsJson(Handle, offset, length ?? (ulong)Height);
+    }
+
     // ==========================================
     // DataFrame Operations
     // ==========================================
@@ -277,6 +394,21 @@ public class DataFrame : IDisposable
         return new DataFrame(PolarsWrapper.Sort(Handle, h, descending));
     }
     /// <summary>
+    /// Sort the DataFrame by multiple expressions.
+    /// </summary>
+    /// <param name="by">Sort keys, in priority order</param>
+    /// <param name="descending">One flag per key, or a single flag for all keys</param>
+    /// <param name="nullsLast">Place nulls at the end</param>
+    /// <param name="maintainOrder">Keep the original order of equal rows (stable sort)</param>
+    /// <param name="multithreaded">Sort using multiple threads</param>
+    /// <returns></returns>
+    public DataFrame Sort(Expr[] by, bool[] descending, bool nullsLast = false, bool maintainOrder = false, bool multithreaded = true)
+    {
+        var handles = by.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        //
+        return new DataFrame(PolarsWrapper.Sort(Handle, handles, descending, nullsLast, maintainOrder, multithreaded));
+    }
+    /// <summary>
     /// Return head lines from a DataFrame
     /// </summary>
     /// <param name="n"></param>
@@ -297,6 +429,62 @@ public class DataFrame : IDisposable
         return new DataFrame(PolarsWrapper.Tail(Handle, (uint)n));
     }
     /// <summary>
+    /// Get a slice of rows. A negative offset counts from the end.
+    /// </summary>
+    /// <param name="offset"></param>
+    /// <param name="length"></param>
+    /// <returns></returns>
+    public DataFrame Slice(long offset, ulong length)
+    {
+        return new DataFrame(PolarsWrapper.Slice(Handle, offset, length));
+    }
+    /// <summary>
+    /// Reverse the order of the rows.
+    /// </summary>
+    /// <returns></returns>
+    public DataFrame Reverse()
+    {
+        return new DataFrame(PolarsWrapper.Reverse(Handle));
+    }
+    /// <summary>
+    /// Shift rows by n positions (negative shifts up), filling with nulls.
+    /// </summary>
+    /// <param name="n"></param>
+    /// <returns></returns>
+    public DataFrame Shift(long n = 1)
+    {
+        return new DataFrame(PolarsWrapper.Shift(Handle, n));
+    }
+    /// <summary>
+    /// Insert a UInt32 row index column at position 0.
+    /// </summary>
+    /// <param name="name"></param>
+    /// <param name="offset">Value of the first index</param>
+    /// <returns></returns>
+    public DataFrame WithRowIndex(string name = "index", uint offset = 0)
+    {
+        return new DataFrame(PolarsWrapper.WithRowIndex(Handle, name, offset));
+    }
+    /// <summary>
+    /// Take rows by position.
+    /// </summary>
+    /// <param name="indices"></param>
+    /// <returns></returns>
+    public DataFrame Gather(params uint[] indices)
+    {
+        return new DataFrame(PolarsWrapper.Gather(Handle, indices));
+    }
+    /// <summary>
+    /// Take every nth row, starting at offset.
+    /// </summary>
+    /// <param name="n"></param>
+    /// <param name="offset"></param>
+    /// <returns></returns>
+    public DataFrame GatherEvery(ulong n, ulong offset = 0)
+    {
+        return new DataFrame(PolarsWrapper.GatherEvery(Handle, n, offset));
+    }
+    /// <summary>
     /// Explode a list or structure in a Column
     /// </summary>
     /// <param name="exprs"></param>
@@ -323,6 +511,65 @@ public class DataFrame : IDisposable
         return new DataFrame(PolarsWrapper.Drop(Handle, columnName));
     }
 
+    /// <summary>
+    /// Drop multiple columns by name.
+    /// </summary>
+    /// <param name="columnNames">Columns to drop.</param>
+    /// <param name="strict">If true, throw when any of the columns does not exist.</param>
+    public DataFrame Drop(string[] columnNames, bool strict = true)
+    {
+        return new DataFrame(PolarsWrapper.DropMany(Handle, columnNames, strict));
+    }
+
+    /// <summary>
+    /// Insert a Series as a new column at the given index (in place).
+    /// The Series name must not already exist and its length must match the DataFrame height.
+    /// </summary>
+    public DataFrame InsertColumn(int index, Series series)
+    {
+        PolarsWrapper.InsertColumn(Handle, index, series.Handle);
+        return this;
+    }
+
+    /// <summary>
+    /// Replace an existing column with a Series (in place).
+    /// The column keeps its position and name; the Series length must match the DataFrame height.
+    /// </summary>
+    public DataFrame ReplaceColumn(string columnName, Series series)
+    {
+        PolarsWrapper.ReplaceColumn(Handle, columnName, series.Handle);
+        return this;
+    }
+
+    /// <summary>
+    /// Append Series as new columns at the end (in place).
+    /// </summary>
+    public DataFrame HStack(params Series[] columns)
+    {
+        PolarsWrapper.HStack(Handle, columns.Select(s => s.Handle).ToArray());
+        return this;
+    }
+
+    /// <summary>
+    /// Append the rows of another DataFrame (in place).
+    /// The chunks of <paramref name="other"/> are appended without copying; call <see cref="Rechunk"/> after many vstacks.
+    /// </summary>
+    public DataFrame VStack(DataFrame other)
+    {
+        PolarsWrapper.VStack(Handle, other.Handle, extend: false);
+        return this;
+    }
+
+    /// <summary>
+    /// Append the rows of another DataFrame by copying them into this DataFrame's memory (in place).
+    /// Prefer this over <see cref="VStack"/> when appending many small frames.
+    /// </summary>
+    public DataFrame Extend(DataFrame other)
+    {
+        PolarsWrapper.VStack(Handle, other.Handle, extend: true);
+        return this;
+    }
+
     /// <summary>
     /// Rename a column.
     /// </summary>
@@ -340,6 +587,56 @@ public class DataFrame : IDisposable
         // Wrapper 处理了 subset 为 null 的情况
         return new DataFrame(PolarsWrapper.DropNulls(Handle, subset));
     }
+    /// <summary>
+    /// Fill null values with an expression.
+    /// </summary>
+    /// <param name="value">Fill value</param>
+    /// <param name="columns">Columns to fill (empty = all columns)</param>
+    /// <returns></returns>
+    public DataFrame FillNull(Expr value, params string[] columns)
+    {
+        var v = PolarsWrapper.CloneExpr(value.Handle);
+        //
+        return new DataFrame(PolarsWrapper.FillNull(Handle, ToSelector(columns), v));
+    }
+    /// <summary>
+    /// Fill null values with a strategy.
+    /// </summary>
+    /// <param name="strategy">Fill strategy</param>
+    /// <param name="limit">Max consecutive nulls to fill for Forward/Backward (0 = unlimited)</param>
+    /// <param name="columns">Columns to fill (empty = all columns)</param>
+    /// <returns></returns>
+    public DataFrame FillNull(FillNullStrategy strategy, uint limit = 0, params string[] columns)
+    {
+        //
+        return new DataFrame(PolarsWrapper.FillNull(Handle, ToSelector(columns), strategy.ToNative(), limit));
+    }
+    /// <summary>
+    /// Fill floating point NaN values with an expression.
+    /// </summary>
+    /// <param name="value">Fill value</param>
+    /// <param name="columns">Columns to fill (empty = all float columns)</param>
+    /// <returns></returns>
+    public DataFrame FillNan(Expr value, params string[] columns)
+    {
+        var v = PolarsWrapper.CloneExpr(value.Handle);
+        //
+        return new DataFrame(PolarsWrapper.FillNan(Handle, ToSelector(columns), v));
+    }
+    /// <summary>
+    /// Interpolate intermediate null values of numeric columns.
+    /// </summary>
+    /// <param name="method">Interpolation method</param>
+    /// <param name="columns">Columns to interpolate (empty = all numeric columns)</param>
+    /// <returns></returns>
+    public DataFrame Interpolate(InterpolationMethod method = InterpolationMethod.Linear, params string[] columns)
+    {
+        //
+        return new DataFrame(PolarsWrapper.Interpolate(Handle, ToSelector(columns), method.ToNative()));
+    }
+    // 空列表表示所有列
+    private static SelectorHandle? ToSelector(string[] columns)
+        => columns.Length == 0 ? null : PolarsWrapper.SelectorCols(columns);
 
     // ==========================================
     // Sampling
@@ -361,6 +658,60 @@ public class DataFrame : IDisposable
         return new DataFrame(PolarsWrapper.SampleFrac(Handle, fraction, withReplacement, shuffle, seed));
     }
     // ==========================================
+    // Unique / Duplicates
+    // ==========================================
+    /// <summary>
+    /// Drop duplicate rows.
+    /// </summary>
+    /// <param name="subset">Columns to compare (null = all columns)</param>
+    /// <param name="keep">Which of the duplicate rows to keep</param>
+    /// <param name="maintainOrder">Keep the original row order</param>
+    /// <returns></returns>
+    public DataFrame Unique(string[]? subset = null, UniqueKeepStrategy keep = UniqueKeepStrategy.Any, bool maintainOrder = false)
+    {
+        return new DataFrame(PolarsWrapper.Unique(Handle, subset, keep.ToNative(), maintainOrder));
+    }
+    /// <summary>
+    /// Drop duplicate rows, keeping the original row order.
+    /// </summary>
+    /// <param name="subset">Columns to compare (null = all columns)</param>
+    /// <param name="keep">Which of the duplicate rows to keep</param>
+    /// <returns></returns>
+    public DataFrame UniqueStable(string[]? subset = null, UniqueKeepStrategy keep = UniqueKeepStrategy.Any)
+        => Unique(subset, keep, maintainOrder: true);
+    /// <summary>
+    /// Boolean mask of rows that occur more than once.
+    /// </summary>
+    /// <param name="subset">Columns to compare (null = all columns)</param>
+    public Series IsDuplicated(string[]? subset = null)
+    {
+        return new Series(PolarsWrapper.IsDuplicated(Handle, subset));
+    }
+    /// <summary>
+    /// Boolean mask of rows that occur exactly once.
+    /// </summary>
+    /// <param name="subset">Columns to compare (null = all columns)</param>
+    public Series IsUnique(string[]? subset = null)
+    {
+        return new Series(PolarsWrapper.IsUnique(Handle, subset));
+    }
+    /// <summary>
+    /// Number of distinct rows.
+    /// </summary>
+    /// <param name="subset">Columns to compare (null = all columns)</param>
+    public ulong NUnique(string[]? subset = null)
+    {
+        return PolarsWrapper.NUnique(Handle, subset);
+    }
+    /// <summary>
+    /// Hash every row into a UInt64 Series. Hashes are stable for the same seed.
+    /// </summary>
+    /// <param name="seed"></param>
+    public Series HashRows(ulong seed = 0)
+    {
+        return new Series(PolarsWrapper.HashRows(Handle, seed));
+    }
+    // ==========================================
     // Combining DataFrames
     // ==========================================
     /// <summary>
@@ -370,8 +721,22 @@ public class DataFrame : IDisposable
     /// <param name="leftOn"></param>
     /// <param name="rightOn"></param>
     /// <param name="how"></param>
+    /// <param name="suffix">Suffix for clashing right-hand column names, defaults to "_right"</param>
+    /// <param name="validate">Check the uniqueness of the join keys</param>
+    /// <param name="coalesce">Whether to merge the key columns of both sides</param>
+    /// <param name="nullsEqual">Treat null keys as equal to each other</param>
+    /// <param name="maintainOrder">Row order of the result</param>
     /// <returns></returns>
-    public DataFrame Join(DataFrame other, Expr[] leftOn, Expr[] rightOn, JoinType how = JoinType.Inner)
+    public DataFrame Join(
+        DataFrame other,
+        Expr[] leftOn,
+        Expr[] rightOn,
+        JoinType how = JoinType.Inner,
+        string? suffix = null,
+        JoinValidation validate = JoinValidation.ManyToMany,
+        JoinCoalesce coalesce = JoinCoalesce.JoinSpecific,
+        bool nullsEqual = false,
+        JoinMaintainOrder maintainOrder = JoinMaintainOrder.None)
     {
         var lHandles = leftOn.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
         var rHandles = rightOn.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
@@ -382,10 +747,65 @@ public class DataFrame : IDisposable
             other.Handle, 
             lHandles, 
             rHandles, 
-            how.ToNative()
+            how.ToNative(),
+            suffix,
+            validate.ToNative(),
+            coalesce.ToNative(),
+            nullsEqual,
+            maintainOrder.ToNative()
         ));
     }
-    
+
+    /// <summary>
+    /// Perform an As-Of Join (time-series join).
+    /// </summary>
+    /// <param name="other"></param>
+    /// <param name="leftOn"></param>
+    /// <param name="rightOn"></param>
+    /// <param name="tolerance">Integer, float or duration string (e.g. "2h")</param>
+    /// <param name="strategy">"backward", "forward" or "nearest"</param>
+    /// <param name="leftBy">Group keys on the left frame</param>
+    /// <param name="rightBy">Group keys on the right frame (may have different names)</param>
+    /// <param name="allowEq">Allow matches on equal keys</param>
+    /// <param name="checkSortedness">Check that the join keys are sorted</param>
+    /// <returns></returns>
+    public DataFrame JoinAsOf(
+        DataFrame other,
+        Expr leftOn, Expr rightOn,
+        string? tolerance = null,
+        string strategy = "backward",
+        Expr[]? leftBy = null,
+        Expr[]? rightBy = null,
+        bool allowEq = true,
+        bool checkSortedness = true)
+    {
+        var lOn = PolarsWrapper.CloneExpr(leftOn.Handle);
+        var rOn = PolarsWrapper.CloneExpr(rightOn.Handle);
+        var lBy = leftBy?.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        var rBy = rightBy?.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        //
+        return new DataFrame(PolarsWrapper.JoinAsOf(
+            Handle, other.Handle,
+            lOn, rOn,
+            lBy, rBy,
+            strategy,
+            PolarsWrapper.InferAsofTolerance(tolerance), tolerance,
+            allowEq, checkSortedness
+        ));
+    }
+    /// <summary>
+    /// Join with another DataFrame on inequality / range predicates.
+    /// </summary>
+    /// <param name="other"></param>
+    /// <param name="predicates">Predicates referencing columns of both frames</param>
+    /// <param name="suffix">Suffix for clashing right-hand column names</param>
+    /// <returns></returns>
+    public DataFrame JoinWhere(DataFrame other, Expr[] predicates, string suffix = "_right")
+    {
+        var handles = predicates.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        //
+        return new DataFrame(PolarsWrapper.JoinWhere(Handle, other.Handle, handles, suffix));
+    }    
     /// <summary>
     /// Concatenate multiple DataFrames
     /// </summary>
@@ -413,22 +833,141 @@ public class DataFrame : IDisposable
         // 返回一个构建器，不立即执行
         return new GroupByBuilder(this, by);
     }
+    /// <summary>
+    /// Group by keys, optionally keeping groups in the order they first appear.
+    /// </summary>
+    /// <param name="by"></param>
+    /// <param name="maintainOrder">Keep the order of the groups consistent with the input</param>
+    /// <returns></returns>
+    public GroupByBuilder GroupBy(Expr[] by, bool maintainOrder)
+    {
+        return new GroupByBuilder(this, by, maintainOrder);
+    }
+    /// <summary>
+    /// Split the DataFrame into one DataFrame per group.
+    /// </summary>
+    /// <param name="by">Columns to partition by</param>
+    /// <param name="maintainOrder">Keep the partitions in the order the groups first appear</param>
+    /// <param name="includeKey">Keep the key columns in the partitions</param>
+    /// <returns>Keys (row i belongs to Frames[i]) and the partitions</returns>
+    public (DataFrame Keys, DataFrame[] Frames) PartitionBy(string[] by, bool maintainOrder = true, bool includeKey = true)
+    {
+        var (frames, keys) = PolarsWrapper.PartitionBy(Handle, by, maintainOrder, includeKey);
+        //
+        return (new DataFrame(keys), frames.Select(h => new DataFrame(h)).ToArray());
+    }
 
     // ==========================================
     // Pivot / Unpivot
     // ==========================================
     /// <summary>
+    /// Group by time windows over a sorted index column.
+    /// </summary>
+    /// <param name="index">Sorted temporal (or integer) index column</param>
+    /// <param name="every">Window start interval, e.g. "5m" ("2i" for integer index)</param>
+    /// <param name="aggs">Aggregations evaluated per window</param>
+    /// <param name="period">Window length, defaults to every</param>
+    /// <param name="offset">Offset of the window boundaries</param>
+    /// <param name="by">Extra group keys</param>
+    /// <param name="closed">"left", "right", "both" or "none"</param>
+    /// <param name="label">"left", "right" or "datapoint"</param>
+    /// <param name="includeBoundaries">Add _lower_boundary / _upper_boundary columns</param>
+    /// <param name="startBy">"window", "datapoint" or a weekday ("monday" ...)</param>
+    /// <returns></returns>
+    public DataFrame GroupByDynamic(
+        Expr index,
+        string every,
+        Expr[] aggs,
+        string? period = null,
+        string? offset = null,
+        Expr[]? by = null,
+        string closed = "left",
+        string label = "left",
+        bool includeBoundaries = false,
+        string startBy = "window")
+    {
+        var idx = PolarsWrapper.CloneExpr(index.Handle);
+        var byHandles = (by ?? []).Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        var aggHandles = aggs.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        //
+        return new DataFrame(PolarsWrapper.GroupByDynamic(
+            Handle, idx, byHandles,
+            every, period, offset,
+            label, includeBoundaries, closed, startBy,
+            aggHandles
+        ));
+    }
+    /// <summary>
+    /// Rolling group-by: every row gets a look-back window over the index column.
+    /// </summary>
+    /// <param name="index">Sorted temporal (or integer) index column</param>
+    /// <param name="period">Window length, e.g. "2d" ("3i" for integer index)</param>
+    /// <param name="aggs">Aggregations evaluated per window</param>
+    /// <param name="offset">Offset of the window start, defaults to -period</param>
+    /// <param name="by">Extra group keys</param>
+    /// <param name="closed">"left", "right", "both" or "none"</param>
+    /// <returns></returns>
+    public DataFrame Rolling(
+        Expr index,
+        string period,
+        Expr[] aggs,
+        string? offset = null,
+        Expr[]? by = null,
+        string closed = "right")
+    {
+        var idx = PolarsWrapper.CloneExpr(index.Handle);
+        var byHandles = (by ?? []).Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        var aggHandles = aggs.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        //
+        return new DataFrame(PolarsWrapper.Rolling(
+            Handle, idx, byHandles,
+            period, offset, closed,
+            aggHandles
+        ));
+    }
+    /// <summary>
     /// Pivot the DataFrame from long to wide format.
+    /// Cells without any rows are null, for Count/Len as well.
     /// </summary>
-    /// <param name="index"></param>
-    /// <param name="columns"></param>
-    /// <param name="values"></param>
+    /// <param name="index">Rows of the result. When empty, all columns not in columns/values are used</param>
+    /// <param name="columns">Columns whose values become the new column names</param>
+    /// <param name="values">Value columns. When empty, all columns not in index/columns are used</param>
     /// <param name="agg"></param>
+    /// <param name="sortColumns">Sort the generated columns by name</param>
+    /// <param name="separator">Used in "{value}{separator}{column}" names when there are multiple value columns, defaults to "_"</param>
+    /// <param name="maintainOrder">Keep the rows in order of first appearance</param>
     /// <returns></returns>
-    public DataFrame Pivot(string[] index, string[] columns, string[] values, PivotAgg agg = PivotAgg.First)
+    public DataFrame Pivot(
+        string[] index, string[] columns, string[] values, PivotAgg agg = PivotAgg.First,
+        bool sortColumns = false, string? separator = null, bool maintainOrder = false)
     {
         //
-        return new DataFrame(PolarsWrapper.Pivot(Handle, index, columns, values, agg.ToNative()));
+        return new DataFrame(PolarsWrapper.Pivot(
+            Handle, index, columns, values, agg.ToNative(), null,
+            sortColumns, separator, maintainOrder));
+    }
+    /// <summary>
+    /// Pivot the DataFrame from long to wide format with a custom aggregation.
+    /// The aggregation refers to the values of each cell through <see cref="Polars.Element"/>,
+    /// e.g. <c>Element().Sum() * 2</c>. Cells without any rows are null.
+    /// </summary>
+    /// <param name="index">Rows of the result. When empty, all columns not in columns/values are used</param>
+    /// <param name="columns">Columns whose values become the new column names</param>
+    /// <param name="values">Value columns. When empty, all columns not in index/columns are used</param>
+    /// <param name="agg">Aggregation expression</param>
+    /// <param name="sortColumns">Sort the generated columns by name</param>
+    /// <param name="separator">Used in "{value}{separator}{column}" names when there are multiple value columns, defaults to "_"</param>
+    /// <param name="maintainOrder">Keep the rows in order of first appearance</param>
+    /// <returns></returns>
+    public DataFrame Pivot(
+        string[] index, string[] columns, string[] values, Expr agg,
+        bool sortColumns = false, string? separator = null, bool maintainOrder = false)
+    {
+        var aggHandle = PolarsWrapper.CloneExpr(agg.Handle);
+        //
+        return new DataFrame(PolarsWrapper.Pivot(
+            Handle, index, columns, values, PlPivotAgg.First, aggHandle,
+            sortColumns, separator, maintainOrder));
     }
     /// <summary>
     /// Unpivot (Melt) the DataFrame from wide to long format.
@@ -453,6 +992,46 @@ public class DataFrame : IDisposable
     /// <returns></returns>
     public DataFrame Melt(string[] index, string[] on, string variableName = "variable", string valueName = "value") 
         => Unpivot(index, on, variableName, valueName);
+    /// <summary>
+    /// Transpose the DataFrame, turning rows into columns.
+    /// This is an expensive operation.
+    /// </summary>
+    /// <param name="keepNamesAs">If set, the original column names are kept in a column with this name</param>
+    /// <param name="headerColumn">A string column whose values become the new column names</param>
+    /// <param name="columnNames">New column names, used when headerColumn is not set. Defaults to column_0, column_1, ...</param>
+    /// <returns></returns>
+    public DataFrame Transpose(string? keepNamesAs = null, string? headerColumn = null, string[]? columnNames = null)
+    {
+        //
+        return new DataFrame(PolarsWrapper.Transpose(Handle, keepNamesAs, headerColumn, columnNames));
+    }
+    /// <summary>
+    /// One-hot encode columns into indicator columns named "{column}{separator}{value}".
+    /// </summary>
+    /// <param name="columns">Columns to encode, all columns when null or empty</param>
+    /// <param name="separator">Separator between column name and value, defaults to "_"</param>
+    /// <param name="dropFirst">Drop the first indicator column of every encoded column</param>
+    /// <param name="dropNulls">Do not create an indicator column for null values</param>
+    /// <returns></returns>
+    public DataFrame ToDummies(string[]? columns = null, string? separator = null, bool dropFirst = false, bool dropNulls = false)
+    {
+        //
+        return new DataFrame(PolarsWrapper.ToDummies(Handle, columns, separator, dropFirst, dropNulls));
+    }
+    /// <summary>
+    /// Upsample the DataFrame at a regular interval. Missing rows are inserted with null values.
+    /// The time column must be sorted (within every group when <paramref name="by"/> is given).
+    /// </summary>
+    /// <param name="timeColumn">Date/Datetime (or integer) column to upsample on</param>
+    /// <param name="every">Interval, e.g. "1d", "30m", "1mo"</param>
+    /// <param name="by">Group keys, upsampling is done per group and inserted rows carry their group's keys</param>
+    /// <param name="maintainOrder">Keep the order of the groups</param>
+    /// <returns></returns>
+    public DataFrame Upsample(string timeColumn, string every, string[]? by = null, bool maintainOrder = true)
+    {
+        //
+        return new DataFrame(PolarsWrapper.Upsample(Handle, timeColumn, every, by, maintainOrder));
+    }
 
     // ==========================================
     // IO Write
@@ -484,6 +1063,25 @@ public class DataFrame : IDisposable
         PolarsWrapper.WriteIpc(Handle, path);
     }
     /// <summary>
+    /// Write DataFrame to Avro File
+    /// </summary>
+    /// <param name="path"></param>
+    /// <param name="compression">Compression codec, defaults to none.</param>
+    public void WriteAvro(string path, AvroCompression compression = AvroCompression.None)
+    {
+        PolarsWrapper.WriteAvro(Handle, path, compression.ToNative());
+    }
+    /// <summary>
+    /// Write DataFrame to a table in a SQLite database file
+    /// </summary>
+    /// <param name="dbPath">Path to the SQLite database file, created if missing.</param>
+    /// <param name="table">Target table name.</param>
+    /// <param name="mode">Whether to create, append to or replace the table.</param>
+    public void WriteSqlite(string dbPath, string table, SqliteWriteMode mode = SqliteWriteMode.Create)
+    {
+        PolarsWrapper.WriteSqlite(Handle, dbPath, table, mode.ToNative());
+    }
+    /// <summary>
     /// Write DataFrame to JSON File
     /// </summary>
     /// <param name="path"></param>
@@ -492,71 +1090,15 @@ public class DataFrame : IDisposable
         PolarsWrapper.WriteJson(Handle, path);
     }
     /// <summary>
-    /// Generate a summary statistics DataFrame (count, mean, std, min, 25%, 50%, 75%, max).
-    /// Similar to pandas/polars describe().
+    /// Summary statistics (count, null_count, mean, std, min, percentiles, max) for every column.
+    /// Numeric and boolean columns are reported as Float64, other columns as String.
     /// </summary>
-    public DataFrame Describe()
+    /// <param name="percentiles">Percentiles to include, in the given order (duplicates keep their first position), defaults to 25%, 50% and 75%</param>
+    /// <returns></returns>
+    public DataFrame Describe(params double[] percentiles)
     {
-        // 1. 筛选数值列
-        var schema = this.Schema;
-        var numericCols = schema
-            .Where(kv => kv.Value.IsNumeric)
-            .Select(kv => kv.Key)
-            .ToList();
-
-        if (numericCols.Count == 0)
-            throw new InvalidOperationException("No numeric columns to describe.");
-
-        // 2. 定义统计指标
-        // 每个指标是一个 Tuple: (Name, Func<colName, Expr>)
-        var metrics = new List<(string Name, Func<string, Expr> Op)>
-        {
-            ("count",      c => Polars.Col(c).Count().Cast(DataType.Float64)),
-            ("null_count", c => Polars.Col(c).IsNull().Sum().Cast(DataType.Float64)),
-            ("mean",       c => Polars.Col(c).Mean()),
-            ("std",        c => Polars.Col(c).Std()),
-            ("min",        c => Polars.Col(c).Min().Cast(DataType.Float64)),
-            ("25%",        c => Polars.Col(c).Quantile(0.25, "nearest").Cast(DataType.Float64)),
-            ("50%",        c => Polars.Col(c).Median().Cast(DataType.Float64)),
-            ("75%",        c => Polars.Col(c).Quantile(0.75, "nearest").Cast(DataType.Float64)),
-            ("max",        c => Polars.Col(c).Max().Cast(DataType.Float64))
-        };
-
-        // 3. 计算每一行 (Row Frames)
-        var rowFrames = new List<DataFrame>();
-        
-        try
-        {
-            foreach (var (statName, op) in metrics)
-            {
-                // 构建 Select 表达式列表: [ Lit(statName).Alias("statistic"), op(col1), op(col2)... ]
-                var exprs = new List<Expr>
-                {
-                    Polars.Lit(statName).Alias("statistic")
-                };
-
-                foreach (var col in numericCols)
-                {
-                    exprs.Add(op(col));
-                }
-
-                // 执行 Select -> 得到 1 行 N 列的 DataFrame
-                // 注意：Select 返回新 DF，我们需要收集起来
-                rowFrames.Add(this.Select([.. exprs]));
-            }
-
-            // 4. 垂直拼接
-            // 需要 Wrapper 支持 Concat(DataFrameHandle[])
-            return Concat(rowFrames);
-        }
-        finally
-        {
-            // 清理中间产生的临时 DataFrames
-            foreach (var frame in rowFrames)
-            {
-                frame.Dispose();
-            }
-        }
+        if (percentiles.Length == 0) percentiles = [0.25, 0.5, 0.75];
+        return new DataFrame(PolarsWrapper.Describe(Handle, percentiles));
     }
 
     private static bool IsNumeric(string dtype)
@@ -713,6 +1255,92 @@ public class DataFrame : IDisposable
     public string? GetString(string colName, int row) 
         => PolarsWrapper.GetString(Handle, colName, row); //
     /// <summary>
+    /// Get Scalar Boolean from DataFrame
+    /// </summary>
+    /// <param name="colName"></param>
+    /// <param name="row"></param>
+    /// <returns></returns>
+    public bool? GetBool(string colName, int row)
+        => PolarsWrapper.GetBool(Handle, colName, row);
+    /// <summary>
+    /// Get Scalar Date from DataFrame
+    /// </summary>
+    /// <param name="colName"></param>
+    /// <param name="row"></param>
+    /// <returns></returns>
+    public DateOnly? GetDate(string colName, int row)
+        => PolarsWrapper.GetDate(Handle, colName, row);
+    /// <summary>
+    /// Get Scalar Time from DataFrame
+    /// </summary>
+    /// <param name="colName"></param>
+    /// <param name="row"></param>
+    /// <returns></returns>
+    public TimeOnly? GetTime(string colName, int row)
+        => PolarsWrapper.GetTime(Handle, colName, row);
+    /// <summary>
+    /// Get Scalar Datetime from DataFrame, respecting the column's time unit.
+    /// Timezone-aware values are returned in UTC (<see cref="DateTimeKind.Utc"/>), naive values as <see cref="DateTimeKind.Unspecified"/>.
+    /// </summary>
+    /// <param name="colName"></param>
+    /// <param name="row"></param>
+    /// <returns></returns>
+    public DateTime? GetDatetime(string colName, int row)
+        => PolarsWrapper.GetDatetime(Handle, colName, row)?.Value;
+    /// <summary>
+    /// Get Scalar Datetime from DataFrame together with the column's time zone (null for naive datetimes).
+    /// </summary>
+    /// <param name="colName"></param>
+    /// <param name="row"></param>
+    /// <returns></returns>
+    public (DateTime Value, string? TimeZone)? GetDatetimeWithZone(string colName, int row)
+        => PolarsWrapper.GetDatetime(Handle, colName, row);
+    /// <summary>
+    /// Get Scalar Duration from DataFrame, respecting the column's time unit.
+    /// </summary>
+    /// <param name="colName"></param>
+    /// <param name="row"></param>
+    /// <returns></returns>
+    public TimeSpan? GetDuration(string colName, int row)
+        => PolarsWrapper.GetDuration(Handle, colName, row);
+    /// <summary>
+    /// Get Scalar Decimal from DataFrame
+    /// </summary>
+    /// <param name="colName"></param>
+    /// <param name="row"></param>
+    /// <returns></returns>
+    public decimal? GetDecimal(string colName, int row)
+        => PolarsWrapper.GetDecimal(Handle, colName, row);
+    /// <summary>
+    /// Get Scalar Binary from DataFrame
+    /// </summary>
+    /// <param name="colName"></param>
+    /// <param name="row"></param>
+    /// <returns></returns>
+    public byte[]? GetBinary(string colName, int row)
+        => PolarsWrapper.GetBinary(Handle, colName, row);
+    /// <summary>
+    /// Get the category of a Categorical or Enum value from DataFrame
+    /// </summary>
+    /// <param name="colName"></param>
+    /// <param name="row"></param>
+    /// <returns></returns>
+    public string? GetCategorical(string colName, int row)
+        => PolarsWrapper.GetCategorical(Handle, colName, row);
+    /// <summary>
+    /// Get a nested value (List, Array, Struct) from DataFrame as JSON
+    /// </summary>
+    /// <param name="colName"></param>
+    /// <param name="row"></param>
+    /// <returns></returns>
+    public System.Text.Json.JsonElement? GetNested(string colName, int row)
+    {
+        var json = PolarsWrapper.GetNestedJson(Handle, colName, row);
+        if (json == null) return null;
+        using var doc = System.Text.Json.JsonDocument.Parse(json);
+        return doc.RootElement.Clone();
+    }
+    /// <summary>
     /// Transfer a RecordBatch to Arrow
     /// </summary>
     /// <returns></returns>
@@ -971,6 +1599,50 @@ public class DataFrame : IDisposable
         return _ => null;
     }
     // ==========================================
+    // Equality / Assertions
+    // ==========================================
+
+    /// <summary>
+    /// Compare this DataFrame with another and return a report describing the first difference.
+    /// </summary>
+    /// <param name="other">The DataFrame to compare against.</param>
+    /// <param name="checkDtypes">Require identical column dtypes. If false, the right side is cast to the left dtypes where possible, otherwise values are compared as strings.</param>
+    /// <param name="checkColumnOrder">Require columns in the same order.</param>
+    /// <param name="checkRowOrder">Require rows in the same order. If false, both frames are sorted by all columns first.</param>
+    /// <param name="checkExact">Compare floats exactly instead of using <paramref name="rtol"/> / <paramref name="atol"/>. The tolerance also applies inside List and Struct values.</param>
+    /// <param name="rtol">Relative tolerance for float comparison.</param>
+    /// <param name="atol">Absolute tolerance for float comparison.</param>
+    public EqualityReport FrameEqual(
+        DataFrame other,
+        bool checkDtypes = true,
+        bool checkColumnOrder = true,
+        bool checkRowOrder = true,
+        bool checkExact = false,
+        double rtol = 1e-5,
+        double atol = 1e-8)
+    {
+        var json = PolarsWrapper.DataFrameEquals(Handle, other.Handle, checkDtypes, checkColumnOrder, checkRowOrder, checkExact, rtol, atol);
+        return EqualityReport.Parse(json);
+    }
+
+    /// <summary>
+    /// Assert that this DataFrame equals another.
+    /// Throws <see cref="PolarsAssertionException"/> with the difference report otherwise.
+    /// </summary>
+    /// <inheritdoc cref="FrameEqual" path="/param"/>
+    public void AssertFrameEqual(
+        DataFrame other,
+        bool checkDtypes = true,
+        bool checkColumnOrder = true,
+        bool checkRowOrder = true,
+        bool checkExact = false,
+        double rtol = 1e-5,
+        double atol = 1e-8)
+    {
+        var report = FrameEqual(other, checkDtypes, checkColumnOrder, checkRowOrder, checkExact, rtol, atol);
+        if (!report.IsEqual) throw new PolarsAssertionException("DataFrames", report);
+    }
+    // ==========================================
     // Conversion to Lazy
     // ==========================================
 
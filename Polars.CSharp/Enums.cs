@@ -141,6 +141,178 @@ public enum ConcatType
     /// </summary>
     Diagonal
 }
+/// <summary>
+/// Strategy used to fill null values.
+/// </summary>
+public enum FillNullStrategy
+{
+    /// <summary>
+    /// Fill with the previous non-null value.
+    /// </summary>
+    Forward,
+    /// <summary>
+    /// Fill with the next non-null value.
+    /// </summary>
+    Backward,
+    /// <summary>
+    /// Fill with the column minimum.
+    /// </summary>
+    Min,
+    /// <summary>
+    /// Fill with the column maximum.
+    /// </summary>
+    Max,
+    /// <summary>
+    /// Fill with the column mean (numeric columns only).
+    /// </summary>
+    Mean,
+    /// <summary>
+    /// Fill with 0 (numeric columns only).
+    /// </summary>
+    Zero,
+    /// <summary>
+    /// Fill with 1 (numeric columns only).
+    /// </summary>
+    One
+}
+/// <summary>
+/// Interpolation method.
+/// </summary>
+public enum InterpolationMethod
+{
+    /// <summary>
+    /// Linear interpolation.
+    /// </summary>
+    Linear,
+    /// <summary>
+    /// Use the nearest value.
+    /// </summary>
+    Nearest
+}
+/// <summary>
+/// Which row to keep when dropping duplicates.
+/// </summary>
+public enum UniqueKeepStrategy
+{
+    /// <summary>
+    /// Keep the first occurrence.
+    /// </summary>
+    First,
+    /// <summary>
+    /// Keep the last occurrence.
+    /// </summary>
+    Last,
+    /// <summary>
+    /// Keep any occurrence (no ordering guarantee, fastest).
+    /// </summary>
+    Any,
+    /// <summary>
+    /// Drop every row that has a duplicate.
+    /// </summary>
+    None
+}
+/// <summary>
+/// Compression codec used when writing Avro files.
+/// </summary>
+public enum AvroCompression
+{
+    /// <summary>
+    /// No compression.
+    /// </summary>
+    None,
+    /// <summary>
+    /// Deflate compression.
+    /// </summary>
+    Deflate,
+    /// <summary>
+    /// Snappy compression.
+    /// </summary>
+    Snappy
+}
+/// <summary>
+/// How a DataFrame is written into an existing SQLite database.
+/// </summary>
+public enum SqliteWriteMode
+{
+    /// <summary>
+    /// Create the table, fail if it already exists.
+    /// </summary>
+    Create,
+    /// <summary>
+    /// Append rows, creating the table if it does not exist.
+    /// </summary>
+    Append,
+    /// <summary>
+    /// Drop any existing table and create it again.
+    /// </summary>
+    Replace
+}
+/// <summary>
+/// Uniqueness check applied to the join keys.
+/// </summary>
+public enum JoinValidation
+{
+    /// <summary>
+    /// No check.
+    /// </summary>
+    ManyToMany,
+    /// <summary>
+    /// Keys must be unique on both sides.
+    /// </summary>
+    OneToOne,
+    /// <summary>
+    /// Keys must be unique on the left side.
+    /// </summary>
+    OneToMany,
+    /// <summary>
+    /// Keys must be unique on the right side.
+    /// </summary>
+    ManyToOne
+}
+/// <summary>
+/// Whether the key columns of both sides are merged into one.
+/// </summary>
+public enum JoinCoalesce
+{
+    /// <summary>
+    /// Decided by the join type (full joins keep both, others merge).
+    /// </summary>
+    JoinSpecific,
+    /// <summary>
+    /// Always merge the key columns.
+    /// </summary>
+    Coalesce,
+    /// <summary>
+    /// Always keep both key columns.
+    /// </summary>
+    KeepColumns
+}
+/// <summary>
+/// Row order guaranteed by a join.
+/// </summary>
+public enum JoinMaintainOrder
+{
+    /// <summary>
+    /// No guarantee (fastest).
+    /// </summary>
+    None,
+    /// <summary>
+    /// Preserve the order of the left frame.
+    /// </summary>
+    Left,
+    /// <summary>
+    /// Preserve the order of the right frame.
+    /// </summary>
+    Right,
+    /// <summary>
+    /// Preserve the left order, then the right order.
+    /// </summary>
+    LeftRight,
+    /// <summary>
+    /// Preserve the right order, then the left order.
+    /// </summary>
+    RightLeft
+}
 internal static class EnumExtensions
 {
     public static PlTimeUnit ToNative(this TimeUnit unit) => unit switch
@@ -182,6 +354,35 @@ internal static class EnumExtensions
         _ => PlPivotAgg.First
     };
     
+    public static PlFillNullStrategy ToNative(this FillNullStrategy strategy) => strategy switch
+    {
+        FillNullStrategy.Forward => PlFillNullStrategy.Forward,
+        FillNullStrategy.Backward => PlFillNullStrategy.Backward,
+        FillNullStrategy.Min => PlFillNullStrategy.Min,
+        FillNullStrategy.Max => PlFillNullStrategy.Max,
+        FillNullStrategy.Mean => PlFillNullStrategy.Mean,
+        FillNullStrategy.Zero => PlFillNullStrategy.Zero,
+        FillNullStrategy.One => PlFillNullStrategy.One,
+        _ => PlFillNullStrategy.Forward
+    };
+
+    public static PlInterpolationMethod ToNative(this InterpolationMethod method) => method switch
+    {
+        InterpolationMethod.Linear => PlInterpolationMethod.Linear,
+        InterpolationMethod.Nearest => PlInterpolationMethod.Nearest,
+        // 未知取值原样传下去，由 native 端报错
+        _ => (PlInterpolationMethod)(int)method
+    };
+
+    public static PlUniqueKeep ToNative(this UniqueKeepStrategy keep) => keep switch
+    {
+        UniqueKeepStrategy.First => PlUniqueKeep.First,
+        UniqueKeepStrategy.Last => PlUniqueKeep.Last,
+        UniqueKeepStrategy.Any => PlUniqueKeep.Any,
+        UniqueKeepStrategy.None => PlUniqueKeep.None,
+        _ => PlUniqueKeep.First
+    };
+
     public static PlConcatType ToNative(this ConcatType type) => type switch
     {
         ConcatType.Vertical => PlConcatType.Vertical,
@@ -189,4 +390,47 @@ internal static class EnumExtensions
         ConcatType.Diagonal => PlConcatType.Diagonal,
         _ => PlConcatType.Vertical
     };
+
+    public static PlAvroCompression ToNative(this AvroCompression compression) => compression switch
+    {
+        AvroCompression.None => PlAvroCompression.None,
+        AvroCompression.Deflate => PlAvroCompression.Deflate,
+        AvroCompression.Snappy => PlAvroCompression.Snappy,
+        _ => PlAvroCompression.None
+    };
+
+    public static PlSqliteWriteMode ToNative(this SqliteWriteMode mode) => mode switch
+    {
+        SqliteWriteMode.Create => PlSqliteWriteMode.Create,
+        SqliteWriteMode.Append => PlSqliteWriteMode.Append,
+        SqliteWriteMode.Replace => PlSqliteWriteMode.Replace,
+        _ => PlSqliteWriteMode.Create
+    };
+
+    public static PlJoinValidation ToNative(this JoinValidation validation) => validation switch
+    {
+        JoinValidation.ManyToMany => PlJoinValidation.ManyToMany,
+        JoinValidation.OneToOne => PlJoinValidation.OneToOne,
+        JoinValidation.OneToMany => PlJoinValidation.OneToMany,
+        JoinValidation.ManyToOne => PlJoinValidation.ManyToOne,
+        _ => PlJoinValidation.ManyToMany
+    };
+
+    public static PlJoinCoalesce ToNative(this JoinCoalesce coalesce) => coalesce switch
+    {
+        JoinCoalesce.JoinSpecific => PlJoinCoalesce.JoinSpecific,
+        JoinCoalesce.Coalesce => PlJoinCoalesce.Coalesce,
+        JoinCoalesce.KeepColumns => PlJoinCoalesce.KeepColumns,
+        _ => PlJoinCoalesce.JoinSpecific
+    };
+
+    public static PlJoinMaintainOrder ToNative(this JoinMaintainOrder order) => order switch
+    {
+        JoinMaintainOrder.None => PlJoinMaintainOrder.None,
+        JoinMaintainOrder.Left => PlJoinMaintainOrder.Left,
+        JoinMaintainOrder.Right => PlJoinMaintainOrder.Right,
+        JoinMaintainOrder.LeftRight => PlJoinMaintainOrder.LeftRight,
+        JoinMaintainOrder.RightLeft => PlJoinMaintainOrder.RightLeft,
+        _ => PlJoinMaintainOrder.None
+    };
 }
\ No newline at end of file
@@ -8,11 +8,13 @@ public class GroupByBuilder
 {
     private readonly DataFrame _df;
     private readonly Expr[] _by;
+    private readonly bool _maintainOrder;
 
-    internal GroupByBuilder(DataFrame df, Expr[] by)
+    internal GroupByBuilder(DataFrame df, Expr[] by, bool maintainOrder = false)
     {
         _df = df;
         _by = by;
+        _maintainOrder = maintainOrder;
     }
     /// <summary>
     /// Aggregate with specified expressions
@@ -26,7 +28,29 @@ public class GroupByBuilder
         var aggHandles = aggs.Select(a => PolarsWrapper.CloneExpr(a.Handle)).ToArray();
 
         //
-        var h = PolarsWrapper.GroupByAgg(_df.Handle, byHandles, aggHandles);
+        var h = PolarsWrapper.GroupByAgg(_df.Handle, byHandles, aggHandles, _maintainOrder);
         return new DataFrame(h);
     }
+    /// <summary>
+    /// Take the first n rows of every group.
+    /// </summary>
+    /// <param name="n"></param>
+    /// <returns></returns>
+    public DataFrame Head(int n = 5)
+    {
+        var byHandles = _by.Select(b => PolarsWrapper.CloneExpr(b.Handle)).ToArray();
+        //
+        return new DataFrame(PolarsWrapper.GroupByHead(_df.Handle, byHandles, n, _maintainOrder));
+    }
+    /// <summary>
+    /// Take the last n rows of every group.
+    /// </summary>
+    /// <param name="n"></param>
+    /// <returns></returns>
+    public DataFrame Tail(int n = 5)
+    {
+        var byHandles = _by.Select(b => PolarsWrapper.CloneExpr(b.Handle)).ToArray();
+        //
+        return new DataFrame(PolarsWrapper.GroupByTail(_df.Handle, byHandles, n, _maintainOrder));
+    }
 }
\ No newline at end of file
@@ -28,7 +28,8 @@ public class LazyFrame : IDisposable
         bool hasHeader = true,
         char separator = ',',
         ulong skipRows = 0,
-        bool tryParseDates = true) // [新增参数]
+        bool tryParseDates = true, // [新增参数]
+        CloudOptions? cloudOptions = null)
     {
         var schemaHandles = schema?.ToDictionary(
             kv => kv.Key, 
@@ -41,7 +42,8 @@ public class LazyFrame : IDisposable
             hasHeader, 
             separator, 
             skipRows,
-            tryParseDates // 传递给 Wrapper
+            tryParseDates, // 传递给 Wrapper
+            cloudOptions?.Handle
         );
 
         return new LazyFrame(handle);
@@ -49,22 +51,36 @@ public class LazyFrame : IDisposable
     /// <summary>
     /// Read a Parquet file as a LazyFrame.
     /// </summary>
-    /// <param name="path"></param>
+    /// <param name="path">Local path or cloud URI (e.g. s3://bucket/key.parquet).</param>
+    /// <param name="cloudOptions">Object store settings for cloud URIs.</param>
     /// <returns></returns>
-    public static LazyFrame ScanParquet(string path)
+    public static LazyFrame ScanParquet(string path, CloudOptions? cloudOptions = null)
     {
         //
-        return new LazyFrame(PolarsWrapper.ScanParquet(path));
+        return new LazyFrame(PolarsWrapper.ScanParquet(path, cloudOptions?.Handle));
     }
     /// <summary>
     /// Read an IPC (Feather) file as a LazyFrame.
     /// </summary>
-    /// <param name="path"></param>
+    /// <param name="path">Local path or cloud URI (e.g. s3://bucket/key.arrow).</param>
+    /// <param name="cloudOptions">Object store settings for cloud URIs.</param>
     /// <returns></returns>
-    public static LazyFrame ScanIpc(string path)
+    public static LazyFrame ScanIpc(string path, CloudOptions? cloudOptions = null)
     {
         //
-        return new LazyFrame(PolarsWrapper.ScanIpc(path));
+        return new LazyFrame(PolarsWrapper.ScanIpc(path, cloudOptions?.Handle));
+    }
+    /// <summary>
+    /// Read a local Delta Lake table directory as a LazyFrame.
+    /// Partition columns are restored from the transaction log.
+    /// Deletion vectors and column mapping are not supported.
+    /// </summary>
+    /// <param name="path">Table directory (the one containing _delta_log).</param>
+    /// <param name="version">Table version to read (time travel), or null for the latest.</param>
+    /// <returns></returns>
+    public static LazyFrame ScanDelta(string path, long? version = null)
+    {
+        return new LazyFrame(PolarsWrapper.ScanDelta(path, version));
     }
     /// <summary>
     /// Read a NDJSON file as a LazyFrame.
@@ -76,6 +92,23 @@ public class LazyFrame : IDisposable
         //
         return new LazyFrame(PolarsWrapper.ScanNdjson(path));
     }
+    /// <summary>
+    /// Create a LazyFrame backed by a custom .NET data source.
+    /// <paramref name="nextBatch"/> is called repeatedly during each collect and returns one Arrow batch per call,
+    /// or null once the source is exhausted. Every batch must match <paramref name="schema"/> (names and data types);
+    /// it may either contain all columns or only the requested <see cref="ScanRequest.Columns"/>.
+    /// </summary>
+    /// <param name="schema">Declared schema of the source.</param>
+    /// <param name="nextBatch">Produces the next batch; the request carries pushdown hints that can be ignored.</param>
+    /// <returns></returns>
+    public static LazyFrame ScanCallback(Dictionary<string, DataType> schema, Func<ScanRequest, Apache.Arrow.RecordBatch?> nextBatch)
+    {
+        var schemaHandles = schema.ToDictionary(kv => kv.Key, kv => kv.Value.Handle);
+        return new LazyFrame(PolarsWrapper.ScanCallback(
+            schemaHandles,
+            (batchIndex, columns, nRows, predicate) => nextBatch(new ScanRequest(batchIndex, columns, nRows, predicate))
+        ));
+    }
 
     // ==========================================
     // Meta / Inspection
@@ -164,6 +197,22 @@ public class LazyFrame : IDisposable
         return new LazyFrame(PolarsWrapper.LazySort(lfClone, h, descending));
     }
     /// <summary>
+    /// Sort the LazyFrame by multiple expressions.
+    /// </summary>
+    /// <param name="by">Sort keys, in priority order</param>
+    /// <param name="descending">One flag per key, or a single flag for all keys</param>
+    /// <param name="nullsLast">Place nulls at the end</param>
+    /// <param name="maintainOrder">Keep the original order of equal rows (stable sort)</param>
+    /// <param name="multithreaded">Sort using multiple threads</param>
+    /// <returns></returns>
+    public LazyFrame Sort(Expr[] by, bool[] descending, bool nullsLast = false, bool maintainOrder = false, bool multithreaded = true)
+    {
+        var lfClone = this.CloneHandle();
+        var handles = by.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        //
+        return new LazyFrame(PolarsWrapper.LazySort(lfClone, handles, descending, nullsLast, maintainOrder, multithreaded));
+    }
+    /// <summary>
     /// Limit the number of rows in the LazyFrame.
     /// </summary>
     /// <param name="n"></param>
@@ -174,6 +223,146 @@ public class LazyFrame : IDisposable
         return new LazyFrame(PolarsWrapper.LazyLimit(lfClone, n));
     }
     /// <summary>
+    /// Get a slice of rows. A negative offset counts from the end.
+    /// </summary>
+    /// <param name="offset"></param>
+    /// <param name="length"></param>
+    /// <returns></returns>
+    public LazyFrame Slice(long offset, uint length)
+    {
+        var lfClone = this.CloneHandle();
+        return new LazyFrame(PolarsWrapper.LazySlice(lfClone, offset, length));
+    }
+    /// <summary>
+    /// Reverse the order of the rows.
+    /// </summary>
+    /// <returns></returns>
+    public LazyFrame Reverse()
+    {
+        var lfClone = this.CloneHandle();
+        return new LazyFrame(PolarsWrapper.LazyReverse(lfClone));
+    }
+    /// <summary>
+    /// Shift rows by n positions (negative shifts up), filling with nulls.
+    /// </summary>
+    /// <param name="n"></param>
+    /// <returns></returns>
+    public LazyFrame Shift(long n = 1)
+    {
+        var lfClone = this.CloneHandle();
+        return new LazyFrame(PolarsWrapper.LazyShift(lfClone, n));
+    }
+    /// <summary>
+    /// Insert a UInt32 row index column at position 0.
+    /// </summary>
+    /// <param name="name"></param>
+    /// <param name="offset">Value of the first index</param>
+    /// <returns></returns>
+    public LazyFrame WithRowIndex(string name = "index", uint offset = 0)
+    {
+        var lfClone = this.CloneHandle();
+        return new LazyFrame(PolarsWrapper.LazyWithRowIndex(lfClone, name, offset));
+    }
+    /// <summary>
+    /// Take rows by position.
+    /// </summary>
+    /// <param name="indices"></param>
+    /// <returns></returns>
+    public LazyFrame Gather(params uint[] indices)
+    {
+        var lfClone = this.CloneHandle();
+        return new LazyFrame(PolarsWrapper.LazyGather(lfClone, indices));
+    }
+    /// <summary>
+    /// Take every nth row, starting at offset.
+    /// </summary>
+    /// <param name="n"></param>
+    /// <param name="offset"></param>
+    /// <returns></returns>
+    public LazyFrame GatherEvery(ulong n, ulong offset = 0)
+    {
+        var lfClone = this.CloneHandle();
+        return new LazyFrame(PolarsWrapper.LazyGatherEvery(lfClone, n, offset));
+    }
+    /// <summary>
+    /// Summary statistics (count, null_count, mean, std, min, percentiles, max) for every column.
+    /// Numeric and boolean columns are reported as Float64, other columns as String.
+    /// </summary>
+    /// <param name="percentiles">Percentiles to include, in the given order (duplicates keep their first position), defaults to 25%, 50% and 75%</param>
+    /// <returns></returns>
+    public DataFrame Describe(params double[] percentiles)
+    {
+        if (percentiles.Length == 0) percentiles = [0.25, 0.5, 0.75];
+        var lfClone = this.CloneHandle();
+        return new DataFrame(PolarsWrapper.LazyDescribe(lfClone, percentiles));
+    }
+    /// <summary>
+    /// Fill null values with an expression.
+    /// </summary>
+    /// <param name="value">Fill value</param>
+    /// <param name="columns">Columns to fill (empty = all columns)</param>
+    /// <returns></returns>
+    public LazyFrame FillNull(Expr value, params string[] columns)
+    {
+        var lfClone = this.CloneHandle();
+        var v = PolarsWrapper.CloneExpr(value.Handle);
+        //
+        return new LazyFrame(PolarsWrapper.LazyFillNull(lfClone, ToSelector(columns), v));
+    }
+    /// <summary>
+    /// Fill null values with a strategy.
+    /// </summary>
+    /// <param name="strategy">Fill strategy</param>
+    /// <param name="limit">Max consecutive nulls to fill for Forward/Backward (0 = unlimited)</param>
+    /// <param name="columns">Columns to fill (empty = all columns)</param>
+    /// <returns></returns>
+    public LazyFrame FillNull(FillNullStrategy strategy, uint limit = 0, params string[] columns)
+    {
+        var lfClone = this.CloneHandle();
+        //
+        return new LazyFrame(PolarsWrapper.LazyFillNull(lfClone, ToSelector(columns), strategy.ToNative(), limit));
+    }
+    /// <summary>
+    /// Fill floating point NaN values with an expression.
+    /// </summary>
+    /// <param name="value">Fill value</param>
+    /// <param name="columns">Columns to fill (empty = all float columns)</param>
+    /// <returns></returns>
+    public LazyFrame FillNan(Expr value, params string[] columns)
+    {
+        var lfClone = this.CloneHandle();
+        var v = PolarsWrapper.CloneExpr(value.Handle);
+        //
+        return new LazyFrame(PolarsWrapper.LazyFillNan(lfClone, ToSelector(columns), v));
+    }
+    /// <summary>
+    /// Interpolate intermediate null values of numeric columns.
+    /// </summary>
+    /// <param name="method">Interpolation method</param>
+    /// <param name="columns">Columns to interpolate (empty = all numeric columns)</param>
+    /// <returns></returns>
+    public LazyFrame Interpolate(InterpolationMethod method = InterpolationMethod.Linear, params string[] columns)
+    {
+        var lfClone = this.CloneHandle();
+        //
+        return new LazyFrame(PolarsWrapper.LazyInterpolate(lfClone, ToSelector(columns), method.ToNative()));
+    }
+    // 空列表表示所有列
+    private static SelectorHandle? ToSelector(string[] columns)
+        => columns.Length == 0 ? null : PolarsWrapper.SelectorCols(columns);
+    /// <summary>
+    /// Drop duplicate rows.
+    /// </summary>
+    /// <param name="subset">Columns to compare (null = all columns)</param>
+    /// <param name="keep">Which of the duplicate rows to keep</param>
+    /// <param name="maintainOrder">Keep the original row order</param>
+    /// <returns></returns>
+    public LazyFrame Unique(string[]? subset = null, UniqueKeepStrategy keep = UniqueKeepStrategy.Any, bool maintainOrder = false)
+    {
+        var lfClone = this.CloneHandle();
+        return new LazyFrame(PolarsWrapper.LazyUnique(lfClone, subset, keep.ToNative(), maintainOrder));
+    }
+    /// <summary>
     /// Explode list-like columns into multiple rows.
     /// </summary>
     /// <param name="exprs"></param>
@@ -190,6 +379,52 @@ public class LazyFrame : IDisposable
     // Reshaping
     // ==========================================
     /// <summary>
+    /// Pivot the LazyFrame from long to wide format.
+    /// A lazy pivot cannot infer its output columns, so the values of the <paramref name="on"/> column must be given up front.
+    /// Values are matched on their string representation and other values are ignored.
+    /// Cells without any rows are null, for Count/Len as well.
+    /// </summary>
+    /// <param name="on">Column whose values become the new column names</param>
+    /// <param name="onValues">Values of the on column to turn into columns</param>
+    /// <param name="index">Rows of the result. When empty, all columns not in on/values are used</param>
+    /// <param name="values">Value columns. When empty, all columns not in on/index are used</param>
+    /// <param name="agg"></param>
+    /// <param name="separator">Used in "{value}{separator}{on value}" names when there are multiple value columns, defaults to "_"</param>
+    /// <param name="maintainOrder">Keep the rows in order of first appearance</param>
+    /// <returns></returns>
+    public LazyFrame Pivot(
+        string on, string[] onValues, string[] index, string[] values, PivotAgg agg = PivotAgg.First,
+        string? separator = null, bool maintainOrder = false)
+    {
+        var lfClone = this.CloneHandle();
+        //
+        return new LazyFrame(PolarsWrapper.LazyPivot(
+            lfClone, on, onValues, index, values, agg.ToNative(), null, separator, maintainOrder));
+    }
+    /// <summary>
+    /// Pivot the LazyFrame from long to wide format with a custom aggregation,
+    /// which refers to the values of each cell through <see cref="Polars.Element"/>.
+    /// Cells without any rows are null.
+    /// </summary>
+    /// <param name="on">Column whose values become the new column names</param>
+    /// <param name="onValues">Values of the on column to turn into columns</param>
+    /// <param name="index">Rows of the result. When empty, all columns not in on/values are used</param>
+    /// <param name="values">Value columns. When empty, all columns not in on/index are used</param>
+    /// <param name="agg">Aggregation expression</param>
+    /// <param name="separator">Used in "{value}{separator}{on value}" names when there are multiple value columns, defaults to "_"</param>
+    /// <param name="maintainOrder">Keep the rows in order of first appearance</param>
+    /// <returns></returns>
+    public LazyFrame Pivot(
+        string on, string[] onValues, string[] index, string[] values, Expr agg,
+        string? separator = null, bool maintainOrder = false)
+    {
+        var lfClone = this.CloneHandle();
+        var aggHandle = PolarsWrapper.CloneExpr(agg.Handle);
+        //
+        return new LazyFrame(PolarsWrapper.LazyPivot(
+            lfClone, on, onValues, index, values, PlPivotAgg.First, aggHandle, separator, maintainOrder));
+    }
+    /// <summary>
     /// Unpivot (Melt) the DataFrame from wide to long format.
     /// </summary>
     /// <param name="index"></param>
@@ -241,8 +476,22 @@ public class LazyFrame : IDisposable
     /// <param name="leftOn"></param>
     /// <param name="rightOn"></param>
     /// <param name="how"></param>
+    /// <param name="suffix">Suffix for clashing right-hand column names, defaults to "_right"</param>
+    /// <param name="validate">Check the uniqueness of the join keys</param>
+    /// <param name="coalesce">Whether to merge the key columns of both sides</param>
+    /// <param name="nullsEqual">Treat null keys as equal to each other</param>
+    /// <param name="maintainOrder">Row order of the result</param>
     /// <returns></returns>
-    public LazyFrame Join(LazyFrame other, Expr[] leftOn, Expr[] rightOn, JoinType how = JoinType.Inner)
+    public LazyFrame Join(
+        LazyFrame other,
+        Expr[] leftOn,
+        Expr[] rightOn,
+        JoinType how = JoinType.Inner,
+        string? suffix = null,
+        JoinValidation validate = JoinValidation.ManyToMany,
+        JoinCoalesce coalesce = JoinCoalesce.JoinSpecific,
+        bool nullsEqual = false,
+        JoinMaintainOrder maintainOrder = JoinMaintainOrder.None)
     {
         var lOn = leftOn.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
         var rOn = rightOn.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
@@ -254,10 +503,30 @@ public class LazyFrame : IDisposable
             otherClone, 
             lOn, 
             rOn, 
-            how.ToNative()
+            how.ToNative(),
+            suffix,
+            validate.ToNative(),
+            coalesce.ToNative(),
+            nullsEqual,
+            maintainOrder.ToNative()
         ));
     }
     /// <summary>
+    /// Join with another LazyFrame on inequality / range predicates.
+    /// </summary>
+    /// <param name="other"></param>
+    /// <param name="predicates">Predicates referencing columns of both frames</param>
+    /// <param name="suffix">Suffix for clashing right-hand column names</param>
+    /// <returns></returns>
+    public LazyFrame JoinWhere(LazyFrame other, Expr[] predicates, string suffix = "_right")
+    {
+        var handles = predicates.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        var lfClone = this.CloneHandle();
+        var otherClone = other.CloneHandle();
+        //
+        return new LazyFrame(PolarsWrapper.JoinWhere(lfClone, otherClone, handles, suffix));
+    }
+    /// <summary>
     /// Join with another LazyFrame on a single column.
     /// </summary>
     /// <param name="other"></param>
@@ -279,7 +548,9 @@ public class LazyFrame : IDisposable
         string? tolerance = null,
         string strategy = "backward",
         Expr[]? leftBy = null,
-        Expr[]? rightBy = null)
+        Expr[]? rightBy = null,
+        bool allowEq = true,
+        bool checkSortedness = true)
     {
         var lfClone = this.CloneHandle();
         var otherClone = other.CloneHandle();
@@ -294,7 +565,9 @@ public class LazyFrame : IDisposable
             lfClone, otherClone,
             lOn, rOn,
             lBy, rBy,
-            strategy, tolerance
+            strategy,
+            PolarsWrapper.InferAsofTolerance(tolerance), tolerance,
+            allowEq, checkSortedness
         ));
     }
 
@@ -312,6 +585,85 @@ public class LazyFrame : IDisposable
         
         return new LazyGroupBy(lfClone, keys);
     }
+    /// <summary>
+    /// Start a GroupBy operation, optionally keeping groups in the order they first appear.
+    /// </summary>
+    /// <param name="keys"></param>
+    /// <param name="maintainOrder">Keep the order of the groups consistent with the input</param>
+    /// <returns></returns>
+    public LazyGroupBy GroupBy(Expr[] keys, bool maintainOrder)
+    {
+        var lfClone = this.CloneHandle();
+
+        return new LazyGroupBy(lfClone, keys, maintainOrder);
+    }
+    /// <summary>
+    /// Group by time windows over a sorted index column.
+    /// </summary>
+    /// <param name="index">Sorted temporal (or integer) index column</param>
+    /// <param name="every">Window start interval, e.g. "5m" ("2i" for integer index)</param>
+    /// <param name="aggs">Aggregations evaluated per window</param>
+    /// <param name="period">Window length, defaults to every</param>
+    /// <param name="offset">Offset of the window boundaries</param>
+    /// <param name="by">Extra group keys</param>
+    /// <param name="closed">"left", "right", "both" or "none"</param>
+    /// <param name="label">"left", "right" or "datapoint"</param>
+    /// <param name="includeBoundaries">Add _lower_boundary / _upper_boundary columns</param>
+    /// <param name="startBy">"window", "datapoint" or a weekday ("monday" ...)</param>
+    /// <returns></returns>
+    public LazyFrame GroupByDynamic(
+        Expr index,
+        string every,
+        Expr[] aggs,
+        string? period = null,
+        string? offset = null,
+        Expr[]? by = null,
+        string closed = "left",
+        string label = "left",
+        bool includeBoundaries = false,
+        string startBy = "window")
+    {
+        var idx = PolarsWrapper.CloneExpr(index.Handle);
+        var byHandles = (by ?? []).Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        var aggHandles = aggs.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        var lfClone = this.CloneHandle();
+        //
+        return new LazyFrame(PolarsWrapper.LazyGroupByDynamic(
+            lfClone, idx, byHandles,
+            every, period, offset,
+            label, includeBoundaries, closed, startBy,
+            aggHandles
+        ));
+    }
+    /// <summary>
+    /// Rolling group-by: every row gets a look-back window over the index column.
+    /// </summary>
+    /// <param name="index">Sorted temporal (or integer) index column</param>
+    /// <param name="period">Window length, e.g. "2d" ("3i" for integer index)</param>
+    /// <param name="aggs">Aggregations evaluated per window</param>
+    /// <param name="offset">Offset of the window start, defaults to -period</param>
+    /// <param name="by">Extra group keys</param>
+    /// <param name="closed">"left", "right", "both" or "none"</param>
+    /// <returns></returns>
+    public LazyFrame Rolling(
+        Expr index,
+        string period,
+        Expr[] aggs,
+        string? offset = null,
+        Expr[]? by = null,
+        string closed = "right")
+    {
+        var idx = PolarsWrapper.CloneExpr(index.Handle);
+        var byHandles = (by ?? []).Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        var aggHandles = aggs.Select(e => PolarsWrapper.CloneExpr(e.Handle)).ToArray();
+        var lfClone = this.CloneHandle();
+        //
+        return new LazyFrame(PolarsWrapper.LazyRolling(
+            lfClone, idx, byHandles,
+            period, offset, closed,
+            aggHandles
+        ));
+    }
     // ==========================================
     // Execution (Collect)
     // ==========================================
@@ -347,20 +699,41 @@ public class LazyFrame : IDisposable
     /// <summary>
     /// Sink the LazyFrame to a Parquet file.
     /// </summary>
-    /// <param name="path"></param>
-    public void SinkParquet(string path)
+    /// <param name="path">Local path or cloud URI.</param>
+    /// <param name="cloudOptions">Object store settings for cloud URIs.</param>
+    public void SinkParquet(string path, CloudOptions? cloudOptions = null)
     {
         //
-        PolarsWrapper.SinkParquet(Handle, path);
+        PolarsWrapper.SinkParquet(Handle, path, cloudOptions?.Handle);
     }
     /// <summary>
     /// Sink the LazyFrame to a CSV file.
     /// </summary>
-    /// <param name="path"></param>
-    public void SinkIpc(string path)
+    /// <param name="path">Local path or cloud URI.</param>
+    /// <param name="cloudOptions">Object store settings for cloud URIs.</param>
+    public void SinkIpc(string path, CloudOptions? cloudOptions = null)
     {
         //
-        PolarsWrapper.SinkIpc(Handle, path);
+        PolarsWrapper.SinkIpc(Handle, path, cloudOptions?.Handle);
+    }
+    /// <summary>
+    /// Execute the query with the streaming engine and push the result to <paramref name="onBatch"/> one batch at a time,
+    /// without materializing the whole result in memory.
+    /// The callback is invoked serially (from a Polars worker thread) and owns each batch.
+    /// Batches arrive in no guaranteed row order.
+    /// </summary>
+    /// <param name="onBatch">Receives each batch; return false to stop the query early.</param>
+    public void SinkBatches(Func<DataFrame, bool> onBatch)
+    {
+        PolarsWrapper.SinkBatches(this.CloneHandle(), h => onBatch(new DataFrame(h)));
+    }
+    /// <summary>
+    /// Same as <see cref="SinkBatches"/>, but each batch is handed over as an Arrow <see cref="Apache.Arrow.RecordBatch"/>.
+    /// </summary>
+    /// <param name="onBatch">Receives each batch; return false to stop the query early.</param>
+    public void SinkBatchesArrow(Func<Apache.Arrow.RecordBatch, bool> onBatch)
+    {
+        PolarsWrapper.SinkBatchesArrow(this.CloneHandle(), onBatch);
     }
     /// <summary>
     /// Dispose the LazyFrame and release native resources.
@@ -369,4 +742,13 @@ public class LazyFrame : IDisposable
     {
         Handle?.Dispose();
     }
-}
\ No newline at end of file
+}
+
+/// <summary>
+/// Pushdown hints passed to a <see cref="LazyFrame.ScanCallback"/> source.
+/// </summary>
+/// <param name="BatchIndex">Index of the call within the current scan; 0 means a new scan starts (reset your cursor).</param>
+/// <param name="Columns">Columns the query needs, or null for all columns.</param>
+/// <param name="NRows">Maximum number of rows the query needs, or null for no limit.</param>
+/// <param name="Predicate">Filter the query applies (as text), or null. Polars re-applies it, so it is only a hint.</param>
+public sealed record ScanRequest(long BatchIndex, string[]? Columns, ulong? NRows, string? Predicate);
@@ -9,11 +9,13 @@ public class LazyGroupBy
 {
     private readonly LazyFrameHandle _lfHandle; // 这是克隆来的 Handle
     private readonly Expr[] _keys;
+    private readonly bool _maintainOrder;
 
-    internal LazyGroupBy(LazyFrameHandle lfHandle, Expr[] keys)
+    internal LazyGroupBy(LazyFrameHandle lfHandle, Expr[] keys, bool maintainOrder = false)
     {
         _lfHandle = lfHandle;
         _keys = keys;
+        _maintainOrder = maintainOrder;
     }
 
     /// <summary>
@@ -32,8 +34,30 @@ public class LazyGroupBy
         // 注意：这里传入的是 _lfHandle。
         // NativeBindings.pl_lazy_groupby_agg 会消耗这个 handle。
         // 因为我们在创建 LazyGroupBy 时已经 Clone 过了，所以这里消耗的是副本，安全！
-        var resHandle = PolarsWrapper.LazyGroupByAgg(_lfHandle, keyHandles, aggHandles);
+        var resHandle = PolarsWrapper.LazyGroupByAgg(_lfHandle, keyHandles, aggHandles, _maintainOrder);
         
         return new LazyFrame(resHandle);
     }
+
+    /// <summary>
+    /// Take the first n rows of every group.
+    /// This consumes the internal LazyFrame handle.
+    /// </summary>
+    public LazyFrame Head(int n = 5)
+    {
+        var keyHandles = _keys.Select(k => PolarsWrapper.CloneExpr(k.Handle)).ToArray();
+        //
+        return new LazyFrame(PolarsWrapper.LazyGroupByHead(_lfHandle, keyHandles, n, _maintainOrder));
+    }
+
+    /// <summary>
+    /// Take the last n rows of every group.
+    /// This consumes the internal LazyFrame handle.
+    /// </summary>
+    public LazyFrame Tail(int n = 5)
+    {
+        var keyHandles = _keys.Select(k => PolarsWrapper.CloneExpr(k.Handle)).ToArray();
+        //
+        return new LazyFrame(PolarsWrapper.LazyGroupByTail(_lfHandle, keyHandles, n, _maintainOrder));
+    }
 }
\ No newline at end of file
@@ -26,6 +26,14 @@ public static class Polars
         return new Expr(PolarsWrapper.Cols(names));
     }
     /// <summary>
+    /// The element being aggregated, used in custom pivot aggregations
+    /// </summary>
+    /// <returns></returns>
+    public static Expr Element()
+    {
+        return Col("");
+    }
+    /// <summary>
     /// All Columns Exprs (name: string)
     /// </summary>
     /// <returns></returns>
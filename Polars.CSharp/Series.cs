@@ -799,6 +799,30 @@ public class Series : IDisposable
     /// Get the number of null values in the Series.
     /// </summary>
     public long NullCount => PolarsWrapper.SeriesNullCount(Handle);
+    /// <summary>
+    /// Estimated heap size of the Series in bytes (upper bound if buffers are shared).
+    /// </summary>
+    public long EstimatedSize => PolarsWrapper.SeriesEstimatedSize(Handle);
+    /// <summary>
+    /// Number of chunks backing the Series.
+    /// </summary>
+    public long NChunks => PolarsWrapper.SeriesNChunks(Handle);
+    /// <summary>
+    /// Merge all chunks into a single contiguous chunk (in place).
+    /// </summary>
+    public Series Rechunk()
+    {
+        PolarsWrapper.SeriesRechunk(Handle);
+        return this;
+    }
+    /// <summary>
+    /// Release unused capacity (in place).
+    /// </summary>
+    public Series ShrinkToFit()
+    {
+        PolarsWrapper.SeriesShrinkToFit(Handle);
+        return this;
+    }
 
     // ==========================================
     // Operations
@@ -813,6 +837,50 @@ public class Series : IDisposable
         return new Series(PolarsWrapper.SeriesCast(Handle, dtype.Handle));
     }
     // ==========================================
+    // Equality / Assertions
+    // ==========================================
+
+    /// <summary>
+    /// Compare this Series with another and return a report describing the first difference.
+    /// </summary>
+    /// <param name="other">The Series to compare against.</param>
+    /// <param name="checkDtypes">Require identical dtypes. If false, the right side is cast to the left dtype where possible, otherwise values are compared as strings.</param>
+    /// <param name="checkNames">Require identical names.</param>
+    /// <param name="checkOrder">Require values in the same order. If false, both sides are sorted first.</param>
+    /// <param name="checkExact">Compare floats exactly instead of using <paramref name="rtol"/> / <paramref name="atol"/>. The tolerance also applies inside List and Struct values.</param>
+    /// <param name="rtol">Relative tolerance for float comparison.</param>
+    /// <param name="atol">Absolute tolerance for float comparison.</param>
+    public EqualityReport SeriesEqual(
+        Series other,
+        bool checkDtypes = true,
+        bool checkNames = true,
+        bool checkOrder = true,
+        bool checkExact = false,
+        double rtol = 1e-5,
+        double atol = 1e-8)
+    {
+        var json = PolarsWrapper.SeriesEquals(Handle, other.Handle, checkDtypes, checkNames, checkOrder, checkExact, rtol, atol);
+        return EqualityReport.Parse(json);
+    }
+
+    /// <summary>
+    /// Assert that this Series equals another.
+    /// Throws <see cref="PolarsAssertionException"/> with the difference report otherwise.
+    /// </summary>
+    /// <inheritdoc cref="SeriesEqual" path="/param"/>
+    public void AssertSeriesEqual(
+        Series other,
+        bool checkDtypes = true,
+        bool checkNames = true,
+        bool checkOrder = true,
+        bool checkExact = false,
+        double rtol = 1e-5,
+        double atol = 1e-8)
+    {
+        var report = SeriesEqual(other, checkDtypes, checkNames, checkOrder, checkExact, rtol, atol);
+        if (!report.IsEqual) throw new PolarsAssertionException("Series", report);
+    }
+    // ==========================================
     // Null Checks & Boolean Masks
     // ==========================================
 
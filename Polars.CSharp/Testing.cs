@@ -0,0 +1,135 @@
+using System.Text.Json;
+
+namespace Polars.CSharp;
+
+/// <summary>
+/// A dtype difference between two columns with the same name.
+/// </summary>
+/// <param name="Column">Column name.</param>
+/// <param name="Left">Data type on the left side.</param>
+/// <param name="Right">Data type on the right side.</param>
+public record DtypeMismatch(string Column, string Left, string Right);
+
+/// <summary>
+/// Result of comparing two DataFrames or Series.
+/// Describes the first difference found instead of a plain bool.
+/// </summary>
+public class EqualityReport
+{
+    /// <summary>
+    /// True if both sides are considered equal under the given options.
+    /// </summary>
+    public bool IsEqual { get; private init; }
+    /// <summary>
+    /// Kind of the first difference: "columns", "column_order", "name", "dtype", "shape" or "value".
+    /// Null when equal.
+    /// </summary>
+    public string? Kind { get; private init; }
+    /// <summary>
+    /// Human readable description of the first difference.
+    /// </summary>
+    public string? Message { get; private init; }
+    /// <summary>
+    /// Column (or Series name) where the first difference was found.
+    /// </summary>
+    public string? Column { get; private init; }
+    /// <summary>
+    /// Row index of the first mismatching value (after sorting, if row order is ignored).
+    /// </summary>
+    public long? Row { get; private init; }
+    /// <summary>
+    /// Left side value (or name / height / column list, depending on <see cref="Kind"/>), formatted as text.
+    /// </summary>
+    public string? Left { get; private init; }
+    /// <summary>
+    /// Right side value (or name / height / column list, depending on <see cref="Kind"/>), formatted as text.
+    /// </summary>
+    public string? Right { get; private init; }
+    /// <summary>
+    /// Number of mismatching rows in the first differing column.
+    /// </summary>
+    public long MismatchCount { get; private init; }
+    /// <summary>
+    /// All dtype differences (DataFrame comparison only).
+    /// </summary>
+    public IReadOnlyList<DtypeMismatch> DtypeMismatches { get; private init; } = [];
+    /// <summary>
+    /// Columns that only exist in the left frame.
+    /// </summary>
+    public IReadOnlyList<string> OnlyInLeft { get; private init; } = [];
+    /// <summary>
+    /// Columns that only exist in the right frame.
+    /// </summary>
+    public IReadOnlyList<string> OnlyInRight { get; private init; } = [];
+
+    internal static EqualityReport Parse(string json)
+    {
+        using var doc = JsonDocument.Parse(json);
+        var root = doc.RootElement;
+
+        string? Str(string name) => root.TryGetProperty(name, out var v) ? v.ValueKind switch
+        {
+            JsonValueKind.String => v.GetString(),
+            JsonValueKind.Null => null,
+            _ => v.GetRawText()
+        } : null;
+
+        List<string> StrList(string name) => root.TryGetProperty(name, out var v)
+            ? v.EnumerateArray().Select(e => e.GetString()!).ToList()
+            : [];
+
+        var dtypes = new List<DtypeMismatch>();
+        if (root.TryGetProperty("dtype_mismatches", out var arr))
+        {
+            foreach (var e in arr.EnumerateArray())
+            {
+                dtypes.Add(new DtypeMismatch(
+                    e.GetProperty("column").GetString()!,
+                    e.GetProperty("left").GetString()!,
+                    e.GetProperty("right").GetString()!));
+            }
+        }
+        // Series 比较时只有单个 dtype 差异
+        else if (root.TryGetProperty("left_dtype", out var ld))
+        {
+            dtypes.Add(new DtypeMismatch(Str("column") ?? "", ld.GetString()!, Str("right_dtype")!));
+        }
+
+        return new EqualityReport
+        {
+            IsEqual = root.GetProperty("equal").GetBoolean(),
+            Kind = Str("kind"),
+            Message = Str("message"),
+            Column = Str("column"),
+            Row = root.TryGetProperty("row", out var row) ? row.GetInt64() : null,
+            Left = Str("left"),
+            Right = Str("right"),
+            MismatchCount = root.TryGetProperty("mismatch_count", out var mc) ? mc.GetInt64() : 0,
+            DtypeMismatches = dtypes,
+            OnlyInLeft = StrList("only_in_left"),
+            OnlyInRight = StrList("only_in_right"),
+        };
+    }
+
+    /// <summary>
+    /// Returns the difference message, or "equal".
+    /// </summary>
+    public override string ToString() => IsEqual ? "equal" : Message ?? Kind ?? "not equal";
+}
+
+/// <summary>
+/// Thrown by <c>AssertFrameEqual</c> / <c>AssertSeriesEqual</c> when the two sides differ.
+/// </summary>
+public class PolarsAssertionException : Exception
+{
+    /// <summary>
+    /// The comparison report describing the difference.
+    /// </summary>
+    public EqualityReport Report { get; }
+
+    internal PolarsAssertionException(string subject, EqualityReport report)
+        : base($"{subject} are different ({report.Kind}): {report.Message}")
+    {
+        Report = report;
+    }
+}
@@ -110,6 +110,89 @@ id;date_col;val_col
         use dfJson = DataFrame.ReadJson jsonFile.Path
         Assert.Equal(3L, dfJson.Rows)
         Assert.Equal(2L, dfJson.Int("a", 1).Value)
+
+    [<Fact>]
+    member _.``IO: Read Excel with range and schema`` () =
+        use xlsx = new DisposableFile ".xlsx"
+
+        // calamine 只能读，手工拼一个最小的 xlsx
+        let sheet =
+            System.String.Join("", [|
+                """<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData>"""
+                """<row r="1"><c r="A1" t="inlineStr"><is><t>name</t></is></c><c r="B1" t="inlineStr"><is><t>qty</t></is></c><c r="C1" t="inlineStr"><is><t>ok</t></is></c></row>"""
+                """<row r="2"><c r="A2" t="inlineStr"><is><t>apple</t></is></c><c r="B2"><v>3</v></c><c r="C2" t="b"><v>1</v></c></row>"""
+                """<row r="3"><c r="A3" t="inlineStr"><is><t>pear</t></is></c><c r="B3"><v>5</v></c><c r="C3" t="b"><v>0</v></c></row>"""
+                """</sheetData></worksheet>"""
+            |])
+        let parts = [
+            ("[Content_Types].xml",
+             """<?xml version="1.0" encoding="UTF-8"?><Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/><Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/></Types>""")
+            ("_rels/.rels",
+             """<?xml version="1.0" encoding="UTF-8"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>""")
+            ("xl/workbook.xml",
+             """<?xml version="1.0" encoding="UTF-8"?><workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets><sheet name="Data" sheetId="1" r:id="rId1"/></sheets></workbook>""")
+            ("xl/_rels/workbook.xml.rels",
+             """<?xml version="1.0" encoding="UTF-8"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/></Relationships>""")
+            ("xl/worksheets/sheet1.xml", sheet)
+        ]
+
+        do
+            use zip = System.IO.Compression.ZipFile.Open(xlsx.Path, System.IO.Compression.ZipArchiveMode.Create)
+            for (name, xml) in parts do
+                use writer = new StreamWriter(zip.CreateEntry(name).Open())
+                writer.Write xml
+
+        use df = DataFrame.ReadExcel(xlsx.Path, sheetName = "Data")
+        Assert.Equal(2L, df.Rows)
+        Assert.Equal("pear", df.String("name", 1).Value)
+        Assert.Equal(Some true, df.Bool("ok", 0))
+
+        use part = DataFrame.ReadExcel(xlsx.Path, cellRange = "A1:B3", schema = Map [ "qty", Int64 ])
+        Assert.Equal<string list>(["name"; "qty"], part.ColumnNames)
+        Assert.Equal(5L, part.Int("qty", 1).Value)
+
+        // 超出 XFD 的列应该报错
+        Assert.ThrowsAny<exn>(fun () -> DataFrame.ReadExcel(xlsx.Path, cellRange = "XFE1:XFE2") |> ignore) |> ignore
+
+    [<Fact>]
+    member _.``IO: Write & Read SQLite table`` () =
+        use db = new DisposableFile ".db"
+
+        let s1 = Series.create("a", [1; 2; 3])
+        let s2 = Series.create("b", ["x"; "y"; "z"])
+        use df = DataFrame.create [s1; s2]
+
+        df.WriteSqlite(db.Path, "t") |> ignore
+        use dfRead = DataFrame.ReadSqlite(db.Path, "SELECT * FROM t WHERE a >= 2 ORDER BY a")
+        Assert.Equal(2L, dfRead.Rows)
+        Assert.Equal("y", dfRead.String("b", 0).Value)
+
+        // 已存在的表: Create 报错, Append 追加
+        Assert.ThrowsAny<exn>(fun () -> df.WriteSqlite(db.Path, "t") |> ignore) |> ignore
+        df.WriteSqlite(db.Path, "t", Append) |> ignore
+        use count = DataFrame.ReadSqlite(db.Path, "SELECT COUNT(*) AS n FROM t")
+        Assert.Equal(6L, count.Int("n", 0).Value)
+
+    [<Fact>]
+    member _.``IO: Write & Read Avro with projection`` () =
+        use avroFile = new DisposableFile ".avro"
+
+        let s1 = Series.create("a", [1; 2; 3])
+        let s2 = Series.create("b", ["x"; "y"; "z"])
+        use df = DataFrame.create [s1; s2]
+
+        df.WriteAvro(avroFile.Path, Snappy) |> ignore
+        Assert.True(File.Exists avroFile.Path, "Avro file not found")
+
+        use dfAvro = DataFrame.ReadAvro avroFile.Path
+        Assert.Equal(3L, dfAvro.Rows)
+        Assert.Equal("z", dfAvro.String("b", 2).Value)
+
+        // 只读 b 列的前 2 行
+        use dfPart = DataFrame.ReadAvro(avroFile.Path, columns = ["b"], nRows = 2)
+        Assert.Equal(2L, dfPart.Rows)
+        Assert.Equal<string list>(["b"], dfPart.ColumnNames)
+        Assert.Equal("y", dfPart.String("b", 1).Value)
     // [<Fact>]
     // member _.``Streaming: Debug Sink`` () =
     //     // 1. 准备数据
@@ -528,4 +528,235 @@ type ``Complex Query Tests`` () =
 
         // 1005, AAPL (超时，应为 null)
         Assert.Equal("AAPL", res.String("ticker", 2).Value)
-        Assert.True(res.Float("bid", 2).IsNone) // 验证 Tolerance 生效
\ No newline at end of file
+        Assert.True(res.Float("bid", 2).IsNone) // 验证 Tolerance 生效
+
+    [<Fact>]
+    member _.``Sort by multiple keys with per-key direction`` () =
+        use csv = new TempCsv "g,v\nb,1\na,2\nb,3\na,4"
+        let df = DataFrame.ReadCsv (path=csv.Path, tryParseDates=false)
+
+        let res = df |> Polars.sortBy [Polars.col "g"; Polars.col "v"] [false; true]
+        Assert.Equal("a", res.String("g", 0).Value)
+        Assert.Equal(4L, res.Int("v", 0).Value)
+        Assert.Equal(2L, res.Int("v", 1).Value)
+        Assert.Equal(3L, res.Int("v", 2).Value)
+
+        let lazyRes =
+            df.Lazy()
+            |> Polars.sortByLazy [Polars.col "g"; Polars.col "v"] [true]
+            |> Polars.collect
+        Assert.Equal("b", lazyRes.String("g", 0).Value)
+        Assert.Equal(3L, lazyRes.Int("v", 0).Value)
+
+    [<Fact>]
+    member _.``Join Where on a range`` () =
+        use iv = new TempCsv "id,start,end\n1,0,5\n2,10,20"
+        use pts = new TempCsv "t\n3\n12\n7"
+        let intervals = DataFrame.ReadCsv (path=iv.Path, tryParseDates=false)
+        let points = DataFrame.ReadCsv (path=pts.Path, tryParseDates=false)
+
+        let res =
+            intervals
+            |> Polars.joinWhere points [ Polars.col "t" .>= Polars.col "start"; Polars.col "t" .< Polars.col "end" ]
+            |> Polars.sort (Polars.col "id") false
+        Assert.Equal(2L, res.Rows)
+        Assert.Equal(3L, res.Int("t", 0).Value)
+        Assert.Equal(12L, res.Int("t", 1).Value)
+
+    [<Fact>]
+    member _.``AsOf Join with float tolerance and strict strategy`` () =
+        use leftCsv = new TempCsv "t\n1.0\n2.0"
+        use rightCsv = new TempCsv "t,v\n0.5,a\n1.9,b"
+        let lfLeft = LazyFrame.ScanCsv leftCsv.Path
+        let lfRight = LazyFrame.ScanCsv rightCsv.Path
+
+        // 浮点容差 0.2: 1.0 最近的是 0.5 (差 0.5) -> null; 2.0 匹配 1.9
+        let res =
+            lfLeft
+            |> Polars.joinAsOf lfRight (Polars.col "t") (Polars.col "t") [] [] None (Some "0.2")
+            |> Polars.collect
+        Assert.True(res.String("v", 0).IsNone)
+        Assert.Equal("b", res.String("v", 1).Value)
+
+        // 无法识别的策略直接报错
+        Assert.ThrowsAny<exn>(fun () ->
+            lfLeft
+            |> Polars.joinAsOf lfRight (Polars.col "t") (Polars.col "t") [] [] (Some "sideways") None
+            |> Polars.collect
+            |> ignore) |> ignore
+
+    [<Fact>]
+    member _.``Dynamic GroupBy over integer index`` () =
+        use csv = new TempCsv "t,v\n0,1\n1,2\n2,3\n5,4\n6,5\n9,6"
+        let df = DataFrame.ReadCsv (path=csv.Path, tryParseDates=false)
+
+        // 整数索引用 "3i": 窗口 [0,3) [3,6) [6,9) [9,12)
+        let res = df |> Polars.groupByDynamic (Polars.col "t") "3i" [ (Polars.col "v").Sum().Alias "s" ]
+        Assert.Equal(4L, res.Rows)
+        Assert.Equal(6L, res.Int("s", 0).Value)
+        Assert.Equal(4L, res.Int("s", 1).Value)
+        Assert.Equal(6L, res.Int("s", 3).Value)
+
+    [<Fact>]
+    member _.``Rolling GroupBy over integer index`` () =
+        use csv = new TempCsv "t,v\n1,1\n2,2\n3,3\n5,4\n6,5"
+        let df = DataFrame.ReadCsv (path=csv.Path, tryParseDates=false)
+
+        let res = df |> Polars.rolling (Polars.col "t") "2i" [ (Polars.col "v").Sum().Alias "s" ]
+        Assert.Equal(5L, res.Rows)
+        Assert.Equal(5L, res.Int("s", 2).Value)
+        Assert.Equal(9L, res.Int("s", 4).Value)
+
+    [<Fact>]
+    member _.``Partition By keeps first-seen group order`` () =
+        use csv = new TempCsv "g,v\nb,1\na,2\nb,3"
+        let df = DataFrame.ReadCsv (path=csv.Path, tryParseDates=false)
+
+        let parts = df |> Polars.partitionBy ["g"]
+        Assert.Equal(2, parts.Length)
+        Assert.Equal(2L, parts.[0].Rows)
+        Assert.Equal("b", parts.[0].String("g", 0).Value)
+        Assert.Equal(2L, parts.[1].Int("v", 0).Value)
+
+    [<Fact>]
+    member _.``Unique keeps first rows in order`` () =
+        use csv = new TempCsv "k,v\na,1\nb,2\na,3\nc,4"
+        let df = DataFrame.ReadCsv (path=csv.Path, tryParseDates=false)
+
+        let res = df |> Polars.unique ["k"]
+        Assert.Equal(3L, res.Rows)
+        Assert.Equal(1L, res.Int("v", 0).Value)
+        Assert.Equal(4L, res.Int("v", 2).Value)
+        Assert.Equal(3UL, df |> Polars.nUnique ["k"])
+        Assert.Equal(4UL, df |> Polars.nUnique [])
+
+    [<Fact>]
+    member _.``Row index, slice, reverse, shift and gatherEvery`` () =
+        use csv = new TempCsv "v\n10\n20\n30\n40\n50"
+        let df = DataFrame.ReadCsv (path=csv.Path, tryParseDates=false)
+
+        let res =
+            df
+            |> Polars.withRowIndex "idx" 0u
+            |> Polars.reverse
+            |> Polars.slice 0L 3UL
+            |> Polars.gatherEvery 2UL 0UL
+        Assert.Equal(2L, res.Rows)
+        Assert.Equal(4L, res.Int("idx", 0).Value)
+        Assert.Equal(30L, res.Int("v", 1).Value)
+
+        let shifted = df |> Polars.shift 1L
+        Assert.True(shifted.Int("v", 0).IsNone)
+        Assert.Equal(10L, shifted.Int("v", 1).Value)
+
+        Assert.ThrowsAny<exn>(fun () -> df |> Polars.gatherEvery 0UL 0UL |> ignore) |> ignore
+
+    [<Fact>]
+    member _.``Frame fillNull, fillNan and interpolate`` () =
+        use csv = new TempCsv "a,f\n1,1.0\n,NaN\n,\n4,4.0"
+        let df = DataFrame.ReadCsv (path=csv.Path, tryParseDates=false)
+
+        let filled = df.FillNull(Polars.lit 0, columns = ["a"])
+        Assert.Equal(0L, filled.Int("a", 1).Value)
+        Assert.True(filled.Float("f", 2).IsNone)
+
+        let noNan = df.FillNan(Polars.lit 0.0)
+        Assert.Equal(0.0, noNan.Float("f", 1).Value)
+
+        let interp = df.Interpolate(["a"])
+        Assert.Equal(3.0, interp.Float("a", 2).Value)
+
+    [<Fact>]
+    member _.``Transpose, toDummies and upsample`` () =
+        use csv = new TempCsv "name,a,b\nx,1,3\ny,2,4"
+        let df = DataFrame.ReadCsv (path=csv.Path, tryParseDates=false)
+
+        let t = df.Transpose(keepNamesAs = "col", headerColumn = "name")
+        Assert.Equal<string list>(["col"; "x"; "y"], t.ColumnNames)
+        Assert.Equal(2L, t.Int("y", 0).Value)
+
+        let dummies = df.ToDummies(columns = ["name"])
+        Assert.Equal<string list>(["name_x"; "name_y"; "a"; "b"], dummies.ColumnNames)
+
+        use dates = new TempCsv "t,v\n2024-01-01,1\n2024-01-03,3"
+        let ts = DataFrame.ReadCsv (path=dates.Path, tryParseDates=true)
+        let up = ts.Upsample("t", "1d")
+        Assert.Equal(3L, up.Rows)
+        Assert.True(up.Int("v", 1).IsNone)
+
+    [<Fact>]
+    member _.``Pivot Len counts rows including nulls`` () =
+        use csv = new TempCsv "k,c,v\na,x,1\na,x,\na,y,3\nb,x,4"
+        let df = DataFrame.ReadCsv (path=csv.Path, tryParseDates=false)
+
+        let len = df |> Polars.pivot ["k"] ["c"] ["v"] PivotAgg.Len |> Polars.sort (Polars.col "k") false
+        Assert.Equal(2L, len.Int("x", 0).Value)
+        Assert.True(len.Int("y", 1).IsNone)
+
+        let count = df |> Polars.pivot ["k"] ["c"] ["v"] PivotAgg.Count |> Polars.sort (Polars.col "k") false
+        Assert.Equal(1L, count.Int("x", 0).Value)
+
+    [<Fact>]
+    member _.``Row values and JSON records`` () =
+        use csv = new TempCsv "i,s,t\n1,a,2023-11-14 22:13:20\n,b,2023-11-15 00:00:00"
+        let df = DataFrame.ReadCsv (path=csv.Path, tryParseDates=true)
+
+        let row = df.Row 0L
+        Assert.Equal(box 1L, row.[0])
+        Assert.Equal(box "a", row.[1])
+        let t = row.[2] :?> DateTime
+        Assert.Equal(DateTimeKind.Unspecified, t.Kind)
+        Assert.Null(df.Row(-1L).[0])
+
+        Assert.Contains("\"s\":\"b\"", df.RowsToJson(offset = -1L))
+
+    [<Fact>]
+    member _.``assertFrameEqual reports the first difference`` () =
+        use csv = new TempCsv "a,b\n1.0,x\n2.0,y"
+        let left = DataFrame.ReadCsv (path=csv.Path, tryParseDates=false)
+        Polars.assertFrameEqual left (left.Clone())
+
+        use other = new TempCsv "a,b\n1.0,x\n2.5,y"
+        let right = DataFrame.ReadCsv (path=other.Path, tryParseDates=false)
+        let ex = Assert.ThrowsAny<exn>(fun () -> Polars.assertFrameEqual left right)
+        Assert.Contains("row 1", ex.Message)
+
+        Polars.assertSeriesEqual (left.Column "b") (left.Column "b")
+        Assert.ThrowsAny<exn>(fun () -> Polars.assertSeriesEqual (left.Column "a") (left.Column "b")) |> ignore
+
+    [<Fact>]
+    member _.``Estimated size, chunks and rechunk`` () =
+        use csv = new TempCsv "a,b\n1,2\n3,4"
+        let df = DataFrame.ReadCsv (path=csv.Path, tryParseDates=false)
+
+        Assert.True(df.EstimatedSize >= 32L)
+        Assert.Equal(1L, df.NChunks)
+        Assert.Same(df, df.Rechunk().ShrinkToFit())
+        Assert.Equal(3L, df.Int("a", 1).Value)
+
+    [<Fact>]
+    member _.``In-place insert, replace, hstack, vstack and multi-column drop`` () =
+        use csv = new TempCsv "a,b\n1,3\n2,4"
+        let df = DataFrame.ReadCsv (path=csv.Path, tryParseDates=false)
+        let extra = DataFrame.ReadCsv (path=csv.Path, tryParseDates=false)
+
+        let z = (extra.Column "b").Rename "z"
+        df.InsertColumn(0, z) |> ignore
+        Assert.Equal<string list>(["z"; "a"; "b"], df.ColumnNames)
+
+        df.ReplaceColumn("a", extra.Column "b") |> ignore
+        Assert.Equal<string list>(["z"; "a"; "b"], df.ColumnNames)
+        Assert.Equal(4L, df.Int("a", 1).Value)
+
+        let c = (extra.Column "a").Rename "c"
+        df.HStack([ c ]) |> ignore
+        Assert.Equal(4L, df.Columns)
+
+        df.VStack(df) |> ignore
+        Assert.Equal(4L, df.Rows)
+        Assert.ThrowsAny<exn>(fun () -> df.VStack(extra) |> ignore) |> ignore
+
+        let dropped = df.Drop(["z"; "c"])
+        Assert.Equal<string list>(["a"; "b"], dropped.ColumnNames)
+        Assert.ThrowsAny<exn>(fun () -> df.Drop(["missing"]) |> ignore) |> ignore
+        Assert.Equal(4L, df.Drop(["missing"], strict = false).Columns)
@@ -86,18 +86,47 @@ module Polars =
         let h = PolarsWrapper.Sort(df.Handle, expr.CloneHandle(), desc)
         new DataFrame(h)
     let orderBy (expr: Expr) (desc: bool) (df: DataFrame) = sort expr desc df
+    /// <summary> Sort the DataFrame by several keys, one direction per key (or a single direction for all). </summary>
+    let sortBy (exprs: Expr list) (descending: bool list) (df: DataFrame) : DataFrame =
+        let handles = exprs |> List.map (fun e -> e.CloneHandle()) |> List.toArray
+        new DataFrame(PolarsWrapper.Sort(df.Handle, handles, List.toArray descending))
     /// <summary> Group by keys and apply aggregations. </summary>
     let groupBy (keys: Expr list) (aggs: Expr list) (df: DataFrame) : DataFrame =
         let kHandles = keys |> List.map (fun e -> e.CloneHandle()) |> List.toArray
         let aHandles = aggs |> List.map (fun e -> e.CloneHandle()) |> List.toArray
         let h = PolarsWrapper.GroupByAgg(df.Handle, kHandles, aHandles)
         new DataFrame(h)
+    /// <summary> Group by fixed time windows (e.g. "5m") over a sorted index column and apply aggregations. </summary>
+    let groupByDynamic (index: Expr) (every: string) (aggs: Expr list) (df: DataFrame) : DataFrame =
+        let aHandles = aggs |> List.map (fun e -> e.CloneHandle()) |> List.toArray
+        let h = PolarsWrapper.GroupByDynamic(df.Handle, index.CloneHandle(), [||], every, null, null,
+                                             "left", false, "left", "window", aHandles)
+        new DataFrame(h)
+    /// <summary> Rolling group-by: every row aggregates the look-back window (t - period, t] of the index column. </summary>
+    let rolling (index: Expr) (period: string) (aggs: Expr list) (df: DataFrame) : DataFrame =
+        let aHandles = aggs |> List.map (fun e -> e.CloneHandle()) |> List.toArray
+        new DataFrame(PolarsWrapper.Rolling(df.Handle, index.CloneHandle(), [||], period, null, "right", aHandles))
+    /// <summary> Split the DataFrame into one DataFrame per group, in the order the groups first appear. </summary>
+    let partitionBy (by: string list) (df: DataFrame) : DataFrame list =
+        let (frames, keys) = PolarsWrapper.PartitionBy(df.Handle, List.toArray by, true, true)
+        keys.Dispose()
+        frames |> Array.map (fun h -> new DataFrame(h)) |> Array.toList
+    /// <summary> Drop duplicate rows (compared on subset, empty = all columns), keeping the first occurrence in order. </summary>
+    let unique (subset: string list) (df: DataFrame) : DataFrame =
+        new DataFrame(PolarsWrapper.Unique(df.Handle, List.toArray subset, PlUniqueKeep.First, true))
+    /// <summary> Number of distinct rows (compared on subset, empty = all columns). </summary>
+    let nUnique (subset: string list) (df: DataFrame) : uint64 =
+        PolarsWrapper.NUnique(df.Handle, List.toArray subset)
     /// <summary> Perform a join between two DataFrames. </summary>
     let join (other: DataFrame) (leftOn: Expr list) (rightOn: Expr list) (how: JoinType) (left: DataFrame) : DataFrame =
         let lHandles = leftOn |> List.map (fun e -> e.CloneHandle()) |> List.toArray
         let rHandles = rightOn |> List.map (fun e -> e.CloneHandle()) |> List.toArray
         let h = PolarsWrapper.Join(left.Handle, other.Handle, lHandles, rHandles, how.ToNative())
         new DataFrame(h)
+    /// <summary> Join on inequality / range predicates referencing columns of both frames. </summary>
+    let joinWhere (other: DataFrame) (predicates: Expr list) (left: DataFrame) : DataFrame =
+        let handles = predicates |> List.map (fun e -> e.CloneHandle()) |> List.toArray
+        new DataFrame(PolarsWrapper.JoinWhere(left.Handle, other.Handle, handles, "_right"))
     /// <summary> Concatenate multiple DataFrames vertically. </summary>
     let concat (dfs: DataFrame list) : DataFrame =
         let handles = dfs |> List.map (fun df -> df.CloneHandle()) |> List.toArray
@@ -121,6 +150,21 @@ module Polars =
     let tail (n: int) (df: DataFrame) : DataFrame =
         let h = PolarsWrapper.Tail(df.Handle, uint n)
         new DataFrame(h)
+    /// <summary> Take length rows starting at offset (negative offsets count from the end). </summary>
+    let slice (offset: int64) (length: uint64) (df: DataFrame) : DataFrame =
+        new DataFrame(PolarsWrapper.Slice(df.Handle, offset, length))
+    /// <summary> Reverse the row order. </summary>
+    let reverse (df: DataFrame) : DataFrame =
+        new DataFrame(PolarsWrapper.Reverse(df.Handle))
+    /// <summary> Shift rows by n (negative = up), filling with nulls. </summary>
+    let shift (n: int64) (df: DataFrame) : DataFrame =
+        new DataFrame(PolarsWrapper.Shift(df.Handle, n))
+    /// <summary> Prepend a row index column starting at offset. </summary>
+    let withRowIndex (name: string) (offset: uint32) (df: DataFrame) : DataFrame =
+        new DataFrame(PolarsWrapper.WithRowIndex(df.Handle, name, offset))
+    /// <summary> Take every n-th row, starting at offset. </summary>
+    let gatherEvery (n: uint64) (offset: uint64) (df: DataFrame) : DataFrame =
+        new DataFrame(PolarsWrapper.GatherEvery(df.Handle, n, offset))
     /// <summary> Explode list-like columns into multiple rows. </summary>
     let explode (exprs: Expr list) (df: DataFrame) : DataFrame =
         let handles = exprs |> List.map (fun e -> e.CloneHandle()) |> List.toArray
@@ -192,6 +236,11 @@ module Polars =
         new LazyFrame(h)
     /// <summary> Alias for sortLazy </summary>
     let orderByLazy (expr: Expr) (desc: bool) (lf: LazyFrame) = sortLazy expr desc lf
+    /// <summary> Sort the LazyFrame by several keys, one direction per key (or a single direction for all). </summary>
+    let sortByLazy (exprs: Expr list) (descending: bool list) (lf: LazyFrame) : LazyFrame =
+        let lfClone = lf.CloneHandle()
+        let handles = exprs |> List.map (fun e -> e.CloneHandle()) |> List.toArray
+        new LazyFrame(PolarsWrapper.LazySort(lfClone, handles, List.toArray descending))
 
     /// <summary> Limit the number of rows in the LazyFrame. </summary>
     let limit (n: uint) (lf: LazyFrame) : LazyFrame =
@@ -525,3 +574,24 @@ module Polars =
         use df = new DataFrame(h)
         Printing.printTable df 10
         s
+
+    // 解析 native 的比较报告，不相等时抛出包含第一处差异的异常
+    let private failIfDifferent (subject: string) (json: string) =
+        use doc = System.Text.Json.JsonDocument.Parse json
+        let root = doc.RootElement
+        if not (root.GetProperty("equal").GetBoolean()) then
+            failwithf "%s are different: %s" subject (root.GetProperty("message").GetString())
+
+    /// <summary>
+    /// Assert that two DataFrames are equal (dtypes, column order and row order included, floats within default tolerance).
+    /// </summary>
+    let assertFrameEqual (left: DataFrame) (right: DataFrame) : unit =
+        PolarsWrapper.DataFrameEquals(left.Handle, right.Handle, true, true, true, false, 1e-5, 1e-8)
+        |> failIfDifferent "DataFrames"
+
+    /// <summary>
+    /// Assert that two Series are equal (names, dtypes and order included, floats within default tolerance).
+    /// </summary>
+    let assertSeriesEqual (left: Series) (right: Series) : unit =
+        PolarsWrapper.SeriesEquals(left.Handle, right.Handle, true, true, true, false, 1e-5, 1e-8)
+        |> failIfDifferent "Series"
@@ -102,7 +102,7 @@ type JoinType =
 /// Specifies the aggregation function for pivot operations.
 /// </summary>
 type PivotAgg =
-    | First | Sum | Min | Max | Mean | Median | Count | Last
+    | First | Sum | Min | Max | Mean | Median | Count | Len | Last
     
     member internal this.ToNative() =
         match this with
@@ -113,7 +113,32 @@ type PivotAgg =
         | Mean -> PlPivotAgg.Mean
         | Median -> PlPivotAgg.Median
         | Count -> PlPivotAgg.Count
+        | Len -> PlPivotAgg.Len
         | Last -> PlPivotAgg.Last
+
+/// <summary>
+/// Compression codec used when writing Avro files.
+/// </summary>
+type AvroCompression =
+    | Uncompressed | Deflate | Snappy
+
+    member internal this.ToNative() =
+        match this with
+        | Uncompressed -> PlAvroCompression.None
+        | Deflate -> PlAvroCompression.Deflate
+        | Snappy -> PlAvroCompression.Snappy
+
+/// <summary>
+/// How a DataFrame is written into a SQLite table.
+/// </summary>
+type SqliteWriteMode =
+    | Create | Append | Replace
+
+    member internal this.ToNative() =
+        match this with
+        | Create -> PlSqliteWriteMode.Create
+        | Append -> PlSqliteWriteMode.Append
+        | Replace -> PlSqliteWriteMode.Replace
 /// <summary>
 /// Represents a Polars Expression, which can be a column reference, a literal value, or a computation.
 /// </summary>
@@ -856,7 +881,7 @@ type Series(handle: SeriesHandle) =
     member _.Int(index: int) : int64 option = 
         PolarsWrapper.SeriesGetInt(handle, int64 index) |> Option.ofNullable
 
-    /// <summary> Get value as Double Option. Handles Float32/Float64. </summary>
+    /// <summary> Get value as Double Option. Handles Float32/Float64, integers and Decimal are widened. </summary>
     member _.Float(index: int) : float option = 
         PolarsWrapper.SeriesGetDouble(handle, int64 index) |> Option.ofNullable
 
@@ -879,6 +904,7 @@ type Series(handle: SeriesHandle) =
     member _.Time(index: int) : TimeOnly option = 
         PolarsWrapper.SeriesGetTime(handle, int64 index) |> Option.ofNullable
 
+    /// <summary> Get value as DateTime Option. Timezone-aware values are in UTC, naive ones are Unspecified. </summary>
     member _.Datetime(index: int) : DateTime option = 
         PolarsWrapper.SeriesGetDatetime(handle, int64 index) |> Option.ofNullable
 
@@ -1033,6 +1059,39 @@ and DataFrame(handle: DataFrameHandle) =
         new DataFrame(PolarsWrapper.ReadJson path)
     /// <summary> Read an IPC file into a DataFrame (Eager). </summary>
     static member ReadIpc (path: string) = new DataFrame(PolarsWrapper.ReadIpc path)
+    /// <summary> Read a sheet of an Excel / ODS spreadsheet into a DataFrame (Eager). </summary>
+    static member ReadExcel (path: string,
+                             ?sheetName: string,
+                             ?sheetIndex: int,
+                             ?hasHeader: bool,
+                             ?skipRows: int,
+                             ?cellRange: string,
+                             ?schema: Map<string, DataType>,
+                             ?tryParseDates: bool) : DataFrame =
+        let header = defaultArg hasHeader true
+        let skip = defaultArg skipRows 0
+        let index = defaultArg sheetIndex 0
+        let dates = defaultArg tryParseDates true
+
+        let schemaDict =
+            match schema with
+            | Some m ->
+                let d = Dictionary<string, DataTypeHandle>()
+                m |> Map.iter (fun k v -> d.Add(k, v.CreateHandle()))
+                d
+            | None -> null
+
+        let h = PolarsWrapper.ReadExcel(path, Option.toObj sheetName, uint64 index, header, uint64 skip,
+                                        Option.toObj cellRange, schemaDict, dates)
+        new DataFrame(h)
+    /// <summary> Run a query against a SQLite database file (Eager). </summary>
+    static member ReadSqlite (dbPath: string, query: string) : DataFrame =
+        new DataFrame(PolarsWrapper.ReadSqlite(dbPath, query))
+    /// <summary> Read an Avro file into a DataFrame (Eager), optionally projecting columns and limiting rows. </summary>
+    static member ReadAvro (path: string, ?columns: string list, ?nRows: int) : DataFrame =
+        let cols = columns |> Option.map List.toArray |> Option.toObj
+        let n = nRows |> Option.map uint64 |> Option.toNullable
+        new DataFrame(PolarsWrapper.ReadAvro(path, cols, n))
     static member FromArrow (batch: Apache.Arrow.RecordBatch) : DataFrame =
         new DataFrame(PolarsWrapper.FromArrow batch)
     /// <summary> Write DataFrame to CSV. </summary>
@@ -1050,6 +1109,16 @@ and DataFrame(handle: DataFrameHandle) =
     member this.WriteIpc(path: string)=
         PolarsWrapper.WriteIpc(this.Handle, path)
         this
+    /// <summary> Write DataFrame to a table in a SQLite database file. </summary>
+    member this.WriteSqlite(dbPath: string, table: string, ?mode: SqliteWriteMode) =
+        let m = defaultArg mode Create
+        PolarsWrapper.WriteSqlite(this.Handle, dbPath, table, m.ToNative())
+        this
+    /// <summary> Write DataFrame to an Avro file. </summary>
+    member this.WriteAvro(path: string, ?compression: AvroCompression) =
+        let c = defaultArg compression Uncompressed
+        PolarsWrapper.WriteAvro(this.Handle, path, c.ToNative())
+        this
     /// <summary>
     /// Write DataFrame to a JSON file (standard array format).
     /// </summary>
@@ -1105,6 +1174,40 @@ and DataFrame(handle: DataFrameHandle) =
     member this.Drop(name: string) : DataFrame =
         new DataFrame(PolarsWrapper.Drop(handle, name))
 
+    /// <summary>
+    /// Remove several columns. With strict (default), a missing column raises an error.
+    /// </summary>
+    member this.Drop(names: string list, ?strict: bool) : DataFrame =
+        new DataFrame(PolarsWrapper.DropMany(handle, List.toArray names, defaultArg strict true))
+
+    /// <summary>
+    /// Insert a Series as a new column at index (in place).
+    /// </summary>
+    member this.InsertColumn(index: int, series: Series) : DataFrame =
+        PolarsWrapper.InsertColumn(handle, index, series.Handle)
+        this
+
+    /// <summary>
+    /// Replace an existing column with a Series, keeping its position and name (in place).
+    /// </summary>
+    member this.ReplaceColumn(name: string, series: Series) : DataFrame =
+        PolarsWrapper.ReplaceColumn(handle, name, series.Handle)
+        this
+
+    /// <summary>
+    /// Append Series as new columns at the end (in place).
+    /// </summary>
+    member this.HStack(columns: Series list) : DataFrame =
+        PolarsWrapper.HStack(handle, columns |> List.map (fun s -> s.Handle) |> List.toArray)
+        this
+
+    /// <summary>
+    /// Append the rows of another DataFrame (in place). extend copies the data instead of appending chunks.
+    /// </summary>
+    member this.VStack(other: DataFrame, ?extend: bool) : DataFrame =
+        PolarsWrapper.VStack(handle, other.Handle, defaultArg extend false)
+        this
+
     /// <summary>
     /// Rename a column. Returns a new DataFrame.
     /// </summary>
@@ -1139,11 +1242,67 @@ and DataFrame(handle: DataFrameHandle) =
         let s = Option.toNullable seed
         
         new DataFrame(PolarsWrapper.SampleFrac(handle, frac, replace, shuff, s))
+
+    /// <summary>
+    /// Fill nulls with a value. columns: optional columns to fill (default: all).
+    /// </summary>
+    member this.FillNull(value: Expr, ?columns: string list) : DataFrame =
+        new DataFrame(PolarsWrapper.FillNull(handle, DataFrame.ToSelector columns, value.CloneHandle()))
+
+    /// <summary>
+    /// Fill NaN values of float columns with a value.
+    /// </summary>
+    member this.FillNan(value: Expr, ?columns: string list) : DataFrame =
+        new DataFrame(PolarsWrapper.FillNan(handle, DataFrame.ToSelector columns, value.CloneHandle()))
+
+    /// <summary>
+    /// Linearly interpolate nulls of numeric columns.
+    /// </summary>
+    member this.Interpolate(?columns: string list) : DataFrame =
+        new DataFrame(PolarsWrapper.Interpolate(handle, DataFrame.ToSelector columns, PlInterpolationMethod.Linear))
+
+    /// <summary>
+    /// Transpose the DataFrame. keepNamesAs stores the old column names, headerColumn supplies the new ones.
+    /// </summary>
+    member this.Transpose(?keepNamesAs: string, ?headerColumn: string) : DataFrame =
+        new DataFrame(PolarsWrapper.Transpose(handle, Option.toObj keepNamesAs, Option.toObj headerColumn, null))
+
+    /// <summary>
+    /// One-hot encode columns (default: all) into 0/1 indicator columns.
+    /// </summary>
+    member this.ToDummies(?columns: string list, ?separator: string, ?dropFirst: bool) : DataFrame =
+        let cols = columns |> Option.map List.toArray |> Option.toObj
+        new DataFrame(PolarsWrapper.ToDummies(handle, cols, Option.toObj separator, defaultArg dropFirst false, false))
+
+    /// <summary>
+    /// Insert missing rows at a regular interval (e.g. "1d") of a sorted time column, optionally per group.
+    /// </summary>
+    member this.Upsample(timeColumn: string, every: string, ?by: string list) : DataFrame =
+        let b = by |> Option.map List.toArray |> Option.toObj
+        new DataFrame(PolarsWrapper.Upsample(handle, timeColumn, every, b, true))
+
+    // 空列表表示所有列
+    static member private ToSelector (columns: string list option) : SelectorHandle =
+        match columns with
+        | Some cs when not cs.IsEmpty -> PolarsWrapper.SelectorCols(List.toArray cs)
+        | _ -> null
     // Interop
     member this.ToArrow() = PolarsWrapper.Collect handle
     member _.Rows = PolarsWrapper.DataFrameHeight handle
     member _.Columns = PolarsWrapper.DataFrameWidth handle
     member _.ColumnNames = PolarsWrapper.GetColumnNames handle |> Array.toList
+    /// <summary> Estimated heap size in bytes (an upper bound, shared buffers are counted twice). </summary>
+    member _.EstimatedSize = PolarsWrapper.DataFrameEstimatedSize handle
+    /// <summary> Largest number of chunks among the columns. </summary>
+    member _.NChunks = PolarsWrapper.DataFrameNChunks handle
+    /// <summary> Merge every column into a single chunk (in place). </summary>
+    member this.Rechunk() : DataFrame =
+        PolarsWrapper.DataFrameRechunk handle
+        this
+    /// <summary> Release unused capacity (in place). </summary>
+    member this.ShrinkToFit() : DataFrame =
+        PolarsWrapper.DataFrameShrinkToFit handle
+        this
     member this.Item 
         with get(colName: string, rowIndex: int) =
             PolarsWrapper.GetDouble(handle, colName, int64 rowIndex)
@@ -1216,6 +1375,16 @@ and DataFrame(handle: DataFrameHandle) =
     member this.Duration(col: string, row: int) : TimeSpan option =
         use s = this.Column col
         s.Duration row
+    /// <summary>
+    /// All values of one row (negative index counts from the end), read in a single native call.
+    /// </summary>
+    member this.Row(index: int64) : obj array =
+        PolarsWrapper.GetRow(handle, index)
+    /// <summary>
+    /// Rows as JSON records: [{"col": value, ...}, ...]
+    /// </summary>
+    member this.RowsToJson(?offset: int64, ?length: uint64) : string =
+        PolarsWrapper.GetRowsJson(handle, defaultArg offset 0L, defaultArg length (uint64 this.Rows))
     member this.Column(name: string) : Series =
     // 我们假设 Rust 端有 pl_dataframe_get_column
         let h = PolarsWrapper.DataFrameGetColumn(this.Handle, name)
@@ -68,4 +68,95 @@ public enum PlConcatType
     Vertical = 0,
     Horizontal = 1,
     Diagonal = 2
-}
\ No newline at end of file
+}
+// 对应 Avro 写出的压缩方式
+public enum PlAvroCompression
+{
+    None = 0,
+    Deflate = 1,
+    Snappy = 2
+}
+// 对应 SQLite 写入模式
+public enum PlSqliteWriteMode
+{
+    Create = 0,  // 表已存在则报错
+    Append = 1,  // 表不存在则新建
+    Replace = 2  // 先删除旧表
+}
+// 对应 Join 的 validate 参数
+public enum PlJoinValidation
+{
+    ManyToMany = 0, // 不检查
+    OneToOne = 1,
+    OneToMany = 2,
+    ManyToOne = 3
+}
+
+// 对应 Join 的键列合并方式
+public enum PlJoinCoalesce
+{
+    JoinSpecific = 0, // 由 Join 类型决定
+    Coalesce = 1,
+    KeepColumns = 2
+}
+
+// 对应 Join 的 maintain_order 参数
+public enum PlJoinMaintainOrder
+{
+    None = 0,
+    Left = 1,
+    Right = 2,
+    LeftRight = 3,
+    RightLeft = 4
+}
+// 对应 As-Of Join 容差的类型
+public enum PlAsofTolerance
+{
+    None = 0,
+    Int = 1,      // 整数 (和 on 列同单位)
+    Float = 2,    // 浮点数
+    Duration = 3  // 时间字符串，例如 "2h"
+}
+// 对应 fill_null 的填充策略
+public enum PlFillNullStrategy
+{
+    Forward = 0,
+    Backward = 1,
+    Min = 2,
+    Max = 3,
+    Mean = 4,
+    Zero = 5,
+    One = 6
+}
+// 对应 interpolate 的插值方法
+public enum PlInterpolationMethod
+{
+    Linear = 0,
+    Nearest = 1
+}
+// 对应 unique 的 keep 参数
+public enum PlUniqueKeep
+{
+    First = 0,
+    Last = 1,
+    Any = 2,
+    None = 3  // 丢弃所有重复行
+}
+// 对应 RowBuffer.kinds，决定每个 8 字节单元格的解释方式
+public enum PlRowCellKind : byte
+{
+    Boolean = 0,
+    Int64 = 1,
+    UInt64 = 2,
+    Float64 = 3,
+    String = 4,   // heap 偏移
+    Binary = 5,   // heap 偏移
+    Date = 6,     // 距 1970-01-01 的天数
+    Datetime = 7, // 微秒 (无时区)
+    Duration = 8, // 微秒
+    Time = 9,     // 当天的纳秒
+    Json = 10,    // heap 偏移，嵌套类型的 JSON 文本
+    Null = 11,
+    DatetimeTz = 12, // 微秒 (带时区，UTC)
+    Decimal = 13  // heap 偏移，十进制字符串
+}
@@ -35,6 +35,14 @@ public class ExprHandle : PolarsHandle
 // 3. DataFrame Handle
 public class DataFrameHandle : PolarsHandle
 {
+    // 接管 Rust 通过回调交过来的 DataFrameContext 指针
+    internal static DataFrameHandle FromOwnedPointer(IntPtr ptr)
+    {
+        var h = new DataFrameHandle();
+        h.SetHandle(ptr);
+        return h;
+    }
+
     protected override bool ReleaseHandle()
     {
         NativeBindings.pl_dataframe_free(handle);
@@ -112,4 +120,13 @@ public class DataTypeHandle : PolarsHandle
         NativeBindings.pl_datatype_free(handle);
         return true;
     }
-}
\ No newline at end of file
+}
+
+public class CloudOptionsHandle : PolarsHandle
+{
+    protected override bool ReleaseHandle()
+    {
+        NativeBindings.pl_cloud_options_free(handle);
+        return true;
+    }
+}
@@ -18,6 +18,37 @@ public unsafe delegate int UdfCallback(
     CArrowSchema* outSchema,
     byte* msgBuf
 );
+// Batch Sink 回调: 返回 0 继续，非 0 提前停止
+[UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+public delegate int DataFrameBatchCallback(IntPtr df, IntPtr userData);
+
+[UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+public unsafe delegate int ArrowBatchCallback(CArrowArray* array, CArrowSchema* schema, IntPtr userData);
+// 自定义数据源: 返回 0 = 产出了一个 batch, 1 = 数据已读完, 其它 = 错误 (消息写入 msgBuf)
+[UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+public unsafe delegate int ScanBatchCallback(
+    IntPtr userData,
+    UIntPtr batchIdx,
+    IntPtr* withColumns,
+    UIntPtr withColumnsLen,
+    UIntPtr nRows,
+    IntPtr predicate,
+    CArrowArray* outArray,
+    CArrowSchema* outSchema,
+    byte* msgBuf
+);
+// 布局与 Rust 端 RowBuffer 一致
+[StructLayout(LayoutKind.Sequential)]
+public unsafe struct PlRowBuffer
+{
+    public UIntPtr NRows;
+    public UIntPtr NCols;
+    public byte* Kinds;
+    public ulong* Cells;
+    public byte* Validity;
+    public byte* Heap;
+    public UIntPtr HeapLen;
+}
 unsafe internal partial class NativeBindings
 {
     const string LibName = "native_shim";
@@ -67,6 +98,11 @@ unsafe internal partial class NativeBindings
     public static partial ExprHandle pl_expr_lit_f32(float val);
     [LibraryImport(LibName)]
     public static partial IntPtr pl_dataframe_schema(DataFrameHandle df);
+    // --- Memory ---
+    [LibraryImport(LibName)] public static partial UIntPtr pl_dataframe_estimated_size(DataFrameHandle df);
+    [LibraryImport(LibName)] public static partial UIntPtr pl_dataframe_n_chunks(DataFrameHandle df);
+    [LibraryImport(LibName)] public static partial void pl_dataframe_rechunk(DataFrameHandle df);
+    [LibraryImport(LibName)] public static partial void pl_dataframe_shrink_to_fit(DataFrameHandle df);
     [LibraryImport(LibName)]
     public static partial UIntPtr pl_dataframe_height(DataFrameHandle df);
     
@@ -91,15 +127,59 @@ unsafe internal partial class NativeBindings
         UIntPtr row, 
         out double outVal // <--- double 也是 blittable 类型，直接用
     );
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    [return: MarshalAs(UnmanagedType.U1)]
+    public static partial bool pl_dataframe_get_bool(DataFrameHandle df, string colName, UIntPtr row, [MarshalAs(UnmanagedType.U1)] out bool outVal);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    [return: MarshalAs(UnmanagedType.U1)]
+    public static partial bool pl_dataframe_get_date(DataFrameHandle df, string colName, UIntPtr row, out int outVal);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    [return: MarshalAs(UnmanagedType.U1)]
+    public static partial bool pl_dataframe_get_time(DataFrameHandle df, string colName, UIntPtr row, out long outVal);
+    // outTz: 没有时区时为 IntPtr.Zero，否则需要 pl_free_string
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    [return: MarshalAs(UnmanagedType.U1)]
+    public static partial bool pl_dataframe_get_datetime(DataFrameHandle df, string colName, UIntPtr row, out long outVal, out PlTimeUnit outUnit, out IntPtr outTz);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    [return: MarshalAs(UnmanagedType.U1)]
+    public static partial bool pl_dataframe_get_duration(DataFrameHandle df, string colName, UIntPtr row, out long outVal, out PlTimeUnit outUnit);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    [return: MarshalAs(UnmanagedType.U1)]
+    public static partial bool pl_dataframe_get_decimal(DataFrameHandle df, string colName, UIntPtr row, out Int128 outVal, out UIntPtr outScale);
+    // 返回值需要 pl_free_bytes(ptr, len)
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial IntPtr pl_dataframe_get_binary(DataFrameHandle df, string colName, UIntPtr row, out UIntPtr outLen);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial IntPtr pl_dataframe_get_categorical(DataFrameHandle df, string colName, UIntPtr row);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial IntPtr pl_dataframe_get_nested(DataFrameHandle df, string colName, UIntPtr row);
+    [LibraryImport(LibName)] public static partial void pl_free_bytes(IntPtr ptr, UIntPtr len);
     [LibraryImport(LibName)] 
     public static partial DataFrameHandle pl_dataframe_clone(DataFrameHandle df);
     [LibraryImport(LibName)]
     public static partial LazyFrameHandle pl_dataframe_lazy(DataFrameHandle df);
     [LibraryImport(LibName)] public static partial IntPtr pl_dataframe_get_string(DataFrameHandle df, [MarshalAs(UnmanagedType.LPUTF8Str)] string colName, UIntPtr row);
+    // Row Extraction
+    [LibraryImport(LibName)] public static partial PlRowBuffer* pl_dataframe_rows_packed(DataFrameHandle df, long offset, UIntPtr len);
+    [LibraryImport(LibName)] public static partial PlRowBuffer* pl_dataframe_row_packed(DataFrameHandle df, long index);
+    [LibraryImport(LibName)] public static partial void pl_row_buffer_free(PlRowBuffer* buf);
+    [LibraryImport(LibName)] public static partial IntPtr pl_dataframe_rows_json(DataFrameHandle df, long offset, UIntPtr len);
     [LibraryImport(LibName)]
     public static partial DataFrameHandle pl_head(DataFrameHandle df, UIntPtr n);
     [LibraryImport(LibName)]
     public static partial DataFrameHandle pl_tail(DataFrameHandle df, UIntPtr n);
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_dataframe_slice(DataFrameHandle df, long offset, UIntPtr len);
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_dataframe_reverse(DataFrameHandle df);
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_dataframe_shift(DataFrameHandle df, long n);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial DataFrameHandle pl_dataframe_with_row_index(DataFrameHandle df, string name, uint offset);
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_dataframe_gather(DataFrameHandle df, uint[] indices, UIntPtr len);
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_dataframe_gather_every(DataFrameHandle df, UIntPtr n, UIntPtr offset);
 
     [LibraryImport(LibName)]
     public static partial DataFrameHandle pl_filter(DataFrameHandle df, ExprHandle expr);
@@ -108,6 +188,17 @@ unsafe internal partial class NativeBindings
     public static partial DataFrameHandle pl_with_columns(DataFrameHandle df, IntPtr[] exprs, UIntPtr len);
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
     public static partial DataFrameHandle pl_dataframe_drop(DataFrameHandle df, string name);
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_dataframe_drop_many(DataFrameHandle df, IntPtr[] names, UIntPtr len, [MarshalAs(UnmanagedType.U1)] bool strict);
+    // --- In-place Column Ops (原地修改 df，Series / other 只是借用) ---
+    [LibraryImport(LibName)]
+    public static partial void pl_dataframe_insert_column(DataFrameHandle df, UIntPtr index, SeriesHandle s);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial void pl_dataframe_replace_column(DataFrameHandle df, string name, SeriesHandle s);
+    [LibraryImport(LibName)]
+    public static partial void pl_dataframe_hstack(DataFrameHandle df, IntPtr[] columns, UIntPtr len);
+    [LibraryImport(LibName)]
+    public static partial void pl_dataframe_vstack(DataFrameHandle df, DataFrameHandle other, [MarshalAs(UnmanagedType.U1)] bool extend);
 
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
     public static partial DataFrameHandle pl_dataframe_rename(DataFrameHandle df, string oldName, string newName);
@@ -123,6 +214,30 @@ unsafe internal partial class NativeBindings
     [LibraryImport(LibName)]
     public static unsafe partial DataFrameHandle pl_dataframe_sample_frac(DataFrameHandle df, double frac, [MarshalAs(UnmanagedType.U1)] bool replacement, [MarshalAs(UnmanagedType.I1)] bool shuffle, ulong* seed);
     [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_dataframe_describe(DataFrameHandle df, double[] percentiles, UIntPtr len);
+    // selector / value 可为 IntPtr.Zero (所有列 / 使用 strategy)
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_dataframe_fill_null(DataFrameHandle df, IntPtr selector, IntPtr value, PlFillNullStrategy strategy, uint limit);
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_dataframe_fill_nan(DataFrameHandle df, IntPtr selector, ExprHandle value);
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_dataframe_interpolate(DataFrameHandle df, IntPtr selector, PlInterpolationMethod method);
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_dataframe_unique(
+        DataFrameHandle df,
+        IntPtr[] subset, UIntPtr subsetLen,
+        PlUniqueKeep keep,
+        [MarshalAs(UnmanagedType.U1)] bool maintainOrder
+    );
+    [LibraryImport(LibName)]
+    public static partial SeriesHandle pl_dataframe_is_duplicated(DataFrameHandle df, IntPtr[] subset, UIntPtr subsetLen);
+    [LibraryImport(LibName)]
+    public static partial SeriesHandle pl_dataframe_is_unique(DataFrameHandle df, IntPtr[] subset, UIntPtr subsetLen);
+    [LibraryImport(LibName)]
+    public static partial void pl_dataframe_n_unique(DataFrameHandle df, IntPtr[] subset, UIntPtr subsetLen, out UIntPtr count);
+    [LibraryImport(LibName)]
+    public static partial SeriesHandle pl_dataframe_hash_rows(DataFrameHandle df, ulong seed);
+    [LibraryImport(LibName)]
     public static partial ExprHandle pl_expr_lit_str([MarshalAs(UnmanagedType.LPUTF8Str)] string val);
 
     [LibraryImport(LibName)] 
@@ -220,24 +335,95 @@ unsafe internal partial class NativeBindings
     );
     [LibraryImport(LibName)] 
     public static partial ExprHandle pl_expr_cast(ExprHandle expr, DataTypeHandle dtype, [MarshalAs(UnmanagedType.U1)] bool strict);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial DataFrameHandle pl_group_by_dynamic(
+        DataFrameHandle df,
+        ExprHandle index,
+        IntPtr[] by, UIntPtr byLen,
+        string every,
+        string? period,
+        string? offset,
+        string label,
+        [MarshalAs(UnmanagedType.U1)] bool includeBoundaries,
+        string closed,
+        string startBy,
+        IntPtr[] aggs, UIntPtr aggsLen
+    );
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial DataFrameHandle pl_rolling(
+        DataFrameHandle df,
+        ExprHandle index,
+        IntPtr[] by, UIntPtr byLen,
+        string period,
+        string? offset,
+        string closed,
+        IntPtr[] aggs, UIntPtr aggsLen
+    );
     [LibraryImport(LibName)]
     public static partial DataFrameHandle pl_groupby_agg(
         DataFrameHandle df, 
         IntPtr[] byExprs, UIntPtr byLen,
-        IntPtr[] aggExprs, UIntPtr aggLen
+        IntPtr[] aggExprs, UIntPtr aggLen,
+        [MarshalAs(UnmanagedType.U1)] bool maintainOrder
+    );
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_groupby_head(
+        DataFrameHandle df,
+        IntPtr[] byExprs, UIntPtr byLen,
+        UIntPtr n,
+        [MarshalAs(UnmanagedType.U1)] bool maintainOrder
     );
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_groupby_tail(
+        DataFrameHandle df,
+        IntPtr[] byExprs, UIntPtr byLen,
+        UIntPtr n,
+        [MarshalAs(UnmanagedType.U1)] bool maintainOrder
+    );
+    [LibraryImport(LibName)]
+    public static partial IntPtr pl_partition_by(
+        DataFrameHandle df,
+        IntPtr[] by, UIntPtr byLen,
+        [MarshalAs(UnmanagedType.U1)] bool maintainOrder,
+        [MarshalAs(UnmanagedType.U1)] bool includeKey,
+        out DataFrameHandle keys,
+        out UIntPtr len
+    );
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_dataframe_array_take(IntPtr arr, UIntPtr len, UIntPtr idx);
+    [LibraryImport(LibName)]
+    public static partial void pl_dataframe_array_free(IntPtr arr, UIntPtr len);
 
     // Join 签名
-    [LibraryImport(LibName)]
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
     public static partial DataFrameHandle pl_join(
         DataFrameHandle left,
         DataFrameHandle right,
         IntPtr[] leftOn, UIntPtr leftLen,
         IntPtr[] rightOn, UIntPtr rightLen,
-        PlJoinType how
+        PlJoinType how,
+        string? suffix,
+        PlJoinValidation validate,
+        PlJoinCoalesce coalesce,
+        [MarshalAs(UnmanagedType.U1)] bool nullsEqual,
+        PlJoinMaintainOrder maintainOrder
+    );
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial DataFrameHandle pl_join_where(
+        DataFrameHandle left,
+        DataFrameHandle right,
+        IntPtr[] predicates, UIntPtr predicatesLen,
+        string? suffix
     );
     [LibraryImport(LibName)] 
-    public static partial DataFrameHandle pl_sort(DataFrameHandle df, ExprHandle expr, [MarshalAs(UnmanagedType.U1)] bool descending);
+    public static partial DataFrameHandle pl_sort(
+        DataFrameHandle df,
+        IntPtr[] exprs, UIntPtr exprsLen,
+        [MarshalAs(UnmanagedType.LPArray, ArraySubType = UnmanagedType.U1)] bool[] descending, UIntPtr descendingLen,
+        [MarshalAs(UnmanagedType.U1)] bool nullsLast,
+        [MarshalAs(UnmanagedType.U1)] bool maintainOrder,
+        [MarshalAs(UnmanagedType.U1)] bool multithreaded
+    );
     [LibraryImport(LibName)] 
     public static partial DataFrameHandle pl_explode(DataFrameHandle df, IntPtr[] exprs, UIntPtr len);
     [LibraryImport(LibName)] 
@@ -258,6 +444,33 @@ unsafe internal partial class NativeBindings
     public static partial void pl_dataframe_write_json(DataFrameHandle df, string path);
     [LibraryImport(LibName)] 
     public static partial DataFrameHandle pl_read_parquet([MarshalAs(UnmanagedType.LPUTF8Str)] string path);
+    // --- Excel / ODS ---
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial DataFrameHandle pl_read_excel(
+        string path,
+        string? sheetName,
+        UIntPtr sheetIndex,
+        [MarshalAs(UnmanagedType.I1)] bool hasHeader,
+        UIntPtr skipRows,
+        string? cellRange,
+        IntPtr[]? schemaNames,
+        IntPtr[]? schemaTypes,
+        UIntPtr schemaLen,
+        [MarshalAs(UnmanagedType.I1)] bool tryParseDates
+    );
+    // --- SQLite ---
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial DataFrameHandle pl_read_sqlite(string dbPath, string query);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial void pl_write_sqlite(DataFrameHandle df, string dbPath, string table, PlSqliteWriteMode mode);
+    // --- Delta Lake ---
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial LazyFrameHandle pl_scan_delta(string path, long version);
+    // --- Avro IO ---
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial DataFrameHandle pl_read_avro(string path, IntPtr[] columns, UIntPtr columnsLen, UIntPtr nRows);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial void pl_write_avro(DataFrameHandle df, string path, PlAvroCompression compression);
     // --- JSON IO ---
 
     // Read JSON
@@ -278,20 +491,34 @@ unsafe internal partial class NativeBindings
         [MarshalAs(UnmanagedType.I1)] bool hasHeader,
         byte separator,
         UIntPtr skipRows,
-        [MarshalAs(UnmanagedType.I1)] bool tryParseDates // [新增]
+        [MarshalAs(UnmanagedType.I1)] bool tryParseDates, // [新增]
+        IntPtr cloudOptions
     );
 
     [LibraryImport(LibName)] 
-    public static partial LazyFrameHandle pl_scan_parquet([MarshalAs(UnmanagedType.LPUTF8Str)] string path);
+    public static partial LazyFrameHandle pl_scan_parquet([MarshalAs(UnmanagedType.LPUTF8Str)] string path, IntPtr cloudOptions);
     // IPC
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)] 
     public static partial DataFrameHandle pl_read_ipc(string path);
 
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)] 
-    public static partial LazyFrameHandle pl_scan_ipc(string path);
+    public static partial LazyFrameHandle pl_scan_ipc(string path, IntPtr cloudOptions);
 
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)] 
-    public static partial void pl_lazy_sink_ipc(LazyFrameHandle lf, string path);
+    public static partial void pl_lazy_sink_ipc(LazyFrameHandle lf, string path, IntPtr cloudOptions);
+    // Cloud Options (S3 / MinIO)
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial CloudOptionsHandle pl_cloud_options_new_s3(
+        string? endpoint,
+        string? region,
+        string? accessKeyId,
+        string? secretAccessKey,
+        string? sessionToken,
+        [MarshalAs(UnmanagedType.U1)] bool pathStyle,
+        [MarshalAs(UnmanagedType.U1)] bool allowHttp,
+        UIntPtr maxRetries
+    );
+    [LibraryImport(LibName)] public static partial void pl_cloud_options_free(IntPtr ptr);
     // Lazy Introspection
     [LibraryImport(LibName)] public static partial IntPtr pl_lazy_schema(LazyFrameHandle lf);
     [LibraryImport(LibName)] public static partial IntPtr pl_lazy_explain(LazyFrameHandle lf,[MarshalAs(UnmanagedType.U1)] bool optimized);
@@ -300,20 +527,78 @@ unsafe internal partial class NativeBindings
     [LibraryImport(LibName)] 
     public static partial LazyFrameHandle pl_lazy_select(LazyFrameHandle lf, IntPtr[] exprs, UIntPtr len);
     [LibraryImport(LibName)] 
-    public static partial LazyFrameHandle pl_lazy_sort(LazyFrameHandle lf, ExprHandle expr, [MarshalAs(UnmanagedType.U1)] bool desc);
+    public static partial LazyFrameHandle pl_lazy_sort(
+        LazyFrameHandle lf,
+        IntPtr[] exprs, UIntPtr exprsLen,
+        [MarshalAs(UnmanagedType.LPArray, ArraySubType = UnmanagedType.U1)] bool[] descending, UIntPtr descendingLen,
+        [MarshalAs(UnmanagedType.U1)] bool nullsLast,
+        [MarshalAs(UnmanagedType.U1)] bool maintainOrder,
+        [MarshalAs(UnmanagedType.U1)] bool multithreaded
+    );
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial LazyFrameHandle pl_lazy_group_by_dynamic(
+        LazyFrameHandle lf,
+        ExprHandle index,
+        IntPtr[] by, UIntPtr byLen,
+        string every,
+        string? period,
+        string? offset,
+        string label,
+        [MarshalAs(UnmanagedType.U1)] bool includeBoundaries,
+        string closed,
+        string startBy,
+        IntPtr[] aggs, UIntPtr aggsLen
+    );
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial LazyFrameHandle pl_lazy_rolling(
+        LazyFrameHandle lf,
+        ExprHandle index,
+        IntPtr[] by, UIntPtr byLen,
+        string period,
+        string? offset,
+        string closed,
+        IntPtr[] aggs, UIntPtr aggsLen
+    );
     [LibraryImport(LibName)] 
     public static partial LazyFrameHandle pl_lazy_groupby_agg(
         LazyFrameHandle lf, 
         IntPtr[] keys, UIntPtr keysLen, 
-        IntPtr[] aggs, UIntPtr aggsLen
+        IntPtr[] aggs, UIntPtr aggsLen,
+        [MarshalAs(UnmanagedType.U1)] bool maintainOrder
     );
     [LibraryImport(LibName)]
+    public static partial LazyFrameHandle pl_lazy_groupby_head(
+        LazyFrameHandle lf,
+        IntPtr[] keys, UIntPtr keysLen,
+        UIntPtr n,
+        [MarshalAs(UnmanagedType.U1)] bool maintainOrder
+    );
+    [LibraryImport(LibName)]
+    public static partial LazyFrameHandle pl_lazy_groupby_tail(
+        LazyFrameHandle lf,
+        IntPtr[] keys, UIntPtr keysLen,
+        UIntPtr n,
+        [MarshalAs(UnmanagedType.U1)] bool maintainOrder
+    );
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
     public static partial LazyFrameHandle pl_lazy_join(
         LazyFrameHandle left, 
         LazyFrameHandle right,
         IntPtr[] leftOn, UIntPtr leftLen,
         IntPtr[] rightOn, UIntPtr rightLen,
-        PlJoinType how
+        PlJoinType how,
+        string? suffix,
+        PlJoinValidation validate,
+        PlJoinCoalesce coalesce,
+        [MarshalAs(UnmanagedType.U1)] bool nullsEqual,
+        PlJoinMaintainOrder maintainOrder
+    );
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial LazyFrameHandle pl_lazy_join_where(
+        LazyFrameHandle left,
+        LazyFrameHandle right,
+        IntPtr[] predicates, UIntPtr predicatesLen,
+        string? suffix
     );
     [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
     public static partial LazyFrameHandle pl_lazy_join_asof(
@@ -321,7 +606,21 @@ unsafe internal partial class NativeBindings
         ExprHandle leftOn, ExprHandle rightOn,
         IntPtr[] leftBy, UIntPtr leftByLen,
         IntPtr[] rightBy, UIntPtr rightByLen,
-        string strategy, string? tolerance
+        string strategy,
+        PlAsofTolerance toleranceKind, string? tolerance,
+        [MarshalAs(UnmanagedType.U1)] bool allowEq,
+        [MarshalAs(UnmanagedType.U1)] bool checkSortedness
+    );
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial DataFrameHandle pl_join_asof(
+        DataFrameHandle left, DataFrameHandle right,
+        ExprHandle leftOn, ExprHandle rightOn,
+        IntPtr[] leftBy, UIntPtr leftByLen,
+        IntPtr[] rightBy, UIntPtr rightByLen,
+        string strategy,
+        PlAsofTolerance toleranceKind, string? tolerance,
+        [MarshalAs(UnmanagedType.U1)] bool allowEq,
+        [MarshalAs(UnmanagedType.U1)] bool checkSortedness
     );
     [LibraryImport(LibName)]
     public static partial DataFrameHandle pl_lazy_collect(LazyFrameHandle lf);
@@ -329,10 +628,46 @@ unsafe internal partial class NativeBindings
     public static partial LazyFrameHandle pl_lazy_clone(LazyFrameHandle lf);
 
     [LibraryImport(LibName)] public static partial LazyFrameHandle pl_lazy_limit(LazyFrameHandle lf, uint n);
+    [LibraryImport(LibName)] public static partial LazyFrameHandle pl_lazy_slice(LazyFrameHandle lf, long offset, uint len);
+    [LibraryImport(LibName)] public static partial LazyFrameHandle pl_lazy_reverse(LazyFrameHandle lf);
+    [LibraryImport(LibName)] public static partial LazyFrameHandle pl_lazy_shift(LazyFrameHandle lf, long n);
+    [LibraryImport(LibName, StringMarshalling = StringMarshalling.Utf8)]
+    public static partial LazyFrameHandle pl_lazy_with_row_index(LazyFrameHandle lf, string name, uint offset);
+    [LibraryImport(LibName)]
+    public static partial LazyFrameHandle pl_lazy_gather(LazyFrameHandle lf, uint[] indices, UIntPtr len);
+    [LibraryImport(LibName)]
+    public static partial LazyFrameHandle pl_lazy_gather_every(LazyFrameHandle lf, UIntPtr n, UIntPtr offset);
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_lazy_describe(LazyFrameHandle lf, double[] percentiles, UIntPtr len);
+    [LibraryImport(LibName)]
+    public static partial LazyFrameHandle pl_lazy_fill_null(LazyFrameHandle lf, IntPtr selector, IntPtr value, PlFillNullStrategy strategy, uint limit);
+    [LibraryImport(LibName)]
+    public static partial LazyFrameHandle pl_lazy_fill_nan(LazyFrameHandle lf, IntPtr selector, ExprHandle value);
+    [LibraryImport(LibName)]
+    public static partial LazyFrameHandle pl_lazy_interpolate(LazyFrameHandle lf, IntPtr selector, PlInterpolationMethod method);
+    [LibraryImport(LibName)]
+    public static partial LazyFrameHandle pl_lazy_unique(
+        LazyFrameHandle lf,
+        IntPtr[] subset, UIntPtr subsetLen,
+        PlUniqueKeep keep,
+        [MarshalAs(UnmanagedType.U1)] bool maintainOrder
+    );
     [LibraryImport(LibName)] public static partial LazyFrameHandle pl_lazy_with_columns(LazyFrameHandle lf, IntPtr[] exprs, UIntPtr len);
     [LibraryImport(LibName)] 
     public static partial LazyFrameHandle pl_lazy_explode(LazyFrameHandle lf, IntPtr[] exprs, UIntPtr len);
     // --- Reshaping (Lazy) ---
+    [LibraryImport(LibName)]
+    public static partial LazyFrameHandle pl_lazy_pivot(
+        LazyFrameHandle lf,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string on,
+        IntPtr[] onValues, UIntPtr onValuesLen,
+        IntPtr[] index, UIntPtr indexLen,
+        IntPtr[] values, UIntPtr valuesLen,
+        PlPivotAgg aggFn,
+        IntPtr aggExpr, // 可为 IntPtr.Zero (使用 aggFn)
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? separator,
+        [MarshalAs(UnmanagedType.U1)] bool maintainOrder
+    );
     [LibraryImport(LibName)] 
     public static partial LazyFrameHandle pl_lazy_unpivot(
         LazyFrameHandle lf,
@@ -356,8 +691,22 @@ unsafe internal partial class NativeBindings
     [LibraryImport(LibName)] 
     public static partial void pl_lazy_sink_parquet(
         LazyFrameHandle lf, 
-        [MarshalAs(UnmanagedType.LPUTF8Str)] string path
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string path,
+        IntPtr cloudOptions
     );
+    [LibraryImport(LibName)]
+    public static partial LazyFrameHandle pl_scan_callback(
+        IntPtr[]? schemaNames,
+        IntPtr[]? schemaTypes,
+        UIntPtr schemaLen,
+        ScanBatchCallback callback,
+        CleanupCallback cleanup,
+        IntPtr userData
+    );
+    [LibraryImport(LibName)]
+    public static partial void pl_lazy_sink_batches(LazyFrameHandle lf, DataFrameBatchCallback callback, IntPtr userData);
+    [LibraryImport(LibName)]
+    public static partial void pl_lazy_sink_batches_arrow(LazyFrameHandle lf, ArrowBatchCallback callback, IntPtr userData);
     // String Ops
     [LibraryImport(LibName)] public static partial ExprHandle pl_expr_str_contains(ExprHandle expr, [MarshalAs(UnmanagedType.LPUTF8Str)] string pat);
 
@@ -436,7 +785,11 @@ unsafe internal partial class NativeBindings
         IntPtr[] values, UIntPtr valuesLen,
         IntPtr[] index, UIntPtr indexLen,
         IntPtr[] columns, UIntPtr columnsLen,
-        PlPivotAgg aggFn
+        PlPivotAgg aggFn,
+        IntPtr aggExpr, // 可为 IntPtr.Zero (使用 aggFn)
+        [MarshalAs(UnmanagedType.U1)] bool sortColumns,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? separator,
+        [MarshalAs(UnmanagedType.U1)] bool maintainOrder
     );
 
     [LibraryImport(LibName)] 
@@ -448,6 +801,32 @@ unsafe internal partial class NativeBindings
         [MarshalAs(UnmanagedType.LPUTF8Str)] string? valName
     );
 
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_dataframe_transpose(
+        DataFrameHandle df,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? keepNamesAs,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? headerColumn,
+        IntPtr[] names, UIntPtr namesLen
+    );
+
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_dataframe_to_dummies(
+        DataFrameHandle df,
+        IntPtr[] columns, UIntPtr columnsLen,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? separator,
+        [MarshalAs(UnmanagedType.U1)] bool dropFirst,
+        [MarshalAs(UnmanagedType.U1)] bool dropNulls
+    );
+
+    [LibraryImport(LibName)]
+    public static partial DataFrameHandle pl_dataframe_upsample(
+        DataFrameHandle df,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string timeColumn,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string every,
+        IntPtr[] by, UIntPtr byLen,
+        [MarshalAs(UnmanagedType.U1)] bool maintainOrder
+    );
+
     // Expr Len
     [LibraryImport(LibName)] 
     public static partial ExprHandle pl_expr_len();
@@ -457,6 +836,7 @@ unsafe internal partial class NativeBindings
     public static partial SelectorHandle pl_selector_clone(SelectorHandle sel);
     // Selectors
     [LibraryImport(LibName)] public static partial SelectorHandle pl_selector_all();
+    [LibraryImport(LibName)] public static partial SelectorHandle pl_selector_cols(IntPtr[] names, UIntPtr len);
     
     [LibraryImport(LibName)] 
     public static partial SelectorHandle pl_selector_exclude(
@@ -557,9 +937,10 @@ unsafe internal partial class NativeBindings
     [return: MarshalAs(UnmanagedType.I1)]
     public static partial bool pl_series_get_time(SeriesHandle s, UIntPtr idx, out long val);
 
+    // tz: 没有时区时为 IntPtr.Zero，否则需要 pl_free_string
     [LibraryImport(LibName)]
     [return: MarshalAs(UnmanagedType.I1)]
-    public static partial bool pl_series_get_datetime(SeriesHandle s, UIntPtr idx, out long val);
+    public static partial bool pl_series_get_datetime(SeriesHandle s, UIntPtr idx, out long val, out PlTimeUnit unit, out IntPtr tz);
 
     [LibraryImport(LibName)]
     [return: MarshalAs(UnmanagedType.I1)]
@@ -628,6 +1009,28 @@ unsafe internal partial class NativeBindings
     [LibraryImport(LibName)] public static partial SeriesHandle pl_series_is_not_nan(SeriesHandle s);
     [LibraryImport(LibName)] public static partial SeriesHandle pl_series_is_finite(SeriesHandle s);
     [LibraryImport(LibName)] public static partial SeriesHandle pl_series_is_infinite(SeriesHandle s);
+    // --- Series Memory ---
+    [LibraryImport(LibName)] public static partial UIntPtr pl_series_estimated_size(SeriesHandle s);
+    [LibraryImport(LibName)] public static partial UIntPtr pl_series_n_chunks(SeriesHandle s);
+    [LibraryImport(LibName)] public static partial void pl_series_rechunk(SeriesHandle s);
+    [LibraryImport(LibName)] public static partial void pl_series_shrink_to_fit(SeriesHandle s);
+    // --- Equality (返回 JSON 报告，需 pl_free_string) ---
+    [LibraryImport(LibName)]
+    public static partial IntPtr pl_dataframe_equals(
+        DataFrameHandle left, DataFrameHandle right,
+        [MarshalAs(UnmanagedType.U1)] bool checkDtypes,
+        [MarshalAs(UnmanagedType.U1)] bool checkColumnOrder,
+        [MarshalAs(UnmanagedType.U1)] bool checkRowOrder,
+        [MarshalAs(UnmanagedType.U1)] bool checkExact,
+        double rtol, double atol);
+    [LibraryImport(LibName)]
+    public static partial IntPtr pl_series_equals(
+        SeriesHandle left, SeriesHandle right,
+        [MarshalAs(UnmanagedType.U1)] bool checkDtypes,
+        [MarshalAs(UnmanagedType.U1)] bool checkNames,
+        [MarshalAs(UnmanagedType.U1)] bool checkOrder,
+        [MarshalAs(UnmanagedType.U1)] bool checkExact,
+        double rtol, double atol);
     // --- Series Cast ---
     [LibraryImport(LibName)]
     public static partial SeriesHandle pl_series_cast(SeriesHandle s, DataTypeHandle dtype);
@@ -28,6 +28,12 @@ public static partial class PolarsWrapper
     public static long DataFrameHeight(DataFrameHandle df) => (long)NativeBindings.pl_dataframe_height(df);
     public static long DataFrameWidth(DataFrameHandle df) => (long)NativeBindings.pl_dataframe_width(df);
 
+    // 内存估算 / 整理 (Rechunk 和 ShrinkToFit 原地修改)
+    public static long DataFrameEstimatedSize(DataFrameHandle df) => (long)NativeBindings.pl_dataframe_estimated_size(df);
+    public static long DataFrameNChunks(DataFrameHandle df) => (long)NativeBindings.pl_dataframe_n_chunks(df);
+    public static void DataFrameRechunk(DataFrameHandle df) => NativeBindings.pl_dataframe_rechunk(df);
+    public static void DataFrameShrinkToFit(DataFrameHandle df) => NativeBindings.pl_dataframe_shrink_to_fit(df);
+
     public static unsafe RecordBatch Collect(DataFrameHandle handle)
     {
         var array = CArrowArray.Create();
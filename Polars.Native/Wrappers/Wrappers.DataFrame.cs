@@ -33,6 +33,13 @@ public static partial class PolarsWrapper
         var ptr = NativeBindings.pl_dataframe_schema(h);
         return ErrorHelper.CheckString(ptr);
     }
+    // 返回 JSON 格式的比较报告
+    public static string DataFrameEquals(DataFrameHandle left, DataFrameHandle right,
+        bool checkDtypes, bool checkColumnOrder, bool checkRowOrder, bool checkExact, double rtol, double atol)
+    {
+        var ptr = NativeBindings.pl_dataframe_equals(left, right, checkDtypes, checkColumnOrder, checkRowOrder, checkExact, rtol, atol);
+        return ErrorHelper.CheckString(ptr);
+    }
     public static DataFrameHandle CloneDataFrame(DataFrameHandle df)
     {
         return ErrorHelper.Check(NativeBindings.pl_dataframe_clone(df));
@@ -66,6 +73,194 @@ public static partial class PolarsWrapper
         try { return Marshal.PtrToStringUTF8(ptr); }
         finally { NativeBindings.pl_free_string(ptr); }
     }
+    public static bool? GetBool(DataFrameHandle df, string colName, long row)
+    {
+        if (NativeBindings.pl_dataframe_get_bool(df, colName, (UIntPtr)row, out bool val)) return val;
+        return null;
+    }
+
+    public static DateOnly? GetDate(DataFrameHandle df, string colName, long row)
+    {
+        if (NativeBindings.pl_dataframe_get_date(df, colName, (UIntPtr)row, out int days))
+        {
+            return DateOnly.FromDayNumber(days + 719162); // 719162 is days from 0001-01-01 to 1970-01-01
+        }
+        return null;
+    }
+
+    public static TimeOnly? GetTime(DataFrameHandle df, string colName, long row)
+    {
+        if (NativeBindings.pl_dataframe_get_time(df, colName, (UIntPtr)row, out long ns))
+        {
+            return new TimeOnly(ns / 100);
+        }
+        return null;
+    }
+
+    // 返回 UTC 时间 (有时区时) 或本地无时区时间，以及时区名
+    public static (DateTime Value, string? TimeZone)? GetDatetime(DataFrameHandle df, string colName, long row)
+    {
+        if (!NativeBindings.pl_dataframe_get_datetime(df, colName, (UIntPtr)row, out long val, out PlTimeUnit unit, out IntPtr tzPtr))
+        {
+            return null;
+        }
+        return ToDateTime(val, unit, tzPtr);
+    }
+
+    // DataFrame / Series 的 datetime getter 共用: 读取并释放时区名，有时区时为 UTC
+    private static (DateTime Value, string? TimeZone) ToDateTime(long val, PlTimeUnit unit, IntPtr tzPtr)
+    {
+        string? tz = null;
+        if (tzPtr != IntPtr.Zero)
+        {
+            try { tz = Marshal.PtrToStringUTF8(tzPtr); }
+            finally { NativeBindings.pl_free_string(tzPtr); }
+        }
+        // Unix Epoch Ticks = 621355968000000000
+        long ticks = ToTicks(val, unit) + 621355968000000000L;
+        var kind = tz == null ? DateTimeKind.Unspecified : DateTimeKind.Utc;
+        return (new DateTime(ticks, kind), tz);
+    }
+
+    public static TimeSpan? GetDuration(DataFrameHandle df, string colName, long row)
+    {
+        if (NativeBindings.pl_dataframe_get_duration(df, colName, (UIntPtr)row, out long val, out PlTimeUnit unit))
+        {
+            return new TimeSpan(ToTicks(val, unit));
+        }
+        return null;
+    }
+
+    // .NET Ticks = 100ns
+    private static long ToTicks(long val, PlTimeUnit unit) => unit switch
+    {
+        PlTimeUnit.Nanoseconds => val / 100,
+        PlTimeUnit.Milliseconds => val * 10_000,
+        _ => val * 10
+    };
+
+    public static decimal? GetDecimal(DataFrameHandle df, string colName, long row)
+    {
+        if (NativeBindings.pl_dataframe_get_decimal(df, colName, (UIntPtr)row, out Int128 val, out UIntPtr scale))
+        {
+            return DecimalFromParts(val, (int)scale);
+        }
+        return null;
+    }
+
+    public static byte[]? GetBinary(DataFrameHandle df, string colName, long row)
+    {
+        IntPtr ptr = NativeBindings.pl_dataframe_get_binary(df, colName, (UIntPtr)row, out UIntPtr len);
+        if (ptr == IntPtr.Zero) return null;
+        try
+        {
+            var bytes = new byte[(int)len];
+            Marshal.Copy(ptr, bytes, 0, bytes.Length);
+            return bytes;
+        }
+        finally { NativeBindings.pl_free_bytes(ptr, len); }
+    }
+
+    public static string? GetCategorical(DataFrameHandle df, string colName, long row)
+    {
+        IntPtr ptr = NativeBindings.pl_dataframe_get_categorical(df, colName, (UIntPtr)row);
+        if (ptr == IntPtr.Zero) return null;
+        try { return Marshal.PtrToStringUTF8(ptr); }
+        finally { NativeBindings.pl_free_string(ptr); }
+    }
+
+    // List / Array / Struct 以 JSON 文本返回
+    public static string? GetNestedJson(DataFrameHandle df, string colName, long row)
+    {
+        IntPtr ptr = NativeBindings.pl_dataframe_get_nested(df, colName, (UIntPtr)row);
+        if (ptr == IntPtr.Zero) return null;
+        try { return Marshal.PtrToStringUTF8(ptr); }
+        finally { NativeBindings.pl_free_string(ptr); }
+    }
+    // ==========================================
+    // Row Extraction (批量取行)
+    // ==========================================
+
+    public static unsafe object?[][] GetRows(DataFrameHandle df, long offset, ulong length)
+    {
+        return ReadRowBuffer(NativeBindings.pl_dataframe_rows_packed(df, offset, (UIntPtr)length));
+    }
+    public static unsafe object?[] GetRow(DataFrameHandle df, long index)
+    {
+        return ReadRowBuffer(NativeBindings.pl_dataframe_row_packed(df, index))[0];
+    }
+    public static string GetRowsJson(DataFrameHandle df, long offset, ulong length)
+    {
+        return ErrorHelper.CheckString(NativeBindings.pl_dataframe_rows_json(df, offset, (UIntPtr)length));
+    }
+
+    // 解码行优先的 RowBuffer，读完后释放
+    private static unsafe object?[][] ReadRowBuffer(PlRowBuffer* buf)
+    {
+        if (buf == null)
+        {
+            ErrorHelper.CheckVoid();
+            throw new Exception("Polars operation failed (Unknown Error).");
+        }
+        try
+        {
+            int nRows = (int)buf->NRows;
+            int nCols = (int)buf->NCols;
+            var rows = new object?[nRows][];
+            for (int r = 0; r < nRows; r++)
+            {
+                var row = new object?[nCols];
+                for (int c = 0; c < nCols; c++)
+                {
+                    long i = (long)r * nCols + c;
+                    if (buf->Validity[i] == 0) continue; // null
+                    row[c] = ReadCell((PlRowCellKind)buf->Kinds[c], buf->Cells[i], buf->Heap);
+                }
+                rows[r] = row;
+            }
+            return rows;
+        }
+        finally
+        {
+            NativeBindings.pl_row_buffer_free(buf);
+        }
+    }
+
+    private static unsafe object? ReadCell(PlRowCellKind kind, ulong cell, byte* heap)
+    {
+        switch (kind)
+        {
+            case PlRowCellKind.Boolean: return cell != 0;
+            case PlRowCellKind.Int64: return (long)cell;
+            case PlRowCellKind.UInt64: return cell;
+            case PlRowCellKind.Float64: return BitConverter.UInt64BitsToDouble(cell);
+            case PlRowCellKind.Date: return DateOnly.FromDayNumber(DateOnly.FromDateTime(DateTime.UnixEpoch).DayNumber + (int)(long)cell);
+            case PlRowCellKind.Datetime: return new DateTime(DateTime.UnixEpoch.Ticks + (long)cell * 10, DateTimeKind.Unspecified);
+            case PlRowCellKind.DatetimeTz: return new DateTime(DateTime.UnixEpoch.Ticks + (long)cell * 10, DateTimeKind.Utc);
+            case PlRowCellKind.Duration: return TimeSpan.FromTicks((long)cell * 10);
+            case PlRowCellKind.Time: return TimeOnly.FromTimeSpan(TimeSpan.FromTicks((long)cell / 100));
+            case PlRowCellKind.Null: return null;
+        }
+
+        // 变长数据: heap 中为 [u32 长度][字节]
+        byte* p = heap + cell;
+        var bytes = new ReadOnlySpan<byte>(p + 4, (int)*(uint*)p);
+        if (kind == PlRowCellKind.Decimal)
+        {
+            // 超出 System.Decimal 范围 (约 28 位有效数字) 时保留原始字符串
+            var text = System.Text.Encoding.UTF8.GetString(bytes);
+            return decimal.TryParse(text, System.Globalization.NumberStyles.Number, System.Globalization.CultureInfo.InvariantCulture, out var d)
+                ? d
+                : text;
+        }
+        return kind switch
+        {
+            PlRowCellKind.String => System.Text.Encoding.UTF8.GetString(bytes),
+            PlRowCellKind.Binary => bytes.ToArray(),
+            PlRowCellKind.Json => System.Text.Json.JsonDocument.Parse(bytes.ToArray()).RootElement.Clone(),
+            _ => throw new NotSupportedException($"Unknown row cell kind: {kind}")
+        };
+    }
     // ==========================================
     // Eager Ops (立即执行操作)
     // ==========================================
@@ -79,10 +274,66 @@ public static partial class PolarsWrapper
     {
         return ErrorHelper.Check(NativeBindings.pl_tail(df, n));
     }
+    public static DataFrameHandle Slice(DataFrameHandle df, long offset, ulong len)
+    {
+        return ErrorHelper.Check(NativeBindings.pl_dataframe_slice(df, offset, (UIntPtr)len));
+    }
+    public static DataFrameHandle Reverse(DataFrameHandle df)
+    {
+        return ErrorHelper.Check(NativeBindings.pl_dataframe_reverse(df));
+    }
+    public static DataFrameHandle Shift(DataFrameHandle df, long n)
+    {
+        return ErrorHelper.Check(NativeBindings.pl_dataframe_shift(df, n));
+    }
+    public static DataFrameHandle WithRowIndex(DataFrameHandle df, string name, uint offset)
+    {
+        return ErrorHelper.Check(NativeBindings.pl_dataframe_with_row_index(df, name, offset));
+    }
+    public static DataFrameHandle Gather(DataFrameHandle df, uint[] indices)
+    {
+        return ErrorHelper.Check(NativeBindings.pl_dataframe_gather(df, indices, (UIntPtr)indices.Length));
+    }
+    public static DataFrameHandle GatherEvery(DataFrameHandle df, ulong n, ulong offset)
+    {
+        return ErrorHelper.Check(NativeBindings.pl_dataframe_gather_every(df, (UIntPtr)n, (UIntPtr)offset));
+    }
     public static DataFrameHandle Drop(DataFrameHandle df, string name)
     {
         return ErrorHelper.Check(NativeBindings.pl_dataframe_drop(df, name));
     }
+    public static DataFrameHandle DropMany(DataFrameHandle df, string[] names, bool strict)
+    {
+        return UseUtf8StringArray(names, ptrs =>
+            ErrorHelper.Check(NativeBindings.pl_dataframe_drop_many(df, ptrs, (UIntPtr)ptrs.Length, strict))
+        );
+    }
+    // 以下为原地修改，Series / other 只是借用 (DangerousGetHandle 不转移所有权)
+    public static void InsertColumn(DataFrameHandle df, int index, SeriesHandle s)
+    {
+        NativeBindings.pl_dataframe_insert_column(df, (UIntPtr)index, s);
+        ErrorHelper.CheckVoid();
+    }
+    public static void ReplaceColumn(DataFrameHandle df, string name, SeriesHandle s)
+    {
+        NativeBindings.pl_dataframe_replace_column(df, name, s);
+        ErrorHelper.CheckVoid();
+    }
+    public static void HStack(DataFrameHandle df, SeriesHandle[] series)
+    {
+        var ptrs = new IntPtr[series.Length];
+        for (int i = 0; i < series.Length; i++)
+        {
+            ptrs[i] = series[i].DangerousGetHandle();
+        }
+        NativeBindings.pl_dataframe_hstack(df, ptrs, (UIntPtr)ptrs.Length);
+        ErrorHelper.CheckVoid();
+    }
+    public static void VStack(DataFrameHandle df, DataFrameHandle other, bool extend)
+    {
+        NativeBindings.pl_dataframe_vstack(df, other, extend);
+        ErrorHelper.CheckVoid();
+    }
 
     public static DataFrameHandle Rename(DataFrameHandle df, string oldName, string newName)
     {
@@ -113,6 +364,69 @@ public static partial class PolarsWrapper
         ulong* sPtr = seed.HasValue ? &sVal : null;
         return ErrorHelper.Check(NativeBindings.pl_dataframe_sample_frac(df, frac, replacement, shuffle, sPtr));
     }
+    // 汇总统计: percentiles 为空时不输出百分位数
+    public static DataFrameHandle Describe(DataFrameHandle df, double[] percentiles)
+    {
+        return ErrorHelper.Check(NativeBindings.pl_dataframe_describe(df, percentiles, (UIntPtr)percentiles.Length));
+    }
+    // selector 为 null 表示所有列；selector 和 value 都会被消耗
+    public static DataFrameHandle FillNull(DataFrameHandle df, SelectorHandle? selector, ExprHandle value)
+    {
+        return ErrorHelper.Check(NativeBindings.pl_dataframe_fill_null(
+            df, selector?.TransferOwnership() ?? IntPtr.Zero, value.TransferOwnership(), PlFillNullStrategy.Forward, 0
+        ));
+    }
+    // limit 只对 Forward/Backward 生效，0 表示不限制
+    public static DataFrameHandle FillNull(DataFrameHandle df, SelectorHandle? selector, PlFillNullStrategy strategy, uint limit)
+    {
+        return ErrorHelper.Check(NativeBindings.pl_dataframe_fill_null(
+            df, selector?.TransferOwnership() ?? IntPtr.Zero, IntPtr.Zero, strategy, limit
+        ));
+    }
+    public static DataFrameHandle FillNan(DataFrameHandle df, SelectorHandle? selector, ExprHandle value)
+    {
+        var h = NativeBindings.pl_dataframe_fill_nan(df, selector?.TransferOwnership() ?? IntPtr.Zero, value);
+        value.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static DataFrameHandle Interpolate(DataFrameHandle df, SelectorHandle? selector, PlInterpolationMethod method)
+    {
+        return ErrorHelper.Check(NativeBindings.pl_dataframe_interpolate(
+            df, selector?.TransferOwnership() ?? IntPtr.Zero, method
+        ));
+    }
+    // subset 为空表示按所有列比较
+    public static DataFrameHandle Unique(DataFrameHandle df, string[]? subset, PlUniqueKeep keep, bool maintainOrder)
+    {
+        return UseUtf8StringArray(subset ?? [], ptrs =>
+            ErrorHelper.Check(NativeBindings.pl_dataframe_unique(df, ptrs, (UIntPtr)ptrs.Length, keep, maintainOrder))
+        );
+    }
+    public static SeriesHandle IsDuplicated(DataFrameHandle df, string[]? subset)
+    {
+        return UseUtf8StringArray(subset ?? [], ptrs =>
+            ErrorHelper.Check(NativeBindings.pl_dataframe_is_duplicated(df, ptrs, (UIntPtr)ptrs.Length))
+        );
+    }
+    public static SeriesHandle IsUnique(DataFrameHandle df, string[]? subset)
+    {
+        return UseUtf8StringArray(subset ?? [], ptrs =>
+            ErrorHelper.Check(NativeBindings.pl_dataframe_is_unique(df, ptrs, (UIntPtr)ptrs.Length))
+        );
+    }
+    public static ulong NUnique(DataFrameHandle df, string[]? subset)
+    {
+        return UseUtf8StringArray(subset ?? [], ptrs =>
+        {
+            NativeBindings.pl_dataframe_n_unique(df, ptrs, (UIntPtr)ptrs.Length, out var count);
+            ErrorHelper.CheckVoid();
+            return (ulong)count;
+        });
+    }
+    public static SeriesHandle HashRows(DataFrameHandle df, ulong seed)
+    {
+        return ErrorHelper.Check(NativeBindings.pl_dataframe_hash_rows(df, seed));
+    }
     public static DataFrameHandle Filter(DataFrameHandle df, ExprHandle expr)
     {
         var h = NativeBindings.pl_filter(df, expr);
@@ -130,36 +444,187 @@ public static partial class PolarsWrapper
         return ErrorHelper.Check(NativeBindings.pl_select(df, rawExprs, (UIntPtr)rawExprs.Length));
     }
 
-    public static DataFrameHandle Join(DataFrameHandle left, DataFrameHandle right, ExprHandle[] leftOn, ExprHandle[] rightOn, PlJoinType how)
+    public static DataFrameHandle Join(
+        DataFrameHandle left, DataFrameHandle right,
+        ExprHandle[] leftOn, ExprHandle[] rightOn,
+        PlJoinType how,
+        string? suffix = null,
+        PlJoinValidation validate = PlJoinValidation.ManyToMany,
+        PlJoinCoalesce coalesce = PlJoinCoalesce.JoinSpecific,
+        bool nullsEqual = false,
+        PlJoinMaintainOrder maintainOrder = PlJoinMaintainOrder.None)
     {
         var lPtrs = HandlesToPtrs(leftOn);
         var rPtrs = HandlesToPtrs(rightOn);
-        return ErrorHelper.Check(NativeBindings.pl_join(left, right, lPtrs, (UIntPtr)lPtrs.Length, rPtrs, (UIntPtr)rPtrs.Length, how));
+        return ErrorHelper.Check(NativeBindings.pl_join(
+            left, right,
+            lPtrs, (UIntPtr)lPtrs.Length,
+            rPtrs, (UIntPtr)rPtrs.Length,
+            how, suffix, validate, coalesce, nullsEqual, maintainOrder
+        ));
     }
-    public static DataFrameHandle Sort(DataFrameHandle df, ExprHandle expr, bool descending)
+    public static DataFrameHandle JoinAsOf(
+        DataFrameHandle left, DataFrameHandle right,
+        ExprHandle leftOn, ExprHandle rightOn,
+        ExprHandle[]? leftBy, ExprHandle[]? rightBy,
+        string strategy,
+        PlAsofTolerance toleranceKind, string? tolerance,
+        bool allowEq = true,
+        bool checkSortedness = true)
     {
-        var h = NativeBindings.pl_sort(df, expr, descending);
-        expr.TransferOwnership();
+        var lByPtrs = HandlesToPtrs(leftBy ?? []);
+        var rByPtrs = HandlesToPtrs(rightBy ?? []);
+
+        var h = NativeBindings.pl_join_asof(
+            left, right,
+            leftOn, rightOn,
+            lByPtrs, (UIntPtr)lByPtrs.Length,
+            rByPtrs, (UIntPtr)rByPtrs.Length,
+            strategy,
+            toleranceKind, tolerance,
+            allowEq, checkSortedness
+        );
+        // on 表达式被 Rust 消耗，DataFrame 只是借用
+        leftOn.TransferOwnership();
+        rightOn.TransferOwnership();
         return ErrorHelper.Check(h);
     }
+    // predicates 同时引用左右两侧的列 (右侧重名列带 suffix)
+    public static DataFrameHandle JoinWhere(DataFrameHandle left, DataFrameHandle right, ExprHandle[] predicates, string? suffix = null)
+    {
+        var raw = HandlesToPtrs(predicates);
+        return ErrorHelper.Check(NativeBindings.pl_join_where(left, right, raw, (UIntPtr)raw.Length, suffix));
+    }
+    public static DataFrameHandle Sort(DataFrameHandle df, ExprHandle expr, bool descending)
+        => Sort(df, [expr], [descending]);
+    // descending: 长度为 1 时所有列共用同一方向，否则和 exprs 一一对应
+    public static DataFrameHandle Sort(
+        DataFrameHandle df,
+        ExprHandle[] exprs,
+        bool[] descending,
+        bool nullsLast = false,
+        bool maintainOrder = false,
+        bool multithreaded = true)
+    {
+        var raw = HandlesToPtrs(exprs);
+        return ErrorHelper.Check(NativeBindings.pl_sort(
+            df, raw, (UIntPtr)raw.Length,
+            descending, (UIntPtr)descending.Length,
+            nullsLast, maintainOrder, multithreaded
+        ));
+    }
     public static DataFrameHandle Explode(DataFrameHandle df, ExprHandle[] exprs)
     {
         var raw = HandlesToPtrs(exprs);
         return ErrorHelper.Check(NativeBindings.pl_explode(df, raw, (UIntPtr)raw.Length));
     }
     // GroupBy
-    public static DataFrameHandle GroupByAgg(DataFrameHandle df, ExprHandle[] by, ExprHandle[] agg)
+    public static DataFrameHandle GroupByAgg(DataFrameHandle df, ExprHandle[] by, ExprHandle[] agg, bool maintainOrder = false)
     {
         var rawBy = HandlesToPtrs(by);
         var rawAgg = HandlesToPtrs(agg);
         return ErrorHelper.Check(NativeBindings.pl_groupby_agg(
             df, 
             rawBy, (UIntPtr)rawBy.Length,
-            rawAgg, (UIntPtr)rawAgg.Length
+            rawAgg, (UIntPtr)rawAgg.Length,
+            maintainOrder
+        ));
+    }
+    public static DataFrameHandle GroupByHead(DataFrameHandle df, ExprHandle[] by, int n, bool maintainOrder)
+    {
+        var rawBy = HandlesToPtrs(by);
+        return ErrorHelper.Check(NativeBindings.pl_groupby_head(
+            df, rawBy, (UIntPtr)rawBy.Length, (UIntPtr)n, maintainOrder
         ));
     }
+    public static DataFrameHandle GroupByTail(DataFrameHandle df, ExprHandle[] by, int n, bool maintainOrder)
+    {
+        var rawBy = HandlesToPtrs(by);
+        return ErrorHelper.Check(NativeBindings.pl_groupby_tail(
+            df, rawBy, (UIntPtr)rawBy.Length, (UIntPtr)n, maintainOrder
+        ));
+    }
+    // 按分组拆分: 返回每组一个 DataFrame，Keys 的第 i 行对应 Frames[i]
+    public static (DataFrameHandle[] Frames, DataFrameHandle Keys) PartitionBy(
+        DataFrameHandle df, string[] by, bool maintainOrder, bool includeKey)
+    {
+        return UseUtf8StringArray(by, ptrs =>
+        {
+            var arr = NativeBindings.pl_partition_by(
+                df, ptrs, (UIntPtr)ptrs.Length,
+                maintainOrder, includeKey,
+                out var keys, out var len
+            );
+            ErrorHelper.Check(keys);
+
+            var frames = new DataFrameHandle[(int)len];
+            try
+            {
+                for (int i = 0; i < frames.Length; i++)
+                {
+                    frames[i] = ErrorHelper.Check(NativeBindings.pl_dataframe_array_take(arr, len, (UIntPtr)i));
+                }
+            }
+            finally
+            {
+                NativeBindings.pl_dataframe_array_free(arr, len);
+            }
+            return (frames, keys);
+        });
+    }
+    // 时间窗口分组: every/period/offset 为时间字符串 ("5m", "1h"...)，整数索引用 "2i"
+    public static DataFrameHandle GroupByDynamic(
+        DataFrameHandle df,
+        ExprHandle index,
+        ExprHandle[] by,
+        string every,
+        string? period,
+        string? offset,
+        string label,
+        bool includeBoundaries,
+        string closed,
+        string startBy,
+        ExprHandle[] aggs)
+    {
+        var rawBy = HandlesToPtrs(by);
+        var rawAgg = HandlesToPtrs(aggs);
+        var h = NativeBindings.pl_group_by_dynamic(
+            df, index,
+            rawBy, (UIntPtr)rawBy.Length,
+            every, period, offset,
+            label, includeBoundaries, closed, startBy,
+            rawAgg, (UIntPtr)rawAgg.Length
+        );
+        index.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    // 滚动窗口分组: 每行一个 (t + offset, t + offset + period] 窗口，offset 默认 -period
+    public static DataFrameHandle Rolling(
+        DataFrameHandle df,
+        ExprHandle index,
+        ExprHandle[] by,
+        string period,
+        string? offset,
+        string closed,
+        ExprHandle[] aggs)
+    {
+        var rawBy = HandlesToPtrs(by);
+        var rawAgg = HandlesToPtrs(aggs);
+        var h = NativeBindings.pl_rolling(
+            df, index,
+            rawBy, (UIntPtr)rawBy.Length,
+            period, offset, closed,
+            rawAgg, (UIntPtr)rawAgg.Length
+        );
+        index.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
     // Pivot (Eager)
-    public static DataFrameHandle Pivot(DataFrameHandle df, string[] index, string[] columns, string[] values, PlPivotAgg aggFn)
+    // aggExpr 不为 null 时代替 aggFn (会被消耗)
+    public static DataFrameHandle Pivot(
+        DataFrameHandle df, string[] index, string[] columns, string[] values,
+        PlPivotAgg aggFn, ExprHandle? aggExpr = null,
+        bool sortColumns = false, string? separator = null, bool maintainOrder = true)
     {
         // 三层嵌套稍微有点丑，但能复用 UseUtf8StringArray 的安全机制
         return UseUtf8StringArray(index, iPtrs =>
@@ -171,7 +636,11 @@ public static partial class PolarsWrapper
                         vPtrs, (UIntPtr)vPtrs.Length,
                         iPtrs, (UIntPtr)iPtrs.Length,
                         cPtrs, (UIntPtr)cPtrs.Length,
-                        aggFn
+                        aggFn,
+                        aggExpr?.TransferOwnership() ?? IntPtr.Zero,
+                        sortColumns,
+                        separator,
+                        maintainOrder
                     ));
                 })
             )
@@ -194,6 +663,24 @@ public static partial class PolarsWrapper
             })
         );
     }
+    public static DataFrameHandle Transpose(DataFrameHandle df, string? keepNamesAs, string? headerColumn, string[]? columnNames)
+    {
+        return UseUtf8StringArray(columnNames ?? [], ptrs =>
+            ErrorHelper.Check(NativeBindings.pl_dataframe_transpose(df, keepNamesAs, headerColumn, ptrs, (UIntPtr)ptrs.Length))
+        );
+    }
+    public static DataFrameHandle ToDummies(DataFrameHandle df, string[]? columns, string? separator, bool dropFirst, bool dropNulls)
+    {
+        return UseUtf8StringArray(columns ?? [], ptrs =>
+            ErrorHelper.Check(NativeBindings.pl_dataframe_to_dummies(df, ptrs, (UIntPtr)ptrs.Length, separator, dropFirst, dropNulls))
+        );
+    }
+    public static DataFrameHandle Upsample(DataFrameHandle df, string timeColumn, string every, string[]? by, bool maintainOrder)
+    {
+        return UseUtf8StringArray(by ?? [], ptrs =>
+            ErrorHelper.Check(NativeBindings.pl_dataframe_upsample(df, timeColumn, every, ptrs, (UIntPtr)ptrs.Length, maintainOrder))
+        );
+    }
     public static DataFrameHandle Concat(DataFrameHandle[] handles, PlConcatType how)
     {
         var ptrs = HandlesToPtrs(handles);
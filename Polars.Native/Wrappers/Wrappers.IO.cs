@@ -1,3 +1,5 @@
+using System.Runtime.ExceptionServices;
+using System.Runtime.InteropServices;
 using Apache.Arrow;
 using Apache.Arrow.C;
 
@@ -23,6 +25,25 @@ public static partial class PolarsWrapper
                 return action(namePtrs, typeHandles, (UIntPtr)names.Length);
             });
         }
+    // Rust 端只借用 CloudOptions (内部 clone)，所以这里直接传裸指针
+    private static IntPtr CloudPtr(CloudOptionsHandle? cloudOptions)
+        => cloudOptions?.DangerousGetHandle() ?? IntPtr.Zero;
+
+    public static CloudOptionsHandle NewS3CloudOptions(
+        string? endpoint = null,
+        string? region = null,
+        string? accessKeyId = null,
+        string? secretAccessKey = null,
+        string? sessionToken = null,
+        bool pathStyle = false,
+        bool allowHttp = false,
+        ulong maxRetries = 2)
+    {
+        return ErrorHelper.Check(NativeBindings.pl_cloud_options_new_s3(
+            endpoint, region, accessKeyId, secretAccessKey, sessionToken,
+            pathStyle, allowHttp, (UIntPtr)maxRetries
+        ));
+    }
     public static DataFrameHandle ReadCsv(
             string path, 
             Dictionary<string, DataTypeHandle>? schema = null,
@@ -61,7 +82,8 @@ public static partial class PolarsWrapper
             bool hasHeader = true,
             char separator = ',',
             ulong skipRows = 0,
-            bool tryParseDates = true) // [新增]
+            bool tryParseDates = true, // [新增]
+            CloudOptionsHandle? cloudOptions = null)
         {
             return WithSchemaArrays(schema, (namePtrs, typePtrs, len) => 
             {
@@ -73,7 +95,8 @@ public static partial class PolarsWrapper
                     hasHeader, 
                     (byte)separator, 
                     (UIntPtr)skipRows,
-                    tryParseDates
+                    tryParseDates,
+                    CloudPtr(cloudOptions)
                 ));
             });
         }
@@ -87,11 +110,70 @@ public static partial class PolarsWrapper
     {
         return Task.Run(() => ReadParquet(path));
     }
-    public static LazyFrameHandle ScanParquet(string path) {
-        if (!File.Exists(path)) throw new FileNotFoundException($"Parquet not found: {path}");
-        return ErrorHelper.Check(NativeBindings.pl_scan_parquet(path));
+    public static LazyFrameHandle ScanParquet(string path, CloudOptionsHandle? cloudOptions = null) {
+        if (cloudOptions == null && !File.Exists(path)) throw new FileNotFoundException($"Parquet not found: {path}");
+        return ErrorHelper.Check(NativeBindings.pl_scan_parquet(path, CloudPtr(cloudOptions)));
     } 
 
+    // 本地 Delta Lake 表目录; version 为 null 时读取最新版本 (time travel)
+    public static LazyFrameHandle ScanDelta(string path, long? version = null)
+    {
+        if (!Directory.Exists(path)) throw new DirectoryNotFoundException($"Delta table not found: {path}");
+        return ErrorHelper.Check(NativeBindings.pl_scan_delta(path, version ?? -1));
+    }
+
+    // sheetName 优先，为 null 时按 sheetIndex 选择; cellRange 例如 "B2:F100"
+    public static DataFrameHandle ReadExcel(
+            string path,
+            string? sheetName = null,
+            ulong sheetIndex = 0,
+            bool hasHeader = true,
+            ulong skipRows = 0,
+            string? cellRange = null,
+            Dictionary<string, DataTypeHandle>? schema = null,
+            bool tryParseDates = true)
+    {
+        if (!File.Exists(path)) throw new FileNotFoundException($"Excel file not found: {path}");
+        return WithSchemaArrays(schema, (namePtrs, typePtrs, len) =>
+        {
+            return ErrorHelper.Check(NativeBindings.pl_read_excel(
+                path,
+                sheetName,
+                (UIntPtr)sheetIndex,
+                hasHeader,
+                (UIntPtr)skipRows,
+                cellRange,
+                namePtrs,
+                typePtrs,
+                len,
+                tryParseDates
+            ));
+        });
+    }
+    public static DataFrameHandle ReadSqlite(string dbPath, string query)
+    {
+        if (!File.Exists(dbPath)) throw new FileNotFoundException($"SQLite database not found: {dbPath}");
+        return ErrorHelper.Check(NativeBindings.pl_read_sqlite(dbPath, query));
+    }
+    public static void WriteSqlite(DataFrameHandle df, string dbPath, string table, PlSqliteWriteMode mode = PlSqliteWriteMode.Create)
+    {
+        NativeBindings.pl_write_sqlite(df, dbPath, table, mode);
+        ErrorHelper.CheckVoid();
+    }
+    // columns: 只读取这些列 (null = 全部); nRows: 最多读取的行数 (null = 全部)
+    public static DataFrameHandle ReadAvro(string path, string[]? columns = null, ulong? nRows = null)
+    {
+        if (!File.Exists(path)) throw new FileNotFoundException($"Avro not found: {path}");
+        return UseUtf8StringArray(columns ?? [], ptrs =>
+            ErrorHelper.Check(NativeBindings.pl_read_avro(path, ptrs, (UIntPtr)ptrs.Length, (UIntPtr)(nRows ?? 0)))
+        );
+    }
+    public static void WriteAvro(DataFrameHandle df, string path, PlAvroCompression compression = PlAvroCompression.None)
+    {
+        NativeBindings.pl_write_avro(df, path, compression);
+        ErrorHelper.CheckVoid();
+    }
+
     public static void WriteCsv(DataFrameHandle df, string path)
     {
         NativeBindings.pl_write_csv(df, path);
@@ -115,9 +197,9 @@ public static partial class PolarsWrapper
         ErrorHelper.CheckVoid();
     }
     // Sink Parquet
-    public static void SinkParquet(LazyFrameHandle lf, string path)
+    public static void SinkParquet(LazyFrameHandle lf, string path, CloudOptionsHandle? cloudOptions = null)
     {
-        NativeBindings.pl_lazy_sink_parquet(lf, path);
+        NativeBindings.pl_lazy_sink_parquet(lf, path, CloudPtr(cloudOptions));
         lf.TransferOwnership();
         ErrorHelper.CheckVoid();
     }
@@ -140,18 +222,91 @@ public static partial class PolarsWrapper
         return ErrorHelper.Check(NativeBindings.pl_read_ipc(path));
     }
 
-    public static LazyFrameHandle ScanIpc(string path)
+    public static LazyFrameHandle ScanIpc(string path, CloudOptionsHandle? cloudOptions = null)
     {
-        if (!File.Exists(path)) throw new FileNotFoundException($"IPC file not found: {path}");
-        return ErrorHelper.Check(NativeBindings.pl_scan_ipc(path));
+        if (cloudOptions == null && !File.Exists(path)) throw new FileNotFoundException($"IPC file not found: {path}");
+        return ErrorHelper.Check(NativeBindings.pl_scan_ipc(path, CloudPtr(cloudOptions)));
     }
 
-    public static void SinkIpc(LazyFrameHandle lf, string path)
+    public static void SinkIpc(LazyFrameHandle lf, string path, CloudOptionsHandle? cloudOptions = null)
     {
-        NativeBindings.pl_lazy_sink_ipc(lf, path);
+        NativeBindings.pl_lazy_sink_ipc(lf, path, CloudPtr(cloudOptions));
         lf.TransferOwnership();
         ErrorHelper.CheckVoid();
     }
+    // 按 batch 把结果推给 onBatch，返回 false 提前停止查询
+    // 回调在 Polars 的工作线程上串行调用，batch 的所有权交给回调 (需要自行 Dispose)
+    // 回调里抛出的异常会停止查询，并在 sink 返回后重新抛出
+    public static void SinkBatches(LazyFrameHandle lf, Func<DataFrameHandle, bool> onBatch)
+    {
+        Exception? callbackError = null;
+
+        int Trampoline(IntPtr dfPtr, IntPtr userData)
+        {
+            try
+            {
+                return onBatch(DataFrameHandle.FromOwnedPointer(dfPtr)) ? 0 : 1;
+            }
+            catch (Exception ex)
+            {
+                callbackError = ex;
+                return 1;
+            }
+        }
+
+        DataFrameBatchCallback callback = Trampoline;
+        // 整个 sink 期间 Rust 都持有函数指针，用 GCHandle 固定住委托
+        GCHandle gcHandle = GCHandle.Alloc(callback);
+        try
+        {
+            NativeBindings.pl_lazy_sink_batches(lf, callback, IntPtr.Zero);
+            lf.TransferOwnership();
+            ErrorHelper.CheckVoid();
+        }
+        finally
+        {
+            gcHandle.Free();
+        }
+
+        if (callbackError != null) ExceptionDispatchInfo.Capture(callbackError).Throw();
+    }
+
+    // 同 SinkBatches，但每个 batch 以 Arrow RecordBatch 的形式交给回调
+    public static unsafe void SinkBatchesArrow(LazyFrameHandle lf, Func<RecordBatch, bool> onBatch)
+    {
+        Exception? callbackError = null;
+
+        int Trampoline(CArrowArray* array, CArrowSchema* schema, IntPtr userData)
+        {
+            try
+            {
+                // Schema 只是拷贝，Array 由 importer 接管 release
+                var managedSchema = CArrowSchemaImporter.ImportSchema(schema);
+                var batch = CArrowArrayImporter.ImportRecordBatch(array, managedSchema);
+                return onBatch(batch) ? 0 : 1;
+            }
+            catch (Exception ex)
+            {
+                callbackError = ex;
+                return 1;
+            }
+        }
+
+        ArrowBatchCallback callback = Trampoline;
+        GCHandle gcHandle = GCHandle.Alloc(callback);
+        try
+        {
+            NativeBindings.pl_lazy_sink_batches_arrow(lf, callback, IntPtr.Zero);
+            lf.TransferOwnership();
+            ErrorHelper.CheckVoid();
+        }
+        finally
+        {
+            gcHandle.Free();
+        }
+
+        if (callbackError != null) ExceptionDispatchInfo.Capture(callbackError).Throw();
+    }
     public static unsafe DataFrameHandle FromArrow(RecordBatch batch)
     {
         // 1. 在栈上分配 C 结构体 (避免 GC 压力)
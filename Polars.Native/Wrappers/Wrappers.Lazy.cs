@@ -52,10 +52,22 @@ public static partial class PolarsWrapper
         return ErrorHelper.Check(h);
     }
     public static LazyFrameHandle LazySort(LazyFrameHandle lf, ExprHandle expr, bool desc)
+        => LazySort(lf, [expr], [desc]);
+    public static LazyFrameHandle LazySort(
+        LazyFrameHandle lf,
+        ExprHandle[] exprs,
+        bool[] descending,
+        bool nullsLast = false,
+        bool maintainOrder = false,
+        bool multithreaded = true)
     {
-        var h = NativeBindings.pl_lazy_sort(lf, expr, desc);
+        var raw = HandlesToPtrs(exprs);
+        var h = NativeBindings.pl_lazy_sort(
+            lf, raw, (UIntPtr)raw.Length,
+            descending, (UIntPtr)descending.Length,
+            nullsLast, maintainOrder, multithreaded
+        );
         lf.TransferOwnership();
-        expr.TransferOwnership();
         return ErrorHelper.Check(h);
     }
 
@@ -65,7 +77,135 @@ public static partial class PolarsWrapper
         lf.TransferOwnership();
         return ErrorHelper.Check(h);
     }
-    public static LazyFrameHandle LazyGroupByAgg(LazyFrameHandle lf, ExprHandle[] keys, ExprHandle[] aggs)
+    public static LazyFrameHandle LazySlice(LazyFrameHandle lf, long offset, uint len)
+    {
+        var h = NativeBindings.pl_lazy_slice(lf, offset, len);
+        lf.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle LazyReverse(LazyFrameHandle lf)
+    {
+        var h = NativeBindings.pl_lazy_reverse(lf);
+        lf.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle LazyShift(LazyFrameHandle lf, long n)
+    {
+        var h = NativeBindings.pl_lazy_shift(lf, n);
+        lf.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle LazyWithRowIndex(LazyFrameHandle lf, string name, uint offset)
+    {
+        var h = NativeBindings.pl_lazy_with_row_index(lf, name, offset);
+        lf.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle LazyGather(LazyFrameHandle lf, uint[] indices)
+    {
+        var h = NativeBindings.pl_lazy_gather(lf, indices, (UIntPtr)indices.Length);
+        lf.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle LazyGatherEvery(LazyFrameHandle lf, ulong n, ulong offset)
+    {
+        var h = NativeBindings.pl_lazy_gather_every(lf, (UIntPtr)n, (UIntPtr)offset);
+        lf.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static DataFrameHandle LazyDescribe(LazyFrameHandle lf, double[] percentiles)
+    {
+        var h = NativeBindings.pl_lazy_describe(lf, percentiles, (UIntPtr)percentiles.Length);
+        lf.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    // selector 为 null 表示所有列；selector 和 value 都会被消耗
+    public static LazyFrameHandle LazyFillNull(LazyFrameHandle lf, SelectorHandle? selector, ExprHandle value)
+    {
+        var h = NativeBindings.pl_lazy_fill_null(
+            lf, selector?.TransferOwnership() ?? IntPtr.Zero, value.TransferOwnership(), PlFillNullStrategy.Forward, 0
+        );
+        lf.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle LazyFillNull(LazyFrameHandle lf, SelectorHandle? selector, PlFillNullStrategy strategy, uint limit)
+    {
+        var h = NativeBindings.pl_lazy_fill_null(
+            lf, selector?.TransferOwnership() ?? IntPtr.Zero, IntPtr.Zero, strategy, limit
+        );
+        lf.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle LazyFillNan(LazyFrameHandle lf, SelectorHandle? selector, ExprHandle value)
+    {
+        var h = NativeBindings.pl_lazy_fill_nan(lf, selector?.TransferOwnership() ?? IntPtr.Zero, value);
+        lf.TransferOwnership();
+        value.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle LazyInterpolate(LazyFrameHandle lf, SelectorHandle? selector, PlInterpolationMethod method)
+    {
+        var h = NativeBindings.pl_lazy_interpolate(lf, selector?.TransferOwnership() ?? IntPtr.Zero, method);
+        lf.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle LazyUnique(LazyFrameHandle lf, string[]? subset, PlUniqueKeep keep, bool maintainOrder)
+    {
+        return UseUtf8StringArray(subset ?? [], ptrs =>
+        {
+            var h = NativeBindings.pl_lazy_unique(lf, ptrs, (UIntPtr)ptrs.Length, keep, maintainOrder);
+            lf.TransferOwnership();
+            return ErrorHelper.Check(h);
+        });
+    }
+    public static LazyFrameHandle LazyGroupByDynamic(
+        LazyFrameHandle lf,
+        ExprHandle index,
+        ExprHandle[] by,
+        string every,
+        string? period,
+        string? offset,
+        string label,
+        bool includeBoundaries,
+        string closed,
+        string startBy,
+        ExprHandle[] aggs)
+    {
+        var rawBy = HandlesToPtrs(by);
+        var rawAgg = HandlesToPtrs(aggs);
+        var h = NativeBindings.pl_lazy_group_by_dynamic(
+            lf, index,
+            rawBy, (UIntPtr)rawBy.Length,
+            every, period, offset,
+            label, includeBoundaries, closed, startBy,
+            rawAgg, (UIntPtr)rawAgg.Length
+        );
+        lf.TransferOwnership();
+        index.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle LazyRolling(
+        LazyFrameHandle lf,
+        ExprHandle index,
+        ExprHandle[] by,
+        string period,
+        string? offset,
+        string closed,
+        ExprHandle[] aggs)
+    {
+        var rawBy = HandlesToPtrs(by);
+        var rawAgg = HandlesToPtrs(aggs);
+        var h = NativeBindings.pl_lazy_rolling(
+            lf, index,
+            rawBy, (UIntPtr)rawBy.Length,
+            period, offset, closed,
+            rawAgg, (UIntPtr)rawAgg.Length
+        );
+        lf.TransferOwnership();
+        index.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle LazyGroupByAgg(LazyFrameHandle lf, ExprHandle[] keys, ExprHandle[] aggs, bool maintainOrder = false)
     {
         var keyPtrs = HandlesToPtrs(keys);
         var aggPtrs = HandlesToPtrs(aggs);
@@ -74,12 +214,31 @@ public static partial class PolarsWrapper
         var h = NativeBindings.pl_lazy_groupby_agg(
             lf, 
             keyPtrs, (UIntPtr)keyPtrs.Length, 
-            aggPtrs, (UIntPtr)aggPtrs.Length
+            aggPtrs, (UIntPtr)aggPtrs.Length,
+            maintainOrder
         );
         
         lf.TransferOwnership();
         return ErrorHelper.Check(h);
     }
+    public static LazyFrameHandle LazyGroupByHead(LazyFrameHandle lf, ExprHandle[] keys, int n, bool maintainOrder)
+    {
+        var keyPtrs = HandlesToPtrs(keys);
+        var h = NativeBindings.pl_lazy_groupby_head(
+            lf, keyPtrs, (UIntPtr)keyPtrs.Length, (UIntPtr)n, maintainOrder
+        );
+        lf.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    public static LazyFrameHandle LazyGroupByTail(LazyFrameHandle lf, ExprHandle[] keys, int n, bool maintainOrder)
+    {
+        var keyPtrs = HandlesToPtrs(keys);
+        var h = NativeBindings.pl_lazy_groupby_tail(
+            lf, keyPtrs, (UIntPtr)keyPtrs.Length, (UIntPtr)n, maintainOrder
+        );
+        lf.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
     public static LazyFrameHandle LazyWithColumns(LazyFrameHandle lf, ExprHandle[] handles)
     {
         var raw = HandlesToPtrs(handles);
@@ -94,6 +253,30 @@ public static partial class PolarsWrapper
         lf.TransferOwnership(); // 链式调用消耗旧 LF
         return ErrorHelper.Check(newLf);
     }
+    public static LazyFrameHandle LazyPivot(
+        LazyFrameHandle lf, string on, string[] onValues, string[] index, string[] values,
+        PlPivotAgg aggFn, ExprHandle? aggExpr, string? separator, bool maintainOrder)
+    {
+        return UseUtf8StringArray(onValues, ovPtrs =>
+            UseUtf8StringArray(index, iPtrs =>
+                UseUtf8StringArray(values, vPtrs =>
+                {
+                    var h = NativeBindings.pl_lazy_pivot(
+                        lf, on,
+                        ovPtrs, (UIntPtr)ovPtrs.Length,
+                        iPtrs, (UIntPtr)iPtrs.Length,
+                        vPtrs, (UIntPtr)vPtrs.Length,
+                        aggFn,
+                        aggExpr?.TransferOwnership() ?? IntPtr.Zero,
+                        separator,
+                        maintainOrder
+                    );
+                    lf.TransferOwnership();
+                    return ErrorHelper.Check(h);
+                })
+            )
+        );
+    }
     public static LazyFrameHandle LazyUnpivot(LazyFrameHandle lf, string[] index, string[] on, string? variableName, string? valueName)
     {
         return UseUtf8StringArray(index, iPtrs =>
@@ -124,7 +307,12 @@ public static partial class PolarsWrapper
     public static LazyFrameHandle Join(
         LazyFrameHandle left, LazyFrameHandle right, 
         ExprHandle[] leftOn, ExprHandle[] rightOn, 
-        PlJoinType how)
+        PlJoinType how,
+        string? suffix = null,
+        PlJoinValidation validate = PlJoinValidation.ManyToMany,
+        PlJoinCoalesce coalesce = PlJoinCoalesce.JoinSpecific,
+        bool nullsEqual = false,
+        PlJoinMaintainOrder maintainOrder = PlJoinMaintainOrder.None)
     {
         var lPtrs = HandlesToPtrs(leftOn);
         var rPtrs = HandlesToPtrs(rightOn);
@@ -133,7 +321,7 @@ public static partial class PolarsWrapper
             left, right, 
             lPtrs, (UIntPtr)lPtrs.Length, 
             rPtrs, (UIntPtr)rPtrs.Length, 
-            how
+            how, suffix, validate, coalesce, nullsEqual, maintainOrder
         );
 
         // 两个 LF 都被 Rust 消耗了
@@ -142,11 +330,32 @@ public static partial class PolarsWrapper
         
         return ErrorHelper.Check(h);
     }
+    public static LazyFrameHandle JoinWhere(
+        LazyFrameHandle left, LazyFrameHandle right,
+        ExprHandle[] predicates, string? suffix = null)
+    {
+        var raw = HandlesToPtrs(predicates);
+        var h = NativeBindings.pl_lazy_join_where(left, right, raw, (UIntPtr)raw.Length, suffix);
+        left.TransferOwnership();
+        right.TransferOwnership();
+        return ErrorHelper.Check(h);
+    }
+    // 旧接口：根据字符串内容推断容差类型 (整数 / 浮点数 / 时间字符串)
     public static LazyFrameHandle JoinAsOf(
         LazyFrameHandle left, LazyFrameHandle right,
         ExprHandle leftOn, ExprHandle rightOn,
         ExprHandle[]? leftBy, ExprHandle[]? rightBy, // 允许为 null
         string strategy, string? tolerance)
+        => JoinAsOf(left, right, leftOn, rightOn, leftBy, rightBy, strategy, InferAsofTolerance(tolerance), tolerance);
+
+    public static LazyFrameHandle JoinAsOf(
+        LazyFrameHandle left, LazyFrameHandle right,
+        ExprHandle leftOn, ExprHandle rightOn,
+        ExprHandle[]? leftBy, ExprHandle[]? rightBy, // 允许为 null
+        string strategy,
+        PlAsofTolerance toleranceKind, string? tolerance,
+        bool allowEq = true,
+        bool checkSortedness = true)
     {
         // 1. 处理数组 (HandlesToPtrs 内部已经处理了 null 检查，如果是 null 会返回空数组)
         var lByPtrs = HandlesToPtrs(leftBy ?? []);
@@ -158,7 +367,9 @@ public static partial class PolarsWrapper
             leftOn, rightOn,
             lByPtrs, (UIntPtr)lByPtrs.Length,
             rByPtrs, (UIntPtr)rByPtrs.Length,
-            strategy, tolerance
+            strategy,
+            toleranceKind, tolerance,
+            allowEq, checkSortedness
         );
 
         // 3. 消耗所有权 (TransferOwnership)
@@ -171,6 +382,15 @@ public static partial class PolarsWrapper
 
         return ErrorHelper.Check(h);
     }
+    public static PlAsofTolerance InferAsofTolerance(string? tolerance)
+    {
+        if (string.IsNullOrEmpty(tolerance)) return PlAsofTolerance.None;
+        if (long.TryParse(tolerance, System.Globalization.NumberStyles.Integer, System.Globalization.CultureInfo.InvariantCulture, out _))
+            return PlAsofTolerance.Int;
+        if (double.TryParse(tolerance, System.Globalization.NumberStyles.Float, System.Globalization.CultureInfo.InvariantCulture, out _))
+            return PlAsofTolerance.Float;
+        return PlAsofTolerance.Duration;
+    }
     // [新增] Streaming Collect
     public static DataFrameHandle CollectStreaming(LazyFrameHandle lf)
     {
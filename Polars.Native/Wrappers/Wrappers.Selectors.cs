@@ -4,6 +4,12 @@ public static partial class PolarsWrapper
 {
     public static SelectorHandle SelectorAll() 
         => ErrorHelper.Check(NativeBindings.pl_selector_all());
+    public static SelectorHandle SelectorCols(string[] names)
+    {
+        return UseUtf8StringArray(names, ptrs =>
+            ErrorHelper.Check(NativeBindings.pl_selector_cols(ptrs, (UIntPtr)ptrs.Length))
+        );
+    }
     public static SelectorHandle CloneSelector(SelectorHandle sel)
     {
         // Clone 操作不消耗原 Handle，只做 Check
@@ -144,6 +144,13 @@ public static partial class PolarsWrapper
         return ErrorHelper.CheckString(ptr);
     }
     public static long SeriesLen(SeriesHandle h) => (long)NativeBindings.pl_series_len(h);
+    // 返回 JSON 格式的比较报告
+    public static string SeriesEquals(SeriesHandle left, SeriesHandle right,
+        bool checkDtypes, bool checkNames, bool checkOrder, bool checkExact, double rtol, double atol)
+    {
+        var ptr = NativeBindings.pl_series_equals(left, right, checkDtypes, checkNames, checkOrder, checkExact, rtol, atol);
+        return ErrorHelper.CheckString(ptr);
+    }
     
     public static string SeriesName(SeriesHandle h) 
     {
@@ -190,42 +197,45 @@ public static partial class PolarsWrapper
     {
         if (NativeBindings.pl_series_get_decimal(s, (UIntPtr)idx, out Int128 val, out UIntPtr scalePtr))
         {
-            int scale = (int)scalePtr;
-            
-            // Int128 -> Decimal 转换
-            // Decimal 构造函数不支持 Int128，但支持 int[] bits
-            // 简单做法：(decimal)val / 10^scale
-            // 但这样会转 double 丢精度。
-            
-            // 正确做法：直接构造 decimal
-            // decimal 布局: flags, hi, lo, mid
-            // 我们需要先把 Int128 变成 decimal (纯整数)，然后设 scale
+            return DecimalFromParts(val, (int)scalePtr);
+        }
+        return null;
+    }
+    // Int128 + scale -> decimal，超出 decimal 范围时返回 null
+    private static decimal? DecimalFromParts(Int128 val, int scale)
+    {
+        // Int128 -> Decimal 转换
+        // Decimal 构造函数不支持 Int128，但支持 int[] bits
+        // 简单做法：(decimal)val / 10^scale
+        // 但这样会转 double 丢精度。
+        
+        // 正确做法：直接构造 decimal
+        // decimal 布局: flags, hi, lo, mid
+        // 我们需要先把 Int128 变成 decimal (纯整数)，然后设 scale
+        
+        // 既然 val 是 i128，我们显式强转 decimal (C# 11+ 支持显式转换)
+        try 
+        {
+            decimal d = (decimal)val; 
+            // 手动应用 scale: d / 10^scale
+            // 或者更高效：创建一个新的 decimal 修改其 flags
+            // 但 C# decimal 是 immutable struct，修改 flags 比较 hacky
+            // 最稳妥： d / PowersOf10[scale] (我们之前算过这个表)
             
-            // 既然 val 是 i128，我们显式强转 decimal (C# 11+ 支持显式转换)
-            try 
+            if (scale >= 0 && scale < PowersOf10.Length)
             {
-                decimal d = (decimal)val; 
-                // 手动应用 scale: d / 10^scale
-                // 或者更高效：创建一个新的 decimal 修改其 flags
-                // 但 C# decimal 是 immutable struct，修改 flags 比较 hacky
-                // 最稳妥： d / PowersOf10[scale] (我们之前算过这个表)
-                
-                if (scale >= 0 && scale < PowersOf10.Length)
-                {
-                    return d / PowersOf10[scale];
-                }
-                else
-                {
-                    // Fallback using double division if scale is huge (unlikely)
-                    return d / (decimal)Math.Pow(10, scale);
-                }
+                return d / PowersOf10[scale];
             }
-            catch (OverflowException)
+            else
             {
-                return null; // 超出 decimal 范围
+                // Fallback using double division if scale is huge (unlikely)
+                return d / (decimal)Math.Pow(10, scale);
             }
         }
-        return null;
+        catch (OverflowException)
+        {
+            return null; // 超出 decimal 范围
+        }
     }
     // Date: Days since 1970-01-01
     public static DateOnly? SeriesGetDate(SeriesHandle s, long idx)
@@ -249,15 +259,12 @@ public static partial class PolarsWrapper
         return null;
     }
 
-    // Datetime: Microseconds since 1970-01-01 (Assuming 'us' time unit)
+    // Datetime: 按列的时间单位换算；带时区时为 UTC，无时区时为 Unspecified (与 GetDatetime 一致)
     public static DateTime? SeriesGetDatetime(SeriesHandle s, long idx)
     {
-        if (NativeBindings.pl_series_get_datetime(s, (UIntPtr)idx, out long us))
+        if (NativeBindings.pl_series_get_datetime(s, (UIntPtr)idx, out long val, out PlTimeUnit unit, out IntPtr tzPtr))
         {
-            // .NET Ticks = 100ns. 1 us = 10 ticks.
-            // Unix Epoch Ticks = 621355968000000000
-            long ticks = (us * 10) + 621355968000000000L;
-            return new DateTime(ticks, DateTimeKind.Utc); // 默认 UTC 语义
+            return ToDateTime(val, unit, tzPtr).Value;
         }
         return null;
     }
@@ -328,6 +335,11 @@ public static partial class PolarsWrapper
     {
         return (long)NativeBindings.pl_series_null_count(s);
     }
+    // Memory (Rechunk 和 ShrinkToFit 原地修改)
+    public static long SeriesEstimatedSize(SeriesHandle s) => (long)NativeBindings.pl_series_estimated_size(s);
+    public static long SeriesNChunks(SeriesHandle s) => (long)NativeBindings.pl_series_n_chunks(s);
+    public static void SeriesRechunk(SeriesHandle s) => NativeBindings.pl_series_rechunk(s);
+    public static void SeriesShrinkToFit(SeriesHandle s) => NativeBindings.pl_series_shrink_to_fit(s);
     // Ops
     public static SeriesHandle SeriesAdd(SeriesHandle s1, SeriesHandle s2) => ErrorHelper.Check(NativeBindings.pl_series_add(s1, s2));
     public static SeriesHandle SeriesSub(SeriesHandle s1, SeriesHandle s2) => ErrorHelper.Check(NativeBindings.pl_series_sub(s1, s2));
@@ -104,4 +104,72 @@ public static partial class PolarsWrapper
             }
         }
     }
-}
\ No newline at end of file
+
+    // 自定义数据源 (lazy scan)
+    // nextBatch(batchIdx, columns, nRows, predicate) 每次产出一个 RecordBatch，返回 null 表示读完
+    //   batchIdx 为 0 表示新一轮 scan 开始 (可以据此重置游标)
+    //   columns / nRows / predicate 只是下推提示，可以忽略
+    // 委托通过 GCHandle 保活，直到 Rust 端的查询计划被释放
+    public static LazyFrameHandle ScanCallback(
+        Dictionary<string, DataTypeHandle> schema,
+        Func<long, string[]?, ulong?, string?, RecordBatch?> nextBatch)
+    {
+        unsafe int Trampoline(
+            IntPtr userData, UIntPtr batchIdx,
+            IntPtr* withColumns, UIntPtr withColumnsLen,
+            UIntPtr nRows, IntPtr predicate,
+            CArrowArray* outArr, CArrowSchema* outSch, byte* msgBuf)
+        {
+            *outArr = default;
+            *outSch = default;
+            try
+            {
+                string[]? columns = null;
+                if (withColumns != null)
+                {
+                    columns = new string[(int)withColumnsLen];
+                    for (int i = 0; i < columns.Length; i++)
+                        columns[i] = Marshal.PtrToStringUTF8(withColumns[i]) ?? "";
+                }
+                ulong? limit = nRows == UIntPtr.Zero ? null : (ulong)nRows;
+                string? predicateStr = predicate == IntPtr.Zero ? null : Marshal.PtrToStringUTF8(predicate);
+
+                var batch = nextBatch((long)batchIdx, columns, limit, predicateStr);
+                if (batch == null) return 1;
+
+                CArrowArrayExporter.ExportRecordBatch(batch, outArr);
+                CArrowSchemaExporter.ExportSchema(batch.Schema, outSch);
+                return 0;
+            }
+            catch (Exception ex)
+            {
+                byte[] bytes = System.Text.Encoding.UTF8.GetBytes(ex.ToString());
+                int copyLen = Math.Min(bytes.Length, 1023);
+                Marshal.Copy(bytes, 0, (IntPtr)msgBuf, copyLen);
+                msgBuf[copyLen] = 0;
+
+                *outArr = default;
+                *outSch = default;
+                return 2;
+            }
+        }
+
+        unsafe
+        {
+            ScanBatchCallback callback = Trampoline;
+            GCHandle gcHandle = GCHandle.Alloc(callback);
+            IntPtr userData = GCHandle.ToIntPtr(gcHandle);
+
+            // Rust 在校验 schema 之前就接管了 userData，出错时也会调用 cleanup，
+            // 所以这里不需要 (也不能) 再释放 gcHandle
+            return WithSchemaArrays(schema, (namePtrs, typePtrs, len) =>
+                ErrorHelper.Check(NativeBindings.pl_scan_callback(
+                    namePtrs, typePtrs, len,
+                    callback,
+                    s_cleanupDelegate,
+                    userData
+                ))
+            );
+        }
+    }
+}
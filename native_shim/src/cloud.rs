@@ -0,0 +1,85 @@
+use polars::prelude::*;
+use polars_io::cloud::{AmazonS3ConfigKey, CloudOptions};
+use std::os::raw::c_char;
+use crate::types::ptr_to_str;
+
+// 包装 CloudOptions，传给 scan / sink 使用
+// 这些函数只借用它 (clone 一份)，C# 端负责 free
+pub struct CloudOptionsContext {
+    pub inner: CloudOptions,
+}
+
+/// 可空的 C 字符串 -> Option<String>
+fn opt_str(ptr: *const c_char) -> PolarsResult<Option<String>> {
+    if ptr.is_null() {
+        return Ok(None);
+    }
+    let s = ptr_to_str(ptr).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+    Ok(if s.is_empty() { None } else { Some(s.to_string()) })
+}
+
+/// scan / sink 用：null 表示不使用云配置
+pub(crate) fn clone_cloud_options(ptr: *mut CloudOptionsContext) -> Option<CloudOptions> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(unsafe { &*ptr }.inner.clone())
+    }
+}
+
+// S3 (以及 MinIO 等 S3 兼容存储)
+// 所有字符串参数都可以传 null，表示沿用环境变量 / 默认值
+// path_style: true = 路径风格 (http://host/bucket/key)，MinIO 通常需要
+// allow_http: 允许非 TLS 的 endpoint (本地 MinIO)
+// max_retries: 请求失败的重试次数
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_cloud_options_new_s3(
+    endpoint_ptr: *const c_char,
+    region_ptr: *const c_char,
+    access_key_id_ptr: *const c_char,
+    secret_access_key_ptr: *const c_char,
+    session_token_ptr: *const c_char,
+    path_style: bool,
+    allow_http: bool,
+    max_retries: usize
+) -> *mut CloudOptionsContext {
+    ffi_try!({
+        let mut configs: Vec<(AmazonS3ConfigKey, String)> = Vec::new();
+
+        let keyed = [
+            (AmazonS3ConfigKey::Endpoint, endpoint_ptr),
+            (AmazonS3ConfigKey::Region, region_ptr),
+            (AmazonS3ConfigKey::AccessKeyId, access_key_id_ptr),
+            (AmazonS3ConfigKey::SecretAccessKey, secret_access_key_ptr),
+            (AmazonS3ConfigKey::Token, session_token_ptr),
+        ];
+        for (key, ptr) in keyed {
+            if let Some(v) = opt_str(ptr)? {
+                configs.push((key, v));
+            }
+        }
+
+        configs.push((AmazonS3ConfigKey::VirtualHostedStyleRequest, (!path_style).to_string()));
+
+        // allow_http 属于 ClientConfigKey，object_store 没有直接导出，按字符串解析
+        let allow_http_key = "allow_http".parse::<AmazonS3ConfigKey>()
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        configs.push((allow_http_key, allow_http.to_string()));
+
+        let inner = CloudOptions::default()
+            .with_max_retries(max_retries)
+            .with_aws(configs);
+
+        Ok(Box::into_raw(Box::new(CloudOptionsContext { inner })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_cloud_options_free(ptr: *mut CloudOptionsContext) {
+    ffi_try_void!({
+        if !ptr.is_null() {
+            unsafe { let _ = Box::from_raw(ptr); }
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,451 @@
+use polars::prelude::*;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::os::raw::c_char;
+use std::path::{Path, PathBuf};
+use chrono::{NaiveDate, NaiveDateTime};
+use serde_json::Value;
+use crate::types::{LazyFrameContext, ptr_to_str};
+
+// ==========================================
+// Delta Lake (本地目录)
+// ==========================================
+// 只实现读路径：回放 _delta_log 得到当前有效的 Parquet 文件，
+// 然后复用 parquet scan，并把分区列以字面量的形式补回去。
+// 不支持 deletion vectors / column mapping，遇到时直接报错。
+
+// 分区列名 -> 分区值 (字符串形式，null 表示空分区)
+type PartitionValues = HashMap<String, Option<String>>;
+// path (相对表目录，已解码) -> 分区值
+type ActiveFiles = HashMap<String, PartitionValues>;
+
+/// 回放日志后得到的表状态
+struct DeltaSnapshot {
+    schema: Schema,
+    partition_columns: Vec<String>,
+    files: Vec<(String, PartitionValues)>,
+}
+
+/// _delta_log 目录的文件清单
+struct DeltaLogListing {
+    commits: Vec<i64>,
+    // checkpoint 版本 -> 文件列表 (multi-part checkpoint 会有多个)
+    checkpoints: HashMap<i64, Vec<PathBuf>>,
+}
+
+fn delta_err(msg: String) -> PolarsError {
+    PolarsError::ComputeError(msg.into())
+}
+
+/// add.path 是 URI 编码的 (例如空格是 %20)
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hi = (bytes[i + 1] as char).to_digit(16);
+            let lo = (bytes[i + 2] as char).to_digit(16);
+            if let (Some(hi), Some(lo)) = (hi, lo) {
+                out.push((hi * 16 + lo) as u8);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+// --- Schema ---
+
+/// Delta 的 schemaString (Spark StructType JSON) -> Polars DataType
+fn delta_type_to_dtype(t: &Value) -> PolarsResult<DataType> {
+    if let Some(name) = t.as_str() {
+        let dtype = match name {
+            "string" => DataType::String,
+            "long" => DataType::Int64,
+            "integer" => DataType::Int32,
+            "short" => DataType::Int16,
+            "byte" => DataType::Int8,
+            "float" => DataType::Float32,
+            "double" => DataType::Float64,
+            "boolean" => DataType::Boolean,
+            "binary" => DataType::Binary,
+            "date" => DataType::Date,
+            "timestamp" => DataType::Datetime(TimeUnit::Microseconds, Some(TimeZone::UTC)),
+            "timestamp_ntz" => DataType::Datetime(TimeUnit::Microseconds, None),
+            s if s.starts_with("decimal(") => {
+                // decimal(p,s)
+                let inner = s.trim_start_matches("decimal(").trim_end_matches(')');
+                let mut parts = inner.split(',').map(|p| p.trim().parse::<usize>());
+                match (parts.next(), parts.next()) {
+                    (Some(Ok(p)), Some(Ok(s))) => DataType::Decimal(Some(p), Some(s)),
+                    _ => return Err(delta_err(format!("Invalid delta decimal type: {}", name))),
+                }
+            },
+            other => return Err(delta_err(format!("Unsupported delta type: {}", other))),
+        };
+        return Ok(dtype);
+    }
+
+    match t.get("type").and_then(|v| v.as_str()) {
+        Some("struct") => Ok(DataType::Struct(delta_struct_fields(t)?)),
+        Some("array") => {
+            let inner = t.get("elementType")
+                .ok_or_else(|| delta_err("Delta array type without elementType".into()))?;
+            Ok(DataType::List(Box::new(delta_type_to_dtype(inner)?)))
+        },
+        Some("map") => {
+            // 与 parquet reader 一致：map 读成 List<Struct<key, value>>
+            let key = t.get("keyType")
+                .ok_or_else(|| delta_err("Delta map type without keyType".into()))?;
+            let value = t.get("valueType")
+                .ok_or_else(|| delta_err("Delta map type without valueType".into()))?;
+            let fields = vec![
+                Field::new("key".into(), delta_type_to_dtype(key)?),
+                Field::new("value".into(), delta_type_to_dtype(value)?),
+            ];
+            Ok(DataType::List(Box::new(DataType::Struct(fields))))
+        },
+        _ => Err(delta_err(format!("Unsupported delta type: {}", t))),
+    }
+}
+
+fn delta_struct_fields(t: &Value) -> PolarsResult<Vec<Field>> {
+    let fields = t.get("fields").and_then(|v| v.as_array())
+        .ok_or_else(|| delta_err("Delta struct type without fields".into()))?;
+
+    fields.iter()
+        .map(|f| {
+            let name = f.get("name").and_then(|v| v.as_str())
+                .ok_or_else(|| delta_err("Delta field without name".into()))?;
+            let dtype = delta_type_to_dtype(f.get("type").unwrap_or(&Value::Null))?;
+            Ok(Field::new(name.into(), dtype))
+        })
+        .collect()
+}
+
+fn parse_schema_string(schema_string: &str) -> PolarsResult<Schema> {
+    let v: Value = serde_json::from_str(schema_string)
+        .map_err(|e| delta_err(format!("Invalid delta schemaString: {}", e)))?;
+    Ok(Schema::from_iter(delta_struct_fields(&v)?))
+}
+
+// --- Log Replay ---
+
+/// 列出 _delta_log 里的 commit 版本和 checkpoint
+fn list_log(log_dir: &Path) -> PolarsResult<DeltaLogListing> {
+    let entries = std::fs::read_dir(log_dir)
+        .map_err(|e| delta_err(format!("Cannot read delta log {}: {}", log_dir.display(), e)))?;
+
+    let mut commits = Vec::new();
+    let mut checkpoints: HashMap<i64, Vec<PathBuf>> = HashMap::new();
+
+    for entry in entries {
+        let entry = entry.map_err(|e| delta_err(e.to_string()))?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+
+        // 00000000000000000010.json
+        // 00000000000000000010.checkpoint.parquet
+        // 00000000000000000010.checkpoint.0000000001.0000000002.parquet
+        let Some((version, rest)) = name.split_once('.') else { continue };
+        let Ok(version) = version.parse::<i64>() else { continue };
+
+        if rest == "json" {
+            commits.push(version);
+        } else if rest.starts_with("checkpoint.") && rest.ends_with(".parquet") {
+            checkpoints.entry(version).or_default().push(entry.path());
+        }
+    }
+
+    commits.sort_unstable();
+    Ok(DeltaLogListing { commits, checkpoints })
+}
+
+fn apply_metadata(
+    meta: &Value,
+    schema: &mut Option<Schema>,
+    partition_columns: &mut Vec<String>
+) -> PolarsResult<()> {
+    if let Some(s) = meta.get("schemaString").and_then(|v| v.as_str()) {
+        *schema = Some(parse_schema_string(s)?);
+    }
+    if let Some(cols) = meta.get("partitionColumns").and_then(|v| v.as_array()) {
+        *partition_columns = cols.iter()
+            .filter_map(|c| c.as_str().map(|s| s.to_string()))
+            .collect();
+    }
+    if let Some(mode) = meta.get("configuration")
+        .and_then(|c| c.get("delta.columnMapping.mode"))
+        .and_then(|v| v.as_str())
+        && mode != "none"
+    {
+        return Err(delta_err(format!("Delta column mapping mode '{}' is not supported", mode)));
+    }
+    Ok(())
+}
+
+/// 读取 checkpoint 里的 add / metaData
+fn read_checkpoint(
+    parts: &[PathBuf],
+    active: &mut ActiveFiles,
+    schema: &mut Option<Schema>,
+    partition_columns: &mut Vec<String>
+) -> PolarsResult<()> {
+    for part in parts {
+        let file = File::open(part)
+            .map_err(|e| delta_err(format!("Cannot open checkpoint {}: {}", part.display(), e)))?;
+        let df = ParquetReader::new(file).finish()?;
+
+        if let Ok(add) = df.column("add") {
+            let add = add.struct_()?;
+            if add.fields_as_series().iter().any(|f| f.name() == "deletionVector") {
+                let dv = add.field_by_name("deletionVector")?;
+                if dv.null_count() != dv.len() {
+                    return Err(delta_err("Delta deletion vectors are not supported".into()));
+                }
+            }
+            let paths = add.field_by_name("path")?;
+            let paths = paths.str()?;
+            let values = add.field_by_name("partitionValues")?;
+            let values = values.list()?;
+
+            for (path, pv) in paths.into_iter().zip(values) {
+                let Some(path) = path else { continue };
+                let mut partition = HashMap::new();
+                if let Some(pv) = pv {
+                    let pv = pv.struct_()?;
+                    let keys = pv.field_by_name("key")?;
+                    let vals = pv.field_by_name("value")?;
+                    for (k, v) in keys.str()?.into_iter().zip(vals.str()?) {
+                        if let Some(k) = k {
+                            partition.insert(k.to_string(), v.map(|s| s.to_string()));
+                        }
+                    }
+                }
+                active.insert(percent_decode(path), partition);
+            }
+        }
+
+        if let Ok(meta) = df.column("metaData") {
+            let meta = meta.struct_()?;
+            let schema_strings = meta.field_by_name("schemaString")?;
+            let parts_col = meta.field_by_name("partitionColumns")?;
+            let schema_strings = schema_strings.str()?;
+            let parts_col = parts_col.list()?;
+
+            for (s, cols) in schema_strings.into_iter().zip(parts_col) {
+                let Some(s) = s else { continue };
+                *schema = Some(parse_schema_string(s)?);
+                if let Some(cols) = cols {
+                    *partition_columns = cols.str()?.into_iter()
+                        .flatten()
+                        .map(|c| c.to_string())
+                        .collect();
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn partition_values(add: &Value) -> PartitionValues {
+    add.get("partitionValues")
+        .and_then(|v| v.as_object())
+        .map(|obj| {
+            obj.iter()
+                .map(|(k, v)| (k.clone(), v.as_str().map(|s| s.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 回放一个 JSON commit
+fn apply_commit(
+    path: &Path,
+    active: &mut ActiveFiles,
+    schema: &mut Option<Schema>,
+    partition_columns: &mut Vec<String>
+) -> PolarsResult<()> {
+    let file = File::open(path)
+        .map_err(|e| delta_err(format!("Cannot open delta commit {}: {}", path.display(), e)))?;
+
+    for line in BufReader::new(file).lines() {
+        let line = line.map_err(|e| delta_err(e.to_string()))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let action: Value = serde_json::from_str(&line)
+            .map_err(|e| delta_err(format!("Invalid delta action in {}: {}", path.display(), e)))?;
+
+        if let Some(add) = action.get("add") {
+            if add.get("deletionVector").is_some_and(|dv| !dv.is_null()) {
+                return Err(delta_err("Delta deletion vectors are not supported".into()));
+            }
+            if let Some(p) = add.get("path").and_then(|v| v.as_str()) {
+                active.insert(percent_decode(p), partition_values(add));
+            }
+        } else if let Some(remove) = action.get("remove") {
+            if let Some(p) = remove.get("path").and_then(|v| v.as_str()) {
+                active.remove(&percent_decode(p));
+            }
+        } else if let Some(meta) = action.get("metaData") {
+            apply_metadata(meta, schema, partition_columns)?;
+        }
+    }
+    Ok(())
+}
+
+/// version < 0 表示最新版本
+fn load_snapshot(table_dir: &Path, version: i64) -> PolarsResult<DeltaSnapshot> {
+    let log_dir = table_dir.join("_delta_log");
+    let DeltaLogListing { commits, checkpoints } = list_log(&log_dir)?;
+
+    let latest = commits.last().copied()
+        .into_iter()
+        .chain(checkpoints.keys().copied())
+        .max()
+        .ok_or_else(|| delta_err(format!("No delta log found in {}", log_dir.display())))?;
+
+    let target = if version < 0 { latest } else { version };
+    if target > latest {
+        return Err(delta_err(format!("Delta version {} does not exist (latest is {})", target, latest)));
+    }
+
+    // 1. 找到 <= target 的最近一个 checkpoint
+    let checkpoint = checkpoints.keys().copied().filter(|&v| v <= target).max();
+
+    let mut active = HashMap::new();
+    let mut schema = None;
+    let mut partition_columns = Vec::new();
+
+    let start = match checkpoint {
+        Some(v) => {
+            read_checkpoint(&checkpoints[&v], &mut active, &mut schema, &mut partition_columns)?;
+            v + 1
+        },
+        None => 0,
+    };
+
+    // 2. 回放 checkpoint 之后的 commit，中间缺版本说明日志已被清理
+    for v in start..=target {
+        if commits.binary_search(&v).is_err() {
+            return Err(delta_err(format!("Delta commit for version {} is missing from the log", v)));
+        }
+        let path = log_dir.join(format!("{:020}.json", v));
+        apply_commit(&path, &mut active, &mut schema, &mut partition_columns)?;
+    }
+
+    let schema = schema
+        .ok_or_else(|| delta_err("Delta log does not contain table metadata".into()))?;
+
+    let mut files: Vec<_> = active.into_iter().collect();
+    // 按路径排序，保证扫描顺序稳定
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+
+    Ok(DeltaSnapshot { schema, partition_columns, files })
+}
+
+// --- 分区值 ---
+
+/// Delta 的 date 分区值: yyyy-MM-dd
+fn parse_partition_date(s: &str) -> Option<i32> {
+    let d = NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()?;
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1)?;
+    Some((d - epoch).num_days() as i32)
+}
+
+/// Delta 的 timestamp 分区值: yyyy-MM-dd HH:mm:ss[.SSSSSS]，
+/// 也可能是 ISO 8601 (T 分隔，带 Z 后缀)。timestamp 类型的值按 UTC 写入
+fn parse_partition_datetime(s: &str, unit: TimeUnit) -> Option<i64> {
+    let s = s.strip_suffix('Z').unwrap_or(s);
+    let dt = ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"].iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(s, fmt).ok())?
+        .and_utc();
+    match unit {
+        TimeUnit::Nanoseconds => dt.timestamp_nanos_opt(),
+        TimeUnit::Microseconds => Some(dt.timestamp_micros()),
+        TimeUnit::Milliseconds => Some(dt.timestamp_millis()),
+    }
+}
+
+/// 分区值 (字符串) -> 表 schema 类型的字面量。
+/// 日期/时间戳按 Delta 的格式显式解析，其它类型交给 cast
+fn partition_literal(name: &str, value: Option<&str>, dtype: &DataType) -> PolarsResult<Expr> {
+    let Some(v) = value else {
+        return Ok(lit(NULL).cast(dtype.clone()));
+    };
+    let invalid = || delta_err(format!("Invalid delta partition value for '{}': {}", name, v));
+    let expr = match dtype {
+        DataType::Date => {
+            lit(parse_partition_date(v).ok_or_else(invalid)?).cast(DataType::Date)
+        },
+        DataType::Datetime(unit, _) => {
+            lit(parse_partition_datetime(v, *unit).ok_or_else(invalid)?).cast(dtype.clone())
+        },
+        _ => lit(v.to_string()).cast(dtype.clone()),
+    };
+    Ok(expr)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_scan_delta(
+    path_ptr: *const c_char,
+    version: i64 // < 0 = 最新版本
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let path = ptr_to_str(path_ptr)
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        let table_dir = Path::new(path);
+
+        let snapshot = load_snapshot(table_dir, version)?;
+
+        if snapshot.files.is_empty() {
+            let lf = DataFrame::empty_with_schema(&snapshot.schema).lazy();
+            return Ok(Box::into_raw(Box::new(LazyFrameContext { inner: lf })));
+        }
+
+        // 每个文件一个 parquet scan，分区列用字面量补齐
+        let mut lfs = Vec::with_capacity(snapshot.files.len());
+        for (file, partition) in &snapshot.files {
+            let full_path = table_dir.join(file);
+            let mut lf = LazyFrame::scan_parquet(
+                PlPath::new(&full_path.to_string_lossy()),
+                ScanArgsParquet::default()
+            )?;
+
+            let partition_exprs = snapshot.partition_columns.iter()
+                .map(|c| {
+                    let dtype = snapshot.schema.get(c).cloned().unwrap_or(DataType::String);
+                    let value = partition.get(c).and_then(|v| v.as_deref());
+                    Ok(partition_literal(c, value, &dtype)?.alias(c.as_str()))
+                })
+                .collect::<PolarsResult<Vec<Expr>>>()?;
+            if !partition_exprs.is_empty() {
+                lf = lf.with_columns(partition_exprs);
+            }
+            lfs.push(lf);
+        }
+
+        // 不同版本写入的文件列可能不一致，用 diagonal 对齐，再按表 schema 排列。
+        // schema 里有但所有文件都没有的列 (比如后来 add column) 补成对应类型的 null
+        let args = UnionArgs { rechunk: false, parallel: true, ..Default::default() };
+        let mut lf = concat_lf_diagonal(lfs, args)?;
+        let file_schema = lf.collect_schema()?;
+        let exprs: Vec<Expr> = snapshot.schema.iter()
+            .map(|(name, dtype)| {
+                if file_schema.contains(name) {
+                    col(name.clone())
+                } else {
+                    lit(NULL).cast(dtype.clone()).alias(name.clone())
+                }
+            })
+            .collect();
+        let lf = lf.select(exprs);
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: lf })))
+    })
+}
@@ -1,12 +1,16 @@
 use polars::prelude::*;
+use either::Either;
 use polars_core::utils::concat_df;
 use std::ffi::CStr;
 use std::{ffi::CString, os::raw::c_char};
 use crate::types::*;
-use polars::lazy::frame::pivot::pivot as pivot_impl; 
+use polars::lazy::frame::pivot::{pivot as pivot_impl, pivot_stable as pivot_stable_impl};
 use polars::lazy::dsl::UnpivotArgsDSL;
 use polars::functions::{concat_df_horizontal,concat_df_diagonal};
 use crate::series::SeriesContext;
+use crate::lazy::{asof_join_lf, build_asof_options, build_dynamic_options, build_rolling_options, lazy_group_by};
+use crate::lazy::{describe_lf, fill_nan_lf, fill_null_lf, interpolate_lf, opt_ptr_str, take_pivot_agg, take_selector};
+use crate::selectors::SelectorContext;
 // ==========================================
 // 0. Memory Safety
 // ==========================================
@@ -96,7 +100,8 @@ gen_eager_op_vec!(pl_with_columns, with_columns);
 pub extern "C" fn pl_groupby_agg(
     df_ptr: *mut DataFrameContext,
     by_ptr: *const *mut ExprContext, by_len: usize,
-    agg_ptr: *const *mut ExprContext, agg_len: usize
+    agg_ptr: *const *mut ExprContext, agg_len: usize,
+    maintain_order: bool
 ) -> *mut DataFrameContext {
     ffi_try!({
         let ctx = unsafe { &mut *df_ptr };
@@ -106,8 +111,7 @@ pub extern "C" fn pl_groupby_agg(
         let agg_exprs = unsafe { consume_exprs_array(agg_ptr, agg_len) };
 
         // 链式调用
-        let res_df = ctx.df.clone().lazy()
-            .group_by(by_exprs)
+        let res_df = lazy_group_by(ctx.df.clone().lazy(), by_exprs, maintain_order)
             .agg(agg_exprs)
             .collect()?;
 
@@ -115,6 +119,296 @@ pub extern "C" fn pl_groupby_agg(
     })
 }
 
+// 每组取前 n 行
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_groupby_head(
+    df_ptr: *mut DataFrameContext,
+    by_ptr: *const *mut ExprContext, by_len: usize,
+    n: usize,
+    maintain_order: bool
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let by_exprs = unsafe { consume_exprs_array(by_ptr, by_len) };
+
+        let res_df = lazy_group_by(ctx.df.clone().lazy(), by_exprs, maintain_order)
+            .head(Some(n))
+            .collect()?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: res_df })))
+    })
+}
+
+// 每组取后 n 行
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_groupby_tail(
+    df_ptr: *mut DataFrameContext,
+    by_ptr: *const *mut ExprContext, by_len: usize,
+    n: usize,
+    maintain_order: bool
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let by_exprs = unsafe { consume_exprs_array(by_ptr, by_len) };
+
+        let res_df = lazy_group_by(ctx.df.clone().lazy(), by_exprs, maintain_order)
+            .tail(Some(n))
+            .collect()?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: res_df })))
+    })
+}
+
+// ==========================================
+// Unique / Duplicated (去重与重复检测)
+// ==========================================
+// subset: 参与比较的列，长度为 0 表示所有列
+// keep: 0 = first, 1 = last, 2 = any, 3 = none
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_unique(
+    df_ptr: *mut DataFrameContext,
+    subset_ptr: *const *const c_char, subset_len: usize,
+    keep: i32,
+    maintain_order: bool
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let names = unsafe { ptr_to_names(subset_ptr, subset_len)? };
+        let subset = if names.is_empty() { None } else { Some(names) };
+
+        let res_df = ctx.df.unique_impl(maintain_order, subset, map_unique_keep(keep)?, None)?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: res_df })))
+    })
+}
+
+// 只保留 subset 中的列，空 subset 表示整张表
+fn select_subset(df: &DataFrame, names: Vec<PlSmallStr>) -> PolarsResult<DataFrame> {
+    if names.is_empty() { Ok(df.clone()) } else { df.select(names) }
+}
+
+// 返回 Boolean Series: 该行是否在 subset 上出现多次
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_is_duplicated(
+    df_ptr: *mut DataFrameContext,
+    subset_ptr: *const *const c_char, subset_len: usize
+) -> *mut SeriesContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let names = unsafe { ptr_to_names(subset_ptr, subset_len)? };
+
+        let mask = select_subset(&ctx.df, names)?.is_duplicated()?;
+        let series = mask.with_name("is_duplicated".into()).into_series();
+
+        Ok(Box::into_raw(Box::new(SeriesContext { series })))
+    })
+}
+
+// 返回 Boolean Series: 该行在 subset 上是否唯一
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_is_unique(
+    df_ptr: *mut DataFrameContext,
+    subset_ptr: *const *const c_char, subset_len: usize
+) -> *mut SeriesContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let names = unsafe { ptr_to_names(subset_ptr, subset_len)? };
+
+        let mask = select_subset(&ctx.df, names)?.is_unique()?;
+        let series = mask.with_name("is_unique".into()).into_series();
+
+        Ok(Box::into_raw(Box::new(SeriesContext { series })))
+    })
+}
+
+// 不同行的数量，结果写入 out_val
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_n_unique(
+    df_ptr: *mut DataFrameContext,
+    subset_ptr: *const *const c_char, subset_len: usize,
+    out_val: *mut usize
+) {
+    ffi_try_void!({
+        let ctx = unsafe { &*df_ptr };
+        let names = unsafe { ptr_to_names(subset_ptr, subset_len)? };
+
+        let df = select_subset(&ctx.df, names)?;
+        let n = df.unique_impl(false, None, UniqueKeepStrategy::Any, None)?.height();
+
+        unsafe { *out_val = n; }
+        Ok(())
+    })
+}
+
+// 按行计算哈希 (UInt64)，相同 seed 下结果稳定
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_hash_rows(
+    df_ptr: *mut DataFrameContext,
+    seed: u64
+) -> *mut SeriesContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let mut df = ctx.df.clone();
+
+        let hashes = df.hash_rows(Some(PlSeedableRandomStateQuality::seed_from_u64(seed)))?;
+        let series = hashes.with_name("hash".into()).into_series();
+
+        Ok(Box::into_raw(Box::new(SeriesContext { series })))
+    })
+}
+
+// ==========================================
+// Partition By (按分组拆分成多个 DataFrame)
+// ==========================================
+// 返回 DataFrameContext 指针数组，长度写入 out_len
+// out_keys: 每个分组对应的键 (一行一个分组，顺序和返回的数组一致)
+// 用 pl_dataframe_array_take 逐个取出，最后用 pl_dataframe_array_free 释放数组
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_partition_by(
+    df_ptr: *mut DataFrameContext,
+    by_ptr: *const *const c_char, by_len: usize,
+    maintain_order: bool,
+    include_key: bool,
+    out_keys: *mut *mut DataFrameContext,
+    out_len: *mut usize
+) -> *mut *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+
+        let by = unsafe { ptr_to_names(by_ptr, by_len)? };
+        if by.is_empty() {
+            return Err(PolarsError::ComputeError("partition_by requires at least one key column".into()));
+        }
+
+        // 先保留键列，取出每组的键之后再按需删掉
+        let parts = if maintain_order {
+            ctx.df.partition_by_stable(by.clone(), true)?
+        } else {
+            ctx.df.partition_by(by.clone(), true)?
+        };
+
+        let mut keys = ctx.df.select(by.clone())?.clear();
+        for part in &parts {
+            keys.vstack_mut(&part.select(by.clone())?.head(Some(1)))?;
+        }
+
+        let mut frames: Vec<*mut DataFrameContext> = Vec::with_capacity(parts.len());
+        for part in parts {
+            let df = if include_key { part } else { part.drop_many(by.clone()) };
+            frames.push(Box::into_raw(Box::new(DataFrameContext { df })));
+        }
+
+        unsafe {
+            *out_len = frames.len();
+            *out_keys = Box::into_raw(Box::new(DataFrameContext { df: keys }));
+        }
+        Ok(Box::into_raw(frames.into_boxed_slice()) as *mut *mut DataFrameContext)
+    })
+}
+
+// 从数组中取出第 idx 个 DataFrame (所有权交给调用方，槽位置空)
+// 下标越界或者该槽位已经被取走时报错
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_array_take(
+    ptr: *mut *mut DataFrameContext,
+    len: usize,
+    idx: usize
+) -> *mut DataFrameContext {
+    ffi_try!({
+        if ptr.is_null() {
+            return Err(PolarsError::ComputeError("DataFrame array is null".into()));
+        }
+        if idx >= len {
+            return Err(PolarsError::OutOfBounds(
+                format!("DataFrame array index {} out of bounds for length {}", idx, len).into()
+            ));
+        }
+        let taken = unsafe { std::mem::replace(&mut *ptr.add(idx), std::ptr::null_mut()) };
+        if taken.is_null() {
+            return Err(PolarsError::ComputeError(format!("DataFrame at index {} was already taken", idx).into()));
+        }
+        Ok(taken)
+    })
+}
+
+// 释放指针数组，以及其中尚未被取走的 DataFrame
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_array_free(ptr: *mut *mut DataFrameContext, len: usize) {
+    if !ptr.is_null() {
+        unsafe {
+            let frames = Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr, len));
+            for &p in frames.iter() {
+                if !p.is_null() {
+                    let _ = Box::from_raw(p);
+                }
+            }
+        }
+    }
+}
+
+// 时间窗口分组，参数含义同 pl_lazy_group_by_dynamic
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_group_by_dynamic(
+    df_ptr: *mut DataFrameContext,
+    index_ptr: *mut ExprContext,
+    by_ptr: *const *mut ExprContext, by_len: usize,
+    every_ptr: *const c_char,
+    period_ptr: *const c_char,
+    offset_ptr: *const c_char,
+    label_ptr: *const c_char,
+    include_boundaries: bool,
+    closed_ptr: *const c_char,
+    start_by_ptr: *const c_char,
+    aggs_ptr: *const *mut ExprContext, aggs_len: usize
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let index = unsafe { Box::from_raw(index_ptr) };
+        let by = unsafe { consume_exprs_array(by_ptr, by_len) };
+        let aggs = unsafe { consume_exprs_array(aggs_ptr, aggs_len) };
+
+        let options = build_dynamic_options(
+            every_ptr, period_ptr, offset_ptr,
+            label_ptr, include_boundaries, closed_ptr, start_by_ptr
+        )?;
+
+        let res_df = ctx.df.clone().lazy()
+            .group_by_dynamic(index.inner, by, options)
+            .agg(aggs)
+            .collect()?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: res_df })))
+    })
+}
+
+// 滚动窗口分组，参数含义同 pl_lazy_rolling
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_rolling(
+    df_ptr: *mut DataFrameContext,
+    index_ptr: *mut ExprContext,
+    by_ptr: *const *mut ExprContext, by_len: usize,
+    period_ptr: *const c_char,
+    offset_ptr: *const c_char,
+    closed_ptr: *const c_char,
+    aggs_ptr: *const *mut ExprContext, aggs_len: usize
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let index = unsafe { Box::from_raw(index_ptr) };
+        let by = unsafe { consume_exprs_array(by_ptr, by_len) };
+        let aggs = unsafe { consume_exprs_array(aggs_ptr, aggs_len) };
+
+        let options = build_rolling_options(period_ptr, offset_ptr, closed_ptr)?;
+
+        let res_df = ctx.df.clone().lazy()
+            .rolling(index.inner, by, options)
+            .agg(aggs)
+            .collect()?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: res_df })))
+    })
+}
+
 // ==========================================
 // Join (连接)
 // ==========================================
@@ -125,20 +419,22 @@ pub extern "C" fn pl_join(
     right_ptr: *mut DataFrameContext,
     left_on_ptr: *const *mut ExprContext, left_on_len: usize,
     right_on_ptr: *const *mut ExprContext, right_on_len: usize,
-    how_code: i32
+    how_code: i32,
+    suffix_ptr: *const c_char, // 可空
+    validate: i32,
+    coalesce: i32,
+    nulls_equal: bool,
+    maintain_order: i32
 ) -> *mut DataFrameContext {
     ffi_try!({
         let left_ctx = unsafe { &*left_ptr };
         let right_ctx = unsafe { &*right_ptr };
 
-        // 匹配 JoinType
-        let how = map_jointype(how_code);
-
         let left_on = unsafe { consume_exprs_array(left_on_ptr, left_on_len) };
         let right_on = unsafe { consume_exprs_array(right_on_ptr, right_on_len) };
 
         // 0.50 写法
-        let args = JoinArgs::new(how);
+        let args = build_join_args(how_code, suffix_ptr, validate, coalesce, nulls_equal, maintain_order)?;
         
         let res_df = left_ctx.df.clone().lazy()
             .join(right_ctx.df.clone().lazy(), left_on, right_on, args)
@@ -147,27 +443,98 @@ pub extern "C" fn pl_join(
         Ok(Box::into_raw(Box::new(DataFrameContext { df: res_df })))
     })
 }
+// 不等值 / 区间 Join
+// predicates 同时引用左右两侧的列，右侧重名列需要带上 suffix (默认 "_right")
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_join_where(
+    left_ptr: *mut DataFrameContext,
+    right_ptr: *mut DataFrameContext,
+    predicates_ptr: *const *mut ExprContext, predicates_len: usize,
+    suffix_ptr: *const c_char // 可空
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let left_ctx = unsafe { &*left_ptr };
+        let right_ctx = unsafe { &*right_ptr };
+        let predicates = unsafe { consume_exprs_array(predicates_ptr, predicates_len) };
+
+        let mut builder = left_ctx.df.clone().lazy()
+            .join_builder()
+            .with(right_ctx.df.clone().lazy());
+        if !suffix_ptr.is_null() {
+            let suffix = ptr_to_str(suffix_ptr)
+                .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+            builder = builder.suffix(suffix);
+        }
+
+        let res_df = builder.join_where(predicates).collect()?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: res_df })))
+    })
+}
+// As-Of Join (eager)，参数含义同 pl_lazy_join_asof
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_join_asof(
+    left_ptr: *mut DataFrameContext,
+    right_ptr: *mut DataFrameContext,
+    left_on_ptr: *mut ExprContext,
+    right_on_ptr: *mut ExprContext,
+    by_left_ptr: *const *mut ExprContext, by_left_len: usize,
+    by_right_ptr: *const *mut ExprContext, by_right_len: usize,
+    strategy_ptr: *const c_char,
+    tolerance_kind: i32,
+    tolerance_ptr: *const c_char,
+    allow_eq: bool,
+    check_sortedness: bool
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let left_ctx = unsafe { &*left_ptr };
+        let right_ctx = unsafe { &*right_ptr };
+        let left_on = unsafe { Box::from_raw(left_on_ptr) };
+        let right_on = unsafe { Box::from_raw(right_on_ptr) };
+
+        let by_left_exprs = unsafe { consume_exprs_array(by_left_ptr, by_left_len) };
+        let by_right_exprs = unsafe { consume_exprs_array(by_right_ptr, by_right_len) };
+
+        let options = build_asof_options(
+            &by_left_exprs, &by_right_exprs,
+            strategy_ptr, tolerance_kind, tolerance_ptr,
+            allow_eq, check_sortedness
+        )?;
+        let res_df = asof_join_lf(
+            left_ctx.df.clone().lazy(), right_ctx.df.clone().lazy(),
+            left_on.inner, right_on.inner,
+            options
+        ).collect()?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: res_df })))
+    })
+}
 // ==========================================
 // Sort
 // ==========================================
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_sort(
     df_ptr: *mut DataFrameContext,
-    expr_ptr: *mut ExprContext,
-    descending: bool
+    exprs_ptr: *const *mut ExprContext,
+    exprs_len: usize,
+    descending_ptr: *const bool,
+    descending_len: usize,
+    nulls_last: bool,
+    maintain_order: bool,
+    multithreaded: bool
 ) -> *mut DataFrameContext {
     ffi_try!({
         let ctx = unsafe { &*df_ptr };
-        let expr_ctx = unsafe { Box::from_raw(expr_ptr) };
+        let exprs = unsafe { consume_exprs_array(exprs_ptr, exprs_len) };
+        let options = unsafe {
+            build_sort_options(descending_ptr, descending_len, nulls_last, maintain_order, multithreaded)
+        };
         
         // 0.50+ Eager Sort 支持表达式
         let res_df = ctx.df.clone()
             .lazy()
             // LazyFrame::sort 接受 Expr 列表
-            .sort_by_exprs(
-                vec![expr_ctx.inner], 
-                SortMultipleOptions::default().with_order_descending(descending)
-            )
+            .sort_by_exprs(exprs, options)
             .collect()?;
 
         Ok(Box::into_raw(Box::new(DataFrameContext { df: res_df })))
@@ -222,235 +589,941 @@ pub extern "C" fn pl_dataframe_schema(df_ptr: *mut DataFrameContext) -> *mut c_c
     CString::new(json).unwrap().into_raw()
 }
 
+// ==========================================
+// Memory (内存估算 / 整理)
+// ==========================================
+
+/// 估算占用的堆内存字节数 (共享 buffer 会被重复计算，是上界)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_estimated_size(df_ptr: *mut DataFrameContext) -> usize {
+    let ctx = unsafe { &*df_ptr };
+    ctx.df.estimated_size()
+}
+
+/// 所有列中最大的 chunk 数
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_n_chunks(df_ptr: *mut DataFrameContext) -> usize {
+    let ctx = unsafe { &*df_ptr };
+    ctx.df.max_n_chunks()
+}
+
+/// 原地把所有列合并成单个 chunk (多次 concat 之后用来整理内存)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_rechunk(df_ptr: *mut DataFrameContext) {
+    let ctx = unsafe { &mut *df_ptr };
+    ctx.df.rechunk_mut();
+}
+
+/// 原地释放多余的容量
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_shrink_to_fit(df_ptr: *mut DataFrameContext) {
+    let ctx = unsafe { &mut *df_ptr };
+    ctx.df.shrink_to_fit();
+}
+
 // --- Convenience Ops ---
 
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_dataframe_drop(df_ptr: *mut DataFrameContext, name: *const c_char) -> *mut DataFrameContext {
+pub extern "C" fn pl_dataframe_drop(df_ptr: *mut DataFrameContext, name: *const c_char) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let col_name = unsafe { CStr::from_ptr(name).to_string_lossy() };
+        
+        // Clone + Drop (Immutable Semantics)
+        let new_df = ctx.df.drop(&col_name)?;
+        
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: new_df })))
+    })
+}
+
+/// 一次删除多列；strict 时任何一列不存在都报错
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_drop_many(
+    df_ptr: *mut DataFrameContext,
+    names_ptr: *const *const c_char,
+    len: usize,
+    strict: bool
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let names = unsafe { ptr_to_names(names_ptr, len)? };
+
+        if strict {
+            for name in &names {
+                ctx.df.try_get_column_index(name)?;
+            }
+        }
+        let new_df = ctx.df.drop_many(names);
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: new_df })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_rename(df_ptr: *mut DataFrameContext, old: *const c_char, new: *const c_char) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let old_name = unsafe { CStr::from_ptr(old).to_string_lossy() };
+        let new_name = unsafe { CStr::from_ptr(new).to_string_lossy() };
+
+        // Clone + Rename
+        let mut new_df = ctx.df.clone();
+        new_df.rename(&old_name, PlSmallStr::from_str(&new_name))?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: new_df })))
+    })
+}
+
+// ==========================================
+// In-place Column Ops (原地修改)
+// ==========================================
+// 以下函数直接修改 df_ptr 指向的 DataFrame。
+// 传入的 Series / DataFrame 只是借用 (Clone 只增加 Arc 引用计数)，C# 侧 Handle 依然有效。
+
+/// 在 index 处插入一列 (列名不能重复，长度必须等于 height)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_insert_column(
+    df_ptr: *mut DataFrameContext,
+    index: usize,
+    s_ptr: *mut SeriesContext
+) {
+    ffi_try_void!({
+        let ctx = unsafe { &mut *df_ptr };
+        let series = unsafe { &(*s_ptr).series };
+        let width = ctx.df.width();
+        if index > width {
+            polars_bail!(OutOfBounds: "column index {} is out of bounds for DataFrame of width {}", index, width);
+        }
+        ctx.df.insert_column(index, series.clone())?;
+        Ok(())
+    })
+}
+
+/// 按名字替换一列，保留原位置和原列名
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_replace_column(
+    df_ptr: *mut DataFrameContext,
+    name_ptr: *const c_char,
+    s_ptr: *mut SeriesContext
+) {
+    ffi_try_void!({
+        let ctx = unsafe { &mut *df_ptr };
+        let name = ptr_to_str(name_ptr).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        let series = unsafe { &(*s_ptr).series };
+
+        let idx = ctx.df.try_get_column_index(name)?;
+        let new_col = series.clone().with_name(PlSmallStr::from_str(name));
+        ctx.df.replace_column(idx, new_col)?;
+        Ok(())
+    })
+}
+
+/// 在末尾追加多列
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_hstack(
+    df_ptr: *mut DataFrameContext,
+    columns_ptr: *const *mut SeriesContext,
+    len: usize
+) {
+    ffi_try_void!({
+        let ctx = unsafe { &mut *df_ptr };
+        if columns_ptr.is_null() || len == 0 {
+            return Ok(());
+        }
+        let slice = unsafe { std::slice::from_raw_parts(columns_ptr, len) };
+        let columns = slice.iter()
+            .enumerate()
+            .map(|(i, &p)| {
+                if p.is_null() {
+                    polars_bail!(ComputeError: "hstack: column at position {} is null", i);
+                }
+                Ok(unsafe { (*p).series.clone() }.into_column())
+            })
+            .collect::<PolarsResult<Vec<Column>>>()?;
+
+        ctx.df = ctx.df.hstack(&columns)?;
+        Ok(())
+    })
+}
+
+/// 追加另一个 DataFrame 的行。
+/// vstack: 只追加 chunk (快，但 chunk 数会增加)；
+/// extend: 把数据拷贝进已有内存 (适合频繁追加小块数据)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_vstack(
+    df_ptr: *mut DataFrameContext,
+    other_ptr: *mut DataFrameContext,
+    extend: bool
+) {
+    ffi_try_void!({
+        // 先 Clone (只是 Arc)，避免 df_ptr == other_ptr 时 &mut 和 & 同时存在
+        let other = unsafe { (*other_ptr).df.clone() };
+        let ctx = unsafe { &mut *df_ptr };
+        if extend {
+            ctx.df.extend(&other)?;
+        } else {
+            ctx.df.vstack_mut(&other)?;
+        }
+        Ok(())
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_drop_nulls(df_ptr: *mut DataFrameContext, subset: *const *const c_char, len: usize) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        
+        let new_df = if subset.is_null() || len == 0 {
+            // [修复] 显式告诉编译器：这里的 None 是 Option<&[String]> 类型的 None
+            // 这样泛型 S 就被推断为 String，且 String 实现了 Into<PlSmallStr>
+            ctx.df.drop_nulls::<String>(None)? 
+        } else {
+            let slice = unsafe { std::slice::from_raw_parts(subset, len) };
+            let cols: Vec<String> = slice.iter()
+                .map(|&p| unsafe { CStr::from_ptr(p).to_string_lossy().to_string() })
+                .collect();
+            // 这里 cols 是 Vec<String>，&cols 是 &[String]，S 推断为 String，没问题
+            ctx.df.drop_nulls(Some(&cols))?
+        };
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: new_df })))
+    })
+}
+
+// 参数含义同 pl_lazy_describe
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_describe(
+    df_ptr: *mut DataFrameContext,
+    percentiles_ptr: *const f64,
+    percentiles_len: usize
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let percentiles = if percentiles_len == 0 {
+            &[][..]
+        } else {
+            unsafe { std::slice::from_raw_parts(percentiles_ptr, percentiles_len) }
+        };
+
+        let df = describe_lf(ctx.df.clone().lazy(), percentiles)?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df })))
+    })
+}
+
+// 参数含义同 pl_lazy_fill_null
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_fill_null(
+    df_ptr: *mut DataFrameContext,
+    sel_ptr: *mut SelectorContext,
+    value_ptr: *mut ExprContext,
+    strategy: i32,
+    limit: u32
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let selector = take_selector(sel_ptr);
+        let value = if value_ptr.is_null() { None } else { Some(unsafe { Box::from_raw(value_ptr) }.inner) };
+
+        let new_df = fill_null_lf(ctx.df.clone().lazy(), selector, value, strategy, limit)?.collect()?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: new_df })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_fill_nan(
+    df_ptr: *mut DataFrameContext,
+    sel_ptr: *mut SelectorContext,
+    value_ptr: *mut ExprContext
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let selector = take_selector(sel_ptr);
+        let value = unsafe { Box::from_raw(value_ptr) };
+
+        let new_df = fill_nan_lf(ctx.df.clone().lazy(), selector, value.inner).collect()?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: new_df })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_interpolate(
+    df_ptr: *mut DataFrameContext,
+    sel_ptr: *mut SelectorContext,
+    method: i32
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let selector = take_selector(sel_ptr);
+
+        let new_df = interpolate_lf(ctx.df.clone().lazy(), selector, method)?.collect()?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: new_df })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_sample_n(
+    df_ptr: *mut DataFrameContext, 
+    n: usize, 
+    replacement: bool, 
+    shuffle: bool, 
+    seed: *const u64
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let s = if seed.is_null() { None } else { Some(unsafe { *seed }) };
+        
+        // [修复] 调用 literal 版本
+        let new_df = ctx.df.sample_n_literal(n, replacement, shuffle, s)?;
+        
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: new_df })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_sample_frac(
+    df_ptr: *mut DataFrameContext, 
+    frac: f64, 
+    replacement: bool, 
+    shuffle: bool, 
+    seed: *const u64
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let s = if seed.is_null() { None } else { Some(unsafe { *seed }) };
+        
+        // [修复] 手动计算 n，因为 sample_frac_literal 不存在或未公开
+        // 逻辑参考 Polars 源码: n = height * frac
+        let height = ctx.df.height();
+        let n = (height as f64 * frac) as usize;
+        
+        // 调用 sample_n_literal
+        let new_df = ctx.df.sample_n_literal(n, replacement, shuffle, s)?;
+        
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: new_df })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_clone(ptr: *mut DataFrameContext) -> *mut DataFrameContext {
+    ffi_try!({
+        // 1. 借用 (&*ptr) 而不是消费 (Box::from_raw)
+        let ctx = unsafe { &*ptr };
+        
+        // 2. Clone (Deep copy of the logical plan/structure, data is COW)
+        let new_df = ctx.df.clone();
+        
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: new_df })))
+    })
+}
+// --- 标量获取 (Scalar Access) ---
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_get_i64(
+    df_ptr: *mut DataFrameContext, 
+    col_name_ptr: *const c_char, 
+    row_index: usize,
+    out_val: *mut i64 // <--- [修改] 这是一个输出参数
+) -> bool { // <--- [修改] 返回值变为 bool: true=成功拿到值, false=失败/空/类型不对
+    let ctx = unsafe { &*df_ptr };
+    let col_name = ptr_to_str(col_name_ptr).unwrap_or("");
+    
+    // 如果列不存在，直接返回 false
+    let col = match ctx.df.column(col_name) {
+        Ok(c) => c,
+        Err(_) => return false,
+    };
+
+    // 获取单元格值
+    match col.get(row_index) {
+        Ok(val) => match val {
+            // 严格匹配整数类型
+            AnyValue::Int64(v) => { unsafe { *out_val = v }; true },
+            AnyValue::Int32(v) => { unsafe { *out_val = v as i64 }; true },
+            AnyValue::Int16(v) => { unsafe { *out_val = v as i64 }; true },
+            AnyValue::Int8(v) =>  { unsafe { *out_val = v as i64 }; true },
+            AnyValue::UInt64(v) => { 
+                // i64::MAX 是 9,223,372,036,854,775,807
+                if v > (i64::MAX as u64) {
+                    // 溢出！数值太大，无法用 i64 表示
+                    // 返回 false，这在 C#/F# 端会变成 null/None
+                    false 
+                } else {
+                    // 安全，可以转换
+                    unsafe { *out_val = v as i64 }; 
+                    true 
+                }
+            },// 潜在溢出风险
+            AnyValue::UInt32(v) => { unsafe { *out_val = v as i64 }; true }, // <--- 关键修复
+            AnyValue::UInt16(v) => { unsafe { *out_val = v as i64 }; true },
+            AnyValue::UInt8(v) =>  { unsafe { *out_val = v as i64 }; true },
+            // 如果是 Null 或者其他类型，都视为“无法获取 i64”
+            _ => false, 
+        },
+        Err(_) => false // 索引越界
+    }
+}
+
+// 同理，f64 也要改，防止 NaN 混淆
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_get_f64(
+    df_ptr: *mut DataFrameContext, 
+    col_name_ptr: *const c_char, 
+    row_index: usize,
+    out_val: *mut f64
+) -> bool {
+    let ctx = unsafe { &*df_ptr };
+    let col_name = ptr_to_str(col_name_ptr).unwrap_or("");
+    
+    let col = match ctx.df.column(col_name) {
+        Ok(c) => c,
+        Err(_) => return false,
+    };
+
+    // 整数 / Decimal 也可以转浮点，与 pl_series_get_f64 一致
+    match col.get(row_index).ok().as_ref().and_then(any_value_to_f64) {
+        Some(v) => { unsafe { *out_val = v }; true },
+        None => false,
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_get_string(
+    df_ptr: *mut DataFrameContext, 
+    col_name_ptr: *const c_char, 
+    row_index: usize
+) -> *mut c_char {
+    let ctx = unsafe { &*df_ptr };
+    let col_name = ptr_to_str(col_name_ptr).unwrap_or("");
+    
+    match ctx.df.column(col_name) {
+        Ok(col) => match col.get(row_index) {
+            // 显式处理 Null，返回空指针
+            Ok(AnyValue::Null) => std::ptr::null_mut(),
+            // 1. 本身就是字符串，直接返回
+            Ok(AnyValue::String(s)) => CString::new(s).unwrap().into_raw(),
+            Ok(AnyValue::StringOwned(s)) => CString::new(s.as_str()).unwrap().into_raw(),
+            
+            // 2. [关键修复] 其他类型 (如 Date, Int, Float)，调用 to_string()
+            // Polars 的 AnyValue 实现了 Display，会自动格式化 Date 为 "2023-12-25" 格式
+            Ok(v) => CString::new(v.to_string()).unwrap().into_raw(),
+            
+            // 3. 只有真正的获取失败才返回 null
+            Err(_) => std::ptr::null_mut()
+        },
+        Err(_) => std::ptr::null_mut()
+    }
+}
+
+// 以下 getter 与 series.rs 中的语义一致: 返回 false / null 表示列不存在、越界、值为 null 或类型不匹配
+fn get_cell<'a>(ctx: &'a DataFrameContext, col_name_ptr: *const c_char, row_index: usize) -> Option<AnyValue<'a>> {
+    let col_name = ptr_to_str(col_name_ptr).ok()?;
+    ctx.df.column(col_name).ok()?.get(row_index).ok()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_get_bool(
+    df_ptr: *mut DataFrameContext,
+    col_name_ptr: *const c_char,
+    row_index: usize,
+    out_val: *mut bool
+) -> bool {
+    let ctx = unsafe { &*df_ptr };
+    match get_cell(ctx, col_name_ptr, row_index) {
+        Some(AnyValue::Boolean(v)) => { unsafe { *out_val = v }; true },
+        _ => false,
+    }
+}
+
+// 距 1970-01-01 的天数
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_get_date(
+    df_ptr: *mut DataFrameContext,
+    col_name_ptr: *const c_char,
+    row_index: usize,
+    out_val: *mut i32
+) -> bool {
+    let ctx = unsafe { &*df_ptr };
+    match get_cell(ctx, col_name_ptr, row_index) {
+        Some(AnyValue::Date(v)) => { unsafe { *out_val = v }; true },
+        _ => false,
+    }
+}
+
+// 当天的纳秒数
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_get_time(
+    df_ptr: *mut DataFrameContext,
+    col_name_ptr: *const c_char,
+    row_index: usize,
+    out_val: *mut i64
+) -> bool {
+    let ctx = unsafe { &*df_ptr };
+    match get_cell(ctx, col_name_ptr, row_index) {
+        Some(AnyValue::Time(v)) => { unsafe { *out_val = v }; true },
+        _ => false,
+    }
+}
+
+// 见 write_datetime: out_val 按 out_unit 计，带时区时为 UTC；out_tz 需要用 pl_free_string 释放
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_get_datetime(
+    df_ptr: *mut DataFrameContext,
+    col_name_ptr: *const c_char,
+    row_index: usize,
+    out_val: *mut i64,
+    out_unit: *mut i32,
+    out_tz: *mut *mut c_char
+) -> bool {
+    let ctx = unsafe { &*df_ptr };
+    match get_cell(ctx, col_name_ptr, row_index) {
+        Some(v) => unsafe { write_datetime(v, out_val, out_unit, out_tz) },
+        None => false,
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_get_duration(
+    df_ptr: *mut DataFrameContext,
+    col_name_ptr: *const c_char,
+    row_index: usize,
+    out_val: *mut i64,
+    out_unit: *mut i32
+) -> bool {
+    let ctx = unsafe { &*df_ptr };
+    match get_cell(ctx, col_name_ptr, row_index) {
+        Some(AnyValue::Duration(v, tu)) => {
+            unsafe {
+                *out_val = v;
+                *out_unit = time_unit_code(tu);
+            }
+            true
+        },
+        _ => false,
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_get_decimal(
+    df_ptr: *mut DataFrameContext,
+    col_name_ptr: *const c_char,
+    row_index: usize,
+    out_val: *mut i128,
+    out_scale: *mut usize
+) -> bool {
+    let ctx = unsafe { &*df_ptr };
+    match get_cell(ctx, col_name_ptr, row_index) {
+        Some(AnyValue::Decimal(v, scale)) => {
+            unsafe {
+                *out_val = v;
+                *out_scale = scale;
+            }
+            true
+        },
+        _ => false,
+    }
+}
+
+// 返回的字节需要用 pl_free_bytes(ptr, out_len) 释放，null 表示没有值
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_get_binary(
+    df_ptr: *mut DataFrameContext,
+    col_name_ptr: *const c_char,
+    row_index: usize,
+    out_len: *mut usize
+) -> *mut u8 {
+    let ctx = unsafe { &*df_ptr };
+    let bytes: Box<[u8]> = match get_cell(ctx, col_name_ptr, row_index) {
+        Some(AnyValue::Binary(b)) => b.into(),
+        Some(AnyValue::BinaryOwned(b)) => b.into_boxed_slice(),
+        _ => return std::ptr::null_mut(),
+    };
+    unsafe { *out_len = bytes.len() };
+    Box::into_raw(bytes) as *mut u8
+}
+
+// Categorical / Enum 的类别字符串
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_get_categorical(
+    df_ptr: *mut DataFrameContext,
+    col_name_ptr: *const c_char,
+    row_index: usize
+) -> *mut c_char {
+    let ctx = unsafe { &*df_ptr };
+    match get_cell(ctx, col_name_ptr, row_index) {
+        Some(v @ (AnyValue::Categorical(..) | AnyValue::CategoricalOwned(..)
+            | AnyValue::Enum(..) | AnyValue::EnumOwned(..))) => match v.get_str() {
+            Some(s) => CString::new(s).map_or(std::ptr::null_mut(), |s| s.into_raw()),
+            None => std::ptr::null_mut(),
+        },
+        _ => std::ptr::null_mut(),
+    }
+}
+
+// List / Array / Struct 的值，序列化为 JSON 文本，null 表示没有值
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_get_nested(
+    df_ptr: *mut DataFrameContext,
+    col_name_ptr: *const c_char,
+    row_index: usize
+) -> *mut c_char {
+    let ctx = unsafe { &*df_ptr };
+    let Ok(col_name) = ptr_to_str(col_name_ptr) else { return std::ptr::null_mut() };
+    let Ok(column) = ctx.df.column(col_name) else { return std::ptr::null_mut() };
+    if !column.dtype().is_nested() || row_index >= column.len() {
+        return std::ptr::null_mut();
+    }
+
+    let s = column.as_materialized_series().slice(row_index as i64, 1);
+    match column_json_values(s).map(|v| v.into_iter().next().flatten()) {
+        Ok(Some(json)) => CString::new(json).map_or(std::ptr::null_mut(), |s| s.into_raw()),
+        _ => std::ptr::null_mut(),
+    }
+}
+
+// ==========================================
+// Row Extraction (批量取行)
+// ==========================================
+
+// 行优先的打包结果，一次调用取出多行，避免逐个单元格跨 FFI
+// cells / validity 长度都是 n_rows * n_cols，第 r 行第 c 列位于 r * n_cols + c
+// kinds (每列一个) 决定 cells 中 8 字节的解释方式:
+//   0 = bool (0/1), 1 = i64 (Int128 超出范围时报错), 2 = u64, 3 = f64 (bits)
+//   4 = string, 5 = binary, 10 = json (List/Array/Struct 等嵌套类型), 13 = decimal (十进制字符串，不损失精度)
+//       这四种 cells 存 heap 中的偏移，heap 中为 [u32 长度 (LE)][字节]
+//   6 = date (距 1970-01-01 的天数), 7 = datetime (微秒，无时区)
+//   8 = duration (微秒), 9 = time (当天的纳秒)
+//   11 = null (整列为 Null 类型), 12 = datetime (微秒，带时区，UTC)
+// validity: 1 = 有值, 0 = null (null 单元格的 cells 为 0，类型仍由 kinds 给出)
+#[repr(C)]
+pub struct RowBuffer {
+    pub n_rows: usize,
+    pub n_cols: usize,
+    pub kinds: *mut u8,
+    pub cells: *mut u64,
+    pub validity: *mut u8,
+    pub heap: *mut u8,
+    pub heap_len: usize,
+}
+
+fn push_heap(heap: &mut Vec<u8>, bytes: &[u8]) -> PolarsResult<u64> {
+    let len = u32::try_from(bytes.len())
+        .map_err(|_| PolarsError::ComputeError("Value too large for row buffer".into()))?;
+    let offset = heap.len() as u64;
+    heap.extend_from_slice(&len.to_le_bytes());
+    heap.extend_from_slice(bytes);
+    Ok(offset)
+}
+
+// 嵌套类型借助 JsonWriter 逐行序列化，再取出该列的值 (null 为 None)
+fn column_json_values(s: Series) -> PolarsResult<Vec<Option<String>>> {
+    let name = s.name().clone();
+    let mut single = DataFrame::new(vec![s.into_column()])?;
+    let mut buf: Vec<u8> = Vec::new();
+    JsonWriter::new(&mut buf)
+        .with_json_format(JsonFormat::JsonLines)
+        .finish(&mut single)?;
+
+    buf.split(|b| *b == b'\n')
+        .filter(|l| !l.is_empty())
+        .map(|line| {
+            let row: serde_json::Value = serde_json::from_slice(line)
+                .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+            Ok(match row.get(name.as_str()) {
+                Some(v) if !v.is_null() => Some(v.to_string()),
+                _ => None,
+            })
+        })
+        .collect()
+}
+
+fn pack_rows(df: &DataFrame) -> PolarsResult<RowBuffer> {
+    let n_rows = df.height();
+    let n_cols = df.width();
+    let mut kinds: Vec<u8> = Vec::with_capacity(n_cols);
+    let mut cells = vec![0u64; n_rows * n_cols];
+    let mut validity = vec![0u8; n_rows * n_cols];
+    let mut heap: Vec<u8> = Vec::new();
+
+    for (c, column) in df.get_columns().iter().enumerate() {
+        let s = column.as_materialized_series().rechunk();
+        let mut put = |r: usize, v: u64| {
+            cells[r * n_cols + c] = v;
+            validity[r * n_cols + c] = 1;
+        };
+
+        let kind = match s.dtype() {
+            DataType::Boolean => {
+                for (r, v) in s.bool()?.iter().enumerate() {
+                    if let Some(v) = v { put(r, v as u64) }
+                }
+                0
+            },
+            dt if dt.is_signed_integer() => {
+                let s = s.strict_cast(&DataType::Int64)?;
+                for (r, v) in s.i64()?.iter().enumerate() {
+                    if let Some(v) = v { put(r, v as u64) }
+                }
+                1
+            },
+            dt if dt.is_unsigned_integer() => {
+                let s = s.cast(&DataType::UInt64)?;
+                for (r, v) in s.u64()?.iter().enumerate() {
+                    if let Some(v) = v { put(r, v) }
+                }
+                2
+            },
+            dt if dt.is_float() => {
+                let s = s.cast(&DataType::Float64)?;
+                for (r, v) in s.f64()?.iter().enumerate() {
+                    if let Some(v) = v { put(r, v.to_bits()) }
+                }
+                3
+            },
+            DataType::Decimal(_, _) => {
+                let s = s.cast(&DataType::String)?;
+                for (r, v) in s.str()?.iter().enumerate() {
+                    if let Some(v) = v { put(r, push_heap(&mut heap, v.as_bytes())?) }
+                }
+                13
+            },
+            DataType::String | DataType::Categorical(_, _) | DataType::Enum(_, _) => {
+                let s = s.cast(&DataType::String)?;
+                for (r, v) in s.str()?.iter().enumerate() {
+                    if let Some(v) = v { put(r, push_heap(&mut heap, v.as_bytes())?) }
+                }
+                4
+            },
+            DataType::Binary => {
+                for (r, v) in s.binary()?.iter().enumerate() {
+                    if let Some(v) = v { put(r, push_heap(&mut heap, v)?) }
+                }
+                5
+            },
+            DataType::Date => {
+                let s = s.to_physical_repr().cast(&DataType::Int64)?;
+                for (r, v) in s.i64()?.iter().enumerate() {
+                    if let Some(v) = v { put(r, v as u64) }
+                }
+                6
+            },
+            DataType::Datetime(_, tz) => {
+                let s = s.cast(&DataType::Datetime(TimeUnit::Microseconds, tz.clone()))?;
+                let s = s.to_physical_repr();
+                for (r, v) in s.i64()?.iter().enumerate() {
+                    if let Some(v) = v { put(r, v as u64) }
+                }
+                if tz.is_some() { 12 } else { 7 }
+            },
+            DataType::Duration(_) => {
+                let s = s.cast(&DataType::Duration(TimeUnit::Microseconds))?;
+                let s = s.to_physical_repr();
+                for (r, v) in s.i64()?.iter().enumerate() {
+                    if let Some(v) = v { put(r, v as u64) }
+                }
+                8
+            },
+            DataType::Time => {
+                let s = s.to_physical_repr();
+                for (r, v) in s.i64()?.iter().enumerate() {
+                    if let Some(v) = v { put(r, v as u64) }
+                }
+                9
+            },
+            DataType::Null => 11,
+            _ => {
+                for (r, v) in column_json_values(s)?.into_iter().enumerate() {
+                    if let Some(v) = v { put(r, push_heap(&mut heap, v.as_bytes())?) }
+                }
+                10
+            },
+        };
+        kinds.push(kind);
+    }
+
+    let heap_len = heap.len();
+    Ok(RowBuffer {
+        n_rows,
+        n_cols,
+        kinds: Box::into_raw(kinds.into_boxed_slice()) as *mut u8,
+        cells: Box::into_raw(cells.into_boxed_slice()) as *mut u64,
+        validity: Box::into_raw(validity.into_boxed_slice()) as *mut u8,
+        heap: Box::into_raw(heap.into_boxed_slice()) as *mut u8,
+        heap_len,
+    })
+}
+
+// 取 [offset, offset + len) 的行，offset 可以为负数 (从末尾数)，超出范围的部分会被截掉
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_rows_packed(
+    df_ptr: *mut DataFrameContext,
+    offset: i64,
+    len: usize
+) -> *mut RowBuffer {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let buffer = pack_rows(&ctx.df.slice(offset, len))?;
+        Ok(Box::into_raw(Box::new(buffer)))
+    })
+}
+
+// 取单行 (支持负数索引)，越界时报错
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_row_packed(
+    df_ptr: *mut DataFrameContext,
+    index: i64
+) -> *mut RowBuffer {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let height = ctx.df.height() as i64;
+        let idx = if index < 0 { index + height } else { index };
+        if idx < 0 || idx >= height {
+            polars_bail!(OutOfBounds: "row index {} is out of bounds for DataFrame of height {}", index, height);
+        }
+        let buffer = pack_rows(&ctx.df.slice(idx, 1))?;
+        Ok(Box::into_raw(Box::new(buffer)))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_row_buffer_free(ptr: *mut RowBuffer) {
+    if ptr.is_null() {
+        return;
+    }
+    unsafe {
+        let buf = Box::from_raw(ptr);
+        let n = buf.n_rows * buf.n_cols;
+        let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(buf.kinds, buf.n_cols));
+        let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(buf.cells, n));
+        let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(buf.validity, n));
+        let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(buf.heap, buf.heap_len));
+    }
+}
+
+// 以 JSON records 的形式取出 [offset, offset + len) 的行: [{"a": 1, "b": "x"}, ...]
+// 返回的字符串需要用 pl_free_string 释放
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_rows_json(
+    df_ptr: *mut DataFrameContext,
+    offset: i64,
+    len: usize
+) -> *mut c_char {
     ffi_try!({
         let ctx = unsafe { &*df_ptr };
-        let col_name = unsafe { CStr::from_ptr(name).to_string_lossy() };
-        
-        // Clone + Drop (Immutable Semantics)
-        let new_df = ctx.df.drop(&col_name)?;
-        
-        Ok(Box::into_raw(Box::new(DataFrameContext { df: new_df })))
+        let mut df = ctx.df.slice(offset, len);
+        let mut buf: Vec<u8> = Vec::new();
+        JsonWriter::new(&mut buf)
+            .with_json_format(JsonFormat::Json)
+            .finish(&mut df)?;
+        let json = CString::new(buf).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        Ok(json.into_raw())
     })
 }
 
+// ==========================================
+// Head/Tail
+// ==========================================
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_dataframe_rename(df_ptr: *mut DataFrameContext, old: *const c_char, new: *const c_char) -> *mut DataFrameContext {
+pub extern "C" fn pl_head(df_ptr: *mut DataFrameContext, n: usize) -> *mut DataFrameContext {
     ffi_try!({
         let ctx = unsafe { &*df_ptr };
-        let old_name = unsafe { CStr::from_ptr(old).to_string_lossy() };
-        let new_name = unsafe { CStr::from_ptr(new).to_string_lossy() };
-
-        // Clone + Rename
-        let mut new_df = ctx.df.clone();
-        new_df.rename(&old_name, PlSmallStr::from_str(&new_name))?;
-
-        Ok(Box::into_raw(Box::new(DataFrameContext { df: new_df })))
+        let res_df = ctx.df.head(Some(n));
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: res_df })))
     })
 }
-
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_dataframe_drop_nulls(df_ptr: *mut DataFrameContext, subset: *const *const c_char, len: usize) -> *mut DataFrameContext {
+pub extern "C" fn pl_tail(df_ptr: *mut DataFrameContext, n: usize) -> *mut DataFrameContext {
     ffi_try!({
         let ctx = unsafe { &*df_ptr };
-        
-        let new_df = if subset.is_null() || len == 0 {
-            // [修复] 显式告诉编译器：这里的 None 是 Option<&[String]> 类型的 None
-            // 这样泛型 S 就被推断为 String，且 String 实现了 Into<PlSmallStr>
-            ctx.df.drop_nulls::<String>(None)? 
-        } else {
-            let slice = unsafe { std::slice::from_raw_parts(subset, len) };
-            let cols: Vec<String> = slice.iter()
-                .map(|&p| unsafe { CStr::from_ptr(p).to_string_lossy().to_string() })
-                .collect();
-            // 这里 cols 是 Vec<String>，&cols 是 &[String]，S 推断为 String，没问题
-            ctx.df.drop_nulls(Some(&cols))?
-        };
-
-        Ok(Box::into_raw(Box::new(DataFrameContext { df: new_df })))
+        let res_df = ctx.df.tail(Some(n));
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: res_df })))
     })
 }
 
+// ==========================================
+// Positional (按位置操作)
+// ==========================================
+// offset 为负数时从末尾开始计数
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_dataframe_sample_n(
-    df_ptr: *mut DataFrameContext, 
-    n: usize, 
-    replacement: bool, 
-    shuffle: bool, 
-    seed: *const u64
-) -> *mut DataFrameContext {
+pub extern "C" fn pl_dataframe_slice(df_ptr: *mut DataFrameContext, offset: i64, len: usize) -> *mut DataFrameContext {
     ffi_try!({
         let ctx = unsafe { &*df_ptr };
-        let s = if seed.is_null() { None } else { Some(unsafe { *seed }) };
-        
-        // [修复] 调用 literal 版本
-        let new_df = ctx.df.sample_n_literal(n, replacement, shuffle, s)?;
-        
-        Ok(Box::into_raw(Box::new(DataFrameContext { df: new_df })))
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: ctx.df.slice(offset, len) })))
     })
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_dataframe_sample_frac(
-    df_ptr: *mut DataFrameContext, 
-    frac: f64, 
-    replacement: bool, 
-    shuffle: bool, 
-    seed: *const u64
-) -> *mut DataFrameContext {
+pub extern "C" fn pl_dataframe_reverse(df_ptr: *mut DataFrameContext) -> *mut DataFrameContext {
     ffi_try!({
         let ctx = unsafe { &*df_ptr };
-        let s = if seed.is_null() { None } else { Some(unsafe { *seed }) };
-        
-        // [修复] 手动计算 n，因为 sample_frac_literal 不存在或未公开
-        // 逻辑参考 Polars 源码: n = height * frac
-        let height = ctx.df.height();
-        let n = (height as f64 * frac) as usize;
-        
-        // 调用 sample_n_literal
-        let new_df = ctx.df.sample_n_literal(n, replacement, shuffle, s)?;
-        
-        Ok(Box::into_raw(Box::new(DataFrameContext { df: new_df })))
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: ctx.df.reverse() })))
     })
 }
 
+// 正数向下移动，负数向上移动，空出的位置填 null
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_dataframe_clone(ptr: *mut DataFrameContext) -> *mut DataFrameContext {
+pub extern "C" fn pl_dataframe_shift(df_ptr: *mut DataFrameContext, n: i64) -> *mut DataFrameContext {
     ffi_try!({
-        // 1. 借用 (&*ptr) 而不是消费 (Box::from_raw)
-        let ctx = unsafe { &*ptr };
-        
-        // 2. Clone (Deep copy of the logical plan/structure, data is COW)
-        let new_df = ctx.df.clone();
-        
-        Ok(Box::into_raw(Box::new(DataFrameContext { df: new_df })))
+        let ctx = unsafe { &*df_ptr };
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: ctx.df.shift(n) })))
     })
 }
-// --- 标量获取 (Scalar Access) ---
-
-#[unsafe(no_mangle)]
-pub extern "C" fn pl_dataframe_get_i64(
-    df_ptr: *mut DataFrameContext, 
-    col_name_ptr: *const c_char, 
-    row_index: usize,
-    out_val: *mut i64 // <--- [修改] 这是一个输出参数
-) -> bool { // <--- [修改] 返回值变为 bool: true=成功拿到值, false=失败/空/类型不对
-    let ctx = unsafe { &*df_ptr };
-    let col_name = ptr_to_str(col_name_ptr).unwrap_or("");
-    
-    // 如果列不存在，直接返回 false
-    let col = match ctx.df.column(col_name) {
-        Ok(c) => c,
-        Err(_) => return false,
-    };
-
-    // 获取单元格值
-    match col.get(row_index) {
-        Ok(val) => match val {
-            // 严格匹配整数类型
-            AnyValue::Int64(v) => { unsafe { *out_val = v }; true },
-            AnyValue::Int32(v) => { unsafe { *out_val = v as i64 }; true },
-            AnyValue::Int16(v) => { unsafe { *out_val = v as i64 }; true },
-            AnyValue::Int8(v) =>  { unsafe { *out_val = v as i64 }; true },
-            AnyValue::UInt64(v) => { 
-                // i64::MAX 是 9,223,372,036,854,775,807
-                if v > (i64::MAX as u64) {
-                    // 溢出！数值太大，无法用 i64 表示
-                    // 返回 false，这在 C#/F# 端会变成 null/None
-                    false 
-                } else {
-                    // 安全，可以转换
-                    unsafe { *out_val = v as i64 }; 
-                    true 
-                }
-            },// 潜在溢出风险
-            AnyValue::UInt32(v) => { unsafe { *out_val = v as i64 }; true }, // <--- 关键修复
-            AnyValue::UInt16(v) => { unsafe { *out_val = v as i64 }; true },
-            AnyValue::UInt8(v) =>  { unsafe { *out_val = v as i64 }; true },
-            // 如果是 Null 或者其他类型，都视为“无法获取 i64”
-            _ => false, 
-        },
-        Err(_) => false // 索引越界
-    }
-}
 
-// 同理，f64 也要改，防止 NaN 混淆
+// 在最前面插入行号列 (UInt32)，从 offset 开始
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_dataframe_get_f64(
-    df_ptr: *mut DataFrameContext, 
-    col_name_ptr: *const c_char, 
-    row_index: usize,
-    out_val: *mut f64
-) -> bool {
-    let ctx = unsafe { &*df_ptr };
-    let col_name = ptr_to_str(col_name_ptr).unwrap_or("");
-    
-    let col = match ctx.df.column(col_name) {
-        Ok(c) => c,
-        Err(_) => return false,
-    };
+pub extern "C" fn pl_dataframe_with_row_index(
+    df_ptr: *mut DataFrameContext,
+    name_ptr: *const c_char,
+    offset: u32
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let name = ptr_to_str(name_ptr).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
 
-    match col.get(row_index) {
-        Ok(val) => match val {
-            AnyValue::Float64(v) => { unsafe { *out_val = v }; true },
-            AnyValue::Float32(v) => { unsafe { *out_val = v as f64 }; true },
-            // 整数也可以转浮点
-            AnyValue::Int64(v) => { unsafe { *out_val = v as f64 }; true },
-            AnyValue::Int32(v) => { unsafe { *out_val = v as f64 }; true },
-            _ => false, 
-        },
-        Err(_) => false
-    }
-}
+        let res_df = ctx.df.with_row_index(name.into(), Some(offset))?;
 
-#[unsafe(no_mangle)]
-pub extern "C" fn pl_dataframe_get_string(
-    df_ptr: *mut DataFrameContext, 
-    col_name_ptr: *const c_char, 
-    row_index: usize
-) -> *mut c_char {
-    let ctx = unsafe { &*df_ptr };
-    let col_name = ptr_to_str(col_name_ptr).unwrap_or("");
-    
-    match ctx.df.column(col_name) {
-        Ok(col) => match col.get(row_index) {
-            // 显式处理 Null，返回空指针
-            Ok(AnyValue::Null) => std::ptr::null_mut(),
-            // 1. 本身就是字符串，直接返回
-            Ok(AnyValue::String(s)) => CString::new(s).unwrap().into_raw(),
-            Ok(AnyValue::StringOwned(s)) => CString::new(s.as_str()).unwrap().into_raw(),
-            
-            // 2. [关键修复] 其他类型 (如 Date, Int, Float)，调用 to_string()
-            // Polars 的 AnyValue 实现了 Display，会自动格式化 Date 为 "2023-12-25" 格式
-            Ok(v) => CString::new(v.to_string()).unwrap().into_raw(),
-            
-            // 3. 只有真正的获取失败才返回 null
-            Err(_) => std::ptr::null_mut()
-        },
-        Err(_) => std::ptr::null_mut()
-    }
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: res_df })))
+    })
 }
 
-// ==========================================
-// Head/Tail
-// ==========================================
+// 按行号取行，越界时报错
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_head(df_ptr: *mut DataFrameContext, n: usize) -> *mut DataFrameContext {
+pub extern "C" fn pl_dataframe_gather(
+    df_ptr: *mut DataFrameContext,
+    indices_ptr: *const u32,
+    len: usize
+) -> *mut DataFrameContext {
     ffi_try!({
         let ctx = unsafe { &*df_ptr };
-        let res_df = ctx.df.head(Some(n));
+        let indices = if len == 0 { &[][..] } else { unsafe { std::slice::from_raw_parts(indices_ptr, len) } };
+        let idx = IdxCa::from_slice("".into(), indices);
+
+        let res_df = ctx.df.take(&idx)?;
+
         Ok(Box::into_raw(Box::new(DataFrameContext { df: res_df })))
     })
 }
+
+// 每隔 n 行取一行，从 offset 开始
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_tail(df_ptr: *mut DataFrameContext, n: usize) -> *mut DataFrameContext {
+pub extern "C" fn pl_dataframe_gather_every(
+    df_ptr: *mut DataFrameContext,
+    n: usize,
+    offset: usize
+) -> *mut DataFrameContext {
     ffi_try!({
+        if n == 0 {
+            return Err(PolarsError::ComputeError("gather_every: n must be greater than 0".into()));
+        }
         let ctx = unsafe { &*df_ptr };
-        let res_df = ctx.df.tail(Some(n));
-        Ok(Box::into_raw(Box::new(DataFrameContext { df: res_df })))
+        let columns = ctx.df.get_columns().iter()
+            .map(|c| c.gather_every(n, offset))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: DataFrame::new(columns)? })))
     })
 }
 // ==========================================
@@ -499,54 +1572,43 @@ pub extern "C" fn pl_explode(
 // ==========================================
 // Pivot & Unpivot
 // ==========================================
+// agg_ptr: 可空，非空时代替 agg_code，表达式通过 col("") 引用 pivot 元素
+// values / index 可以为空 (取剩余的所有列)
+// separator_ptr: 可空，多个 values 时列名为 "{value}{separator}{on_value}"，默认 "_"
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_pivot(
     df_ptr: *mut DataFrameContext,
     values_ptr: *const *const c_char, values_len: usize,
     index_ptr: *const *const c_char, index_len: usize,
     columns_ptr: *const *const c_char, columns_len: usize,
-    agg_code: i32
+    agg_code: i32,
+    agg_ptr: *mut ExprContext,
+    sort_columns: bool,
+    separator_ptr: *const c_char,
+    maintain_order: bool
 ) -> *mut DataFrameContext {
     ffi_try!({
         let ctx = unsafe { &*df_ptr };
-        
-        // 1. 转换字符串数组 (逻辑不变)
-        let to_strs = |ptr, len| unsafe {
-            let mut v = Vec::with_capacity(len);
-            for &p in std::slice::from_raw_parts(ptr, len) {
-                v.push(ptr_to_str(p).unwrap()); // 返回 &str
-            }
-            v
-        };
+        let agg_expr = take_pivot_agg(agg_code, agg_ptr);
 
-        let values = to_strs(values_ptr, values_len);
-        let index = to_strs(index_ptr, index_len);
-        let columns = to_strs(columns_ptr, columns_len);
-        
-        // 我们构建一个针对 "element" 的表达式
-        let el = col(""); 
-        let agg_expr = match agg_code {
-            1 => el.sum(),    // Sum
-            2 => el.min(),    // Min
-            3 => el.max(),    // Max
-            4 => el.mean(),   // Mean
-            5 => el.median(), // Median
-            6 => len(),       // Count
-            7 => len(),       // Len
-            8 => el.last(),   // Last
-            0 | _ => el.first(), // First (Default)
-        };
+        let values = unsafe { ptr_to_names(values_ptr, values_len)? };
+        let index = unsafe { ptr_to_names(index_ptr, index_len)? };
+        let columns = unsafe { ptr_to_names(columns_ptr, columns_len)? };
+        let separator = opt_ptr_str(separator_ptr)?;
+
+        let values = if values.is_empty() { None } else { Some(values) };
+        let index = if index.is_empty() { None } else { Some(index) };
 
-        // 3. 调用 polars::lazy::frame::pivot::pivot
-        // 这个函数就是你贴出的那个源代码，它接受 &DataFrame 和 Expr
-        let res_df = pivot_impl(
+        // polars::lazy::frame::pivot::pivot(_stable)
+        let pivot_fn = if maintain_order { pivot_stable_impl } else { pivot_impl };
+        let res_df = pivot_fn(
             &ctx.df,
-            columns,          // I0
-            Some(index),  // Option<I1>
-            Some(values),   // Option<I2>
-            false,          // sort_columns
-            Some(agg_expr), // Option<Expr>
-            None            // separator
+            columns,
+            index,
+            values,
+            sort_columns,
+            Some(agg_expr),
+            separator
         )?;
 
         Ok(Box::into_raw(Box::new(DataFrameContext { df: res_df })))
@@ -609,6 +1671,113 @@ pub extern "C" fn pl_unpivot(
     })
 }
 // ==========================================
+// Transpose / Dummies / Upsample
+// ==========================================
+
+// keep_names_ptr: 可空，把原列名保存为该列
+// header_ptr: 可空，用该 (字符串) 列的值作为新列名
+// names_ptr: header 为空时可选的新列名，为空则使用 column_0, column_1 ...
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_transpose(
+    df_ptr: *mut DataFrameContext,
+    keep_names_ptr: *const c_char,
+    header_ptr: *const c_char,
+    names_ptr: *const *const c_char, names_len: usize
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let keep_names_as = opt_ptr_str(keep_names_ptr)?;
+        let names = unsafe { ptr_to_names(names_ptr, names_len)? };
+
+        let new_col_names = match opt_ptr_str(header_ptr)? {
+            Some(h) => Some(Either::Left(PlSmallStr::from_str(h))),
+            None if !names.is_empty() => Some(Either::Right(names)),
+            None => None,
+        };
+
+        let res_df = ctx.df.clone().transpose_impl(keep_names_as, new_col_names)?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: res_df })))
+    })
+}
+
+// columns_ptr: 需要编码的列，为空表示所有列
+// separator_ptr: 可空，默认 "_"
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_to_dummies(
+    df_ptr: *mut DataFrameContext,
+    columns_ptr: *const *const c_char, columns_len: usize,
+    separator_ptr: *const c_char,
+    drop_first: bool,
+    drop_nulls: bool
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let columns = unsafe { ptr_to_names(columns_ptr, columns_len)? };
+        let separator = opt_ptr_str(separator_ptr)?;
+
+        let res_df = if columns.is_empty() {
+            ctx.df.to_dummies(separator, drop_first, drop_nulls)?
+        } else {
+            let columns = columns.iter().map(|c| c.as_str()).collect();
+            ctx.df.columns_to_dummies(columns, separator, drop_first, drop_nulls)?
+        };
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: res_df })))
+    })
+}
+
+// time_column: 已排序的时间列
+// every: 例如 "1d"、"30m"
+// by_ptr: 分组键 (可以为空)，先分组再在组内补齐
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_upsample(
+    df_ptr: *mut DataFrameContext,
+    time_column_ptr: *const c_char,
+    every_ptr: *const c_char,
+    by_ptr: *const *const c_char, by_len: usize,
+    maintain_order: bool
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let ctx = unsafe { &*df_ptr };
+        let time_column = ptr_to_str(time_column_ptr)
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        let every_str = opt_ptr_str(every_ptr)?
+            .ok_or_else(|| PolarsError::ComputeError("upsample: 'every' is required".into()))?;
+        let every = Duration::try_parse(every_str)?;
+        let by = unsafe { ptr_to_names(by_ptr, by_len)? };
+
+        let res_df = if by.is_empty() {
+            ctx.df.upsample(by, time_column, every)?
+        } else {
+            // polars 按组 upsample 后补出来的行分组键为空，
+            // 这里按键拆分后逐个 upsample，再用该组自己的键值填满整列 (键本身可以是 null)
+            let parts = if maintain_order {
+                ctx.df.partition_by_stable(by.clone(), true)?
+            } else {
+                ctx.df.partition_by(by.clone(), true)?
+            };
+            if parts.is_empty() {
+                ctx.df.clone()
+            } else {
+                let ups = parts.iter()
+                    .map(|part| {
+                        let mut up = part.upsample(Vec::<PlSmallStr>::new(), time_column, every)?;
+                        for name in &by {
+                            let key = part.column(name)?.new_from_index(0, up.height());
+                            up.with_column(key)?;
+                        }
+                        Ok(up)
+                    })
+                    .collect::<PolarsResult<Vec<DataFrame>>>()?;
+                concat_df(&ups)?
+            }
+        };
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df: res_df })))
+    })
+}
+// ==========================================
 // Concat
 // ==========================================
 #[unsafe(no_mangle)]
@@ -0,0 +1,290 @@
+use calamine::{open_workbook_auto, Data, Range, Reader};
+use chrono::{NaiveDate, NaiveDateTime};
+use polars::prelude::*;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use crate::datatypes::DataTypeContext;
+use crate::types::{DataFrameContext, ptr_to_str};
+
+const MS_PER_DAY: i64 = 86_400_000;
+const MAX_COLUMNS: u32 = 16_384; // XFD
+
+fn excel_err(e: impl std::fmt::Display) -> PolarsError {
+    PolarsError::ComputeError(format!("Excel error: {}", e).into())
+}
+
+/// "B3" -> (row, col)，从 0 开始；允许 "$B$3" 这种绝对引用写法
+fn parse_cell_ref(s: &str) -> PolarsResult<(u32, u32)> {
+    let s: String = s.trim().chars().filter(|c| *c != '$').collect();
+    let split = s.find(|c: char| c.is_ascii_digit())
+        .ok_or_else(|| excel_err(format!("Invalid cell reference: {}", s)))?;
+    let (letters, digits) = s.split_at(split);
+
+    if letters.is_empty() || !letters.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(excel_err(format!("Invalid cell reference: {}", s)));
+    }
+
+    // 逐位累加时检查溢出，超过 XFD (Excel 的最大列) 直接报错
+    let mut col: u32 = 0;
+    for c in letters.chars() {
+        let digit = c.to_ascii_uppercase() as u32 - 'A' as u32 + 1;
+        col = col.checked_mul(26)
+            .and_then(|v| v.checked_add(digit))
+            .filter(|v| *v <= MAX_COLUMNS)
+            .ok_or_else(|| excel_err(format!("Column out of range (max XFD): {}", s)))?;
+    }
+    let row: u32 = digits.parse()
+        .map_err(|_| excel_err(format!("Invalid cell reference: {}", s)))?;
+    if row == 0 {
+        return Err(excel_err(format!("Invalid cell reference: {}", s)));
+    }
+
+    Ok((row - 1, col - 1))
+}
+
+/// "A1:D10" -> ((0,0), (9,3))
+fn parse_cell_range(s: &str) -> PolarsResult<((u32, u32), (u32, u32))> {
+    let (a, b) = s.split_once(':')
+        .ok_or_else(|| excel_err(format!("Invalid cell range: {}", s)))?;
+    let start = parse_cell_ref(a)?;
+    let end = parse_cell_ref(b)?;
+    if end.0 < start.0 || end.1 < start.1 {
+        return Err(excel_err(format!("Invalid cell range: {}", s)));
+    }
+    Ok((start, end))
+}
+
+// 单元格归一化后的值，用来推断列类型
+enum Cell {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str, // 文本，兜底时直接用原始单元格
+    Datetime(i64), // ms since epoch
+    Duration(i64), // ms
+}
+
+fn parse_iso_datetime(s: &str) -> Option<i64> {
+    if let Ok(dt) = NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f") {
+        return Some(dt.and_utc().timestamp_millis());
+    }
+    if let Ok(d) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return Some(d.and_time(chrono::NaiveTime::MIN).and_utc().timestamp_millis());
+    }
+    None
+}
+
+fn to_cell(data: &Data, try_parse_dates: bool) -> Cell {
+    match data {
+        Data::Empty | Data::Error(_) => Cell::Null,
+        Data::Bool(b) => Cell::Bool(*b),
+        Data::Int(i) => Cell::Int(*i),
+        Data::Float(f) => Cell::Float(*f),
+        Data::String(_) => Cell::Str,
+        Data::DateTime(dt) => {
+            if !try_parse_dates {
+                return Cell::Float(dt.as_f64());
+            }
+            if dt.is_duration() {
+                match dt.as_duration() {
+                    Some(d) => Cell::Duration(d.num_milliseconds()),
+                    None => Cell::Float(dt.as_f64()),
+                }
+            } else {
+                match dt.as_datetime() {
+                    Some(v) => Cell::Datetime(v.and_utc().timestamp_millis()),
+                    None => Cell::Float(dt.as_f64()),
+                }
+            }
+        },
+        Data::DateTimeIso(s) => {
+            match try_parse_dates.then(|| parse_iso_datetime(s)).flatten() {
+                Some(ms) => Cell::Datetime(ms),
+                None => Cell::Str,
+            }
+        },
+        Data::DurationIso(_) => Cell::Str,
+    }
+}
+
+/// 按列推断类型：
+/// 全 Bool -> Boolean, 全 Int -> Int64, Int/Float 混合 -> Float64,
+/// 全日期 -> Date (没有时间部分) 或 Datetime(ms), 全时长 -> Duration(ms),
+/// 其余情况 (或空列) -> String
+fn build_column(name: PlSmallStr, raw: &[&Data], try_parse_dates: bool) -> Column {
+    let cells: Vec<Cell> = raw.iter().map(|d| to_cell(d, try_parse_dates)).collect();
+
+    let non_null = || cells.iter().filter(|c| !matches!(c, Cell::Null));
+    let has_values = non_null().next().is_some();
+
+    if has_values && non_null().all(|c| matches!(c, Cell::Bool(_))) {
+        let ca: BooleanChunked = cells.iter()
+            .map(|c| match c { Cell::Bool(b) => Some(*b), _ => None })
+            .collect();
+        return ca.with_name(name).into_column();
+    }
+
+    if has_values && non_null().all(|c| matches!(c, Cell::Int(_))) {
+        let ca: Int64Chunked = cells.iter()
+            .map(|c| match c { Cell::Int(i) => Some(*i), _ => None })
+            .collect();
+        return ca.with_name(name).into_column();
+    }
+
+    if has_values && non_null().all(|c| matches!(c, Cell::Int(_) | Cell::Float(_))) {
+        let ca: Float64Chunked = cells.iter()
+            .map(|c| match c {
+                Cell::Int(i) => Some(*i as f64),
+                Cell::Float(f) => Some(*f),
+                _ => None,
+            })
+            .collect();
+        return ca.with_name(name).into_column();
+    }
+
+    if has_values && non_null().all(|c| matches!(c, Cell::Datetime(_))) {
+        let values: Vec<Option<i64>> = cells.iter()
+            .map(|c| match c { Cell::Datetime(ms) => Some(*ms), _ => None })
+            .collect();
+
+        let date_only = values.iter().flatten().all(|ms| ms % MS_PER_DAY == 0);
+        if date_only {
+            let ca: Int32Chunked = values.iter()
+                .map(|v| v.map(|ms| (ms / MS_PER_DAY) as i32))
+                .collect();
+            return ca.with_name(name).into_date().into_column();
+        }
+        let ca: Int64Chunked = values.into_iter().collect();
+        return ca.with_name(name).into_datetime(TimeUnit::Milliseconds, None).into_column();
+    }
+
+    if has_values && non_null().all(|c| matches!(c, Cell::Duration(_))) {
+        let ca: Int64Chunked = cells.iter()
+            .map(|c| match c { Cell::Duration(ms) => Some(*ms), _ => None })
+            .collect();
+        return ca.with_name(name).into_duration(TimeUnit::Milliseconds).into_column();
+    }
+
+    // 兜底：保留单元格原始的文本表示
+    let ca: StringChunked = raw.iter()
+        .map(|d| match d {
+            Data::Empty | Data::Error(_) => None,
+            other => Some(other.to_string()),
+        })
+        .collect();
+    ca.with_name(name).into_column()
+}
+
+/// 表头：空名字用 column_{i} 填充，重名追加 _duplicated_{n} (和 CSV 读取一致)
+fn build_header(row: Option<&[Data]>, width: usize) -> Vec<PlSmallStr> {
+    let mut names: Vec<PlSmallStr> = Vec::with_capacity(width);
+    for i in 0..width {
+        let raw = row.and_then(|r| r.get(i)).map(|d| d.to_string()).unwrap_or_default();
+        let base = if raw.is_empty() { format!("column_{}", i + 1) } else { raw };
+
+        let mut name = base.clone();
+        let mut n = 0;
+        while names.iter().any(|existing| existing.as_str() == name) {
+            name = format!("{}_duplicated_{}", base, n);
+            n += 1;
+        }
+        names.push(name.into());
+    }
+    names
+}
+
+fn range_to_df(
+    range: &Range<Data>,
+    has_header: bool,
+    skip_rows: usize,
+    try_parse_dates: bool
+) -> PolarsResult<DataFrame> {
+    let width = range.width();
+    let mut rows = range.rows().skip(skip_rows);
+
+    let header_row = if has_header { rows.next() } else { None };
+    let names = build_header(header_row, width);
+    let data_rows: Vec<&[Data]> = rows.collect();
+
+    let columns = names.into_iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let raw: Vec<&Data> = data_rows.iter().map(|r| &r[i]).collect();
+            build_column(name, &raw, try_parse_dates)
+        })
+        .collect();
+
+    DataFrame::new(columns)
+}
+
+// ==========================================
+// 读取 Excel (xlsx / xlsm / xlsb / xls / ods)
+// ==========================================
+// sheet_name_ptr: 可空，为空时按 sheet_index 选择工作表
+// cell_range_ptr: 可空，例如 "B2:F100"，只读取该区域
+// skip_rows: 在 (区域内) 跳过前 n 行，之后才是表头
+// schema_*: 按列名覆盖推断出的类型 (strict cast)
+// try_parse_dates: 识别日期/时间单元格，关闭时保留 Excel 的序列号 (Float64)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_read_excel(
+    path_ptr: *const c_char,
+    sheet_name_ptr: *const c_char,
+    sheet_index: usize,
+    has_header: bool,
+    skip_rows: usize,
+    cell_range_ptr: *const c_char,
+    schema_names: *const *const c_char,
+    schema_types: *const *mut DataTypeContext,
+    schema_len: usize,
+    try_parse_dates: bool
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let path = ptr_to_str(path_ptr)
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+
+        let mut workbook = open_workbook_auto(path).map_err(excel_err)?;
+
+        let sheet_name = if sheet_name_ptr.is_null() {
+            None
+        } else {
+            let s = ptr_to_str(sheet_name_ptr)
+                .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+            if s.is_empty() { None } else { Some(s.to_string()) }
+        };
+
+        let mut range = match sheet_name {
+            Some(name) => workbook.worksheet_range(&name).map_err(excel_err)?,
+            None => workbook.worksheet_range_at(sheet_index)
+                .ok_or_else(|| excel_err(format!("Sheet index out of range: {}", sheet_index)))?
+                .map_err(excel_err)?,
+        };
+
+        if !cell_range_ptr.is_null() {
+            let s = ptr_to_str(cell_range_ptr)
+                .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+            if !s.is_empty() {
+                let (start, end) = parse_cell_range(s)?;
+                range = range.range(start, end);
+            }
+        }
+
+        let mut df = range_to_df(&range, has_header, skip_rows, try_parse_dates)?;
+
+        // Schema Overrides
+        if !schema_names.is_null() && schema_len > 0 {
+            let names_slice = unsafe { std::slice::from_raw_parts(schema_names, schema_len) };
+            let types_slice = unsafe { std::slice::from_raw_parts(schema_types, schema_len) };
+
+            for (name_ptr, type_ptr) in names_slice.iter().zip(types_slice) {
+                let name = unsafe { CStr::from_ptr(*name_ptr).to_string_lossy().to_string() };
+                let dtype = unsafe { &(**type_ptr).dtype };
+
+                let casted = df.column(&name)?.strict_cast(dtype)?;
+                df.with_column(casted)?;
+            }
+        }
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df })))
+    })
+}
@@ -147,6 +147,16 @@ fn map_closed_window(s: &str) -> ClosedWindow {
         _ => ClosedWindow::Left, // 默认左闭右开 [ )
     }
 }
+/// 严格版本: 未知的取值直接报错，不回退到 Left
+pub(crate) fn parse_closed_window(s: &str) -> PolarsResult<ClosedWindow> {
+    match s {
+        "left" => Ok(ClosedWindow::Left),
+        "right" => Ok(ClosedWindow::Right),
+        "both" => Ok(ClosedWindow::Both),
+        "none" => Ok(ClosedWindow::None),
+        _ => Err(PolarsError::ComputeError(format!("Unknown closed window: {}", s).into())),
+    }
+}
 macro_rules! gen_rolling_by_op {
     ($func_name:ident, $method:ident) => {
         #[unsafe(no_mangle)]
@@ -3,12 +3,19 @@ use polars_arrow::ffi::{self, ArrowArray, ArrowSchema, export_array_to_c, export
 use polars_arrow::array::StructArray;
 use polars_arrow::datatypes::{ArrowDataType, Field};
 use polars_core::prelude::CompatLevel;
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 use std::io::BufReader;
 use std::os::raw::c_char;
 use std::fs::File;
-use crate::types::{DataFrameContext,LazyFrameContext, ptr_to_str};
+use std::ffi::c_void;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use crate::types::{DataFrameContext,LazyFrameContext, ptr_to_str, ptr_to_names};
 use crate::datatypes::DataTypeContext;
+use crate::udf::CleanupCallback;
+use crate::cloud::{CloudOptionsContext, clone_cloud_options};
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_io::avro::{AvroCompression, AvroReader, AvroWriter};
 
 // ==========================================
 // 读取 csv
@@ -73,7 +80,8 @@ pub extern "C" fn pl_scan_csv(
     has_header: bool,
     separator: u8,
     skip_rows: usize,
-    try_parse_dates: bool // [新增参数]
+    try_parse_dates: bool, // [新增参数]
+    cloud_ptr: *mut CloudOptionsContext // 可空
 ) -> *mut LazyFrameContext {
     ffi_try!({
         let p = unsafe { CStr::from_ptr(path).to_string_lossy() };
@@ -82,7 +90,8 @@ pub extern "C" fn pl_scan_csv(
             .with_has_header(has_header)
             .with_separator(separator)
             .with_skip_rows(skip_rows)
-            .with_try_parse_dates(try_parse_dates); // LazyReader 通常直接支持这个
+            .with_try_parse_dates(try_parse_dates) // LazyReader 通常直接支持这个
+            .with_cloud_options(clone_cloud_options(cloud_ptr));
 
         // ... schema 逻辑 (记得用 Schema::with_capacity) ...
         if !schema_names.is_null() && schema_len > 0 {
@@ -121,12 +130,18 @@ pub extern "C" fn pl_read_parquet(path_ptr: *const c_char) -> *mut DataFrameCont
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_scan_parquet(path_ptr: *const c_char) -> *mut LazyFrameContext {
+pub extern "C" fn pl_scan_parquet(
+    path_ptr: *const c_char,
+    cloud_ptr: *mut CloudOptionsContext // 可空
+) -> *mut LazyFrameContext {
     ffi_try!({
         let path = ptr_to_str(path_ptr)
             .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
         
-        let args = ScanArgsParquet::default();
+        let args = ScanArgsParquet {
+            cloud_options: clone_cloud_options(cloud_ptr),
+            ..Default::default()
+        };
         // LazyFrame::scan_parquet 返回 Result，用 ? 抛出
         let lf = LazyFrame::scan_parquet(PlPath::new(path), args)?;
 
@@ -179,11 +194,17 @@ pub extern "C" fn pl_read_ipc(path_ptr: *const c_char) -> *mut DataFrameContext
     })
 }
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_scan_ipc(path_ptr: *const c_char) -> *mut LazyFrameContext {
+pub extern "C" fn pl_scan_ipc(
+    path_ptr: *const c_char,
+    cloud_ptr: *mut CloudOptionsContext // 可空
+) -> *mut LazyFrameContext {
     ffi_try!({
         let path = ptr_to_str(path_ptr).unwrap();
         // 0.50: ScanArgsIpc::default()
-        let args = ScanArgsIpc::default();
+        let args = ScanArgsIpc {
+            cloud_options: clone_cloud_options(cloud_ptr),
+            ..Default::default()
+        };
         let lf = LazyFrame::scan_ipc(PlPath::new(path), args)?;
         Ok(Box::into_raw(Box::new(LazyFrameContext { inner: lf })))
     })
@@ -192,7 +213,8 @@ pub extern "C" fn pl_scan_ipc(path_ptr: *const c_char) -> *mut LazyFrameContext
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_lazy_sink_ipc(
     lf_ptr: *mut LazyFrameContext,
-    path_ptr: *const c_char
+    path_ptr: *const c_char,
+    cloud_ptr: *mut CloudOptionsContext // 可空
 ) {
     ffi_try_void!({
         let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
@@ -210,7 +232,7 @@ pub extern "C" fn pl_lazy_sink_ipc(
         let _ = lf_ctx.inner.sink_ipc(
             target, 
             writer_options, 
-            None, // CloudOptions
+            clone_cloud_options(cloud_ptr),
             sink_options
         )?;
         
@@ -218,6 +240,69 @@ pub extern "C" fn pl_lazy_sink_ipc(
     })
 }
 
+// ==========================================
+// Avro
+// ==========================================
+// columns_ptr: 只读取这些列 (可空，表示全部列)
+// n_rows: 最多读取的行数，0 表示全部
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_read_avro(
+    path_ptr: *const c_char,
+    columns_ptr: *const *const c_char,
+    columns_len: usize,
+    n_rows: usize
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let path = ptr_to_str(path_ptr)
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+
+        let file = File::open(path)
+            .map_err(|e| PolarsError::ComputeError(format!("File not found: {}", e).into()))?;
+
+        let names = unsafe { ptr_to_names(columns_ptr, columns_len)? };
+        let columns = if names.is_empty() {
+            None
+        } else {
+            Some(names.into_iter().map(|n| n.to_string()).collect())
+        };
+
+        let df = AvroReader::new(file)
+            .with_columns(columns)
+            .with_n_rows(if n_rows == 0 { None } else { Some(n_rows) })
+            .finish()?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df })))
+    })
+}
+
+// compression: 0 = 不压缩, 1 = Deflate, 2 = Snappy
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_write_avro(
+    df_ptr: *mut DataFrameContext,
+    path_ptr: *const c_char,
+    compression: i32
+) {
+    ffi_try_void!({
+        let ctx = unsafe { &mut *df_ptr };
+        let path = ptr_to_str(path_ptr)
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+
+        let compression = match compression {
+            0 => None,
+            1 => Some(AvroCompression::Deflate),
+            2 => Some(AvroCompression::Snappy),
+            _ => return Err(PolarsError::ComputeError(format!("Invalid avro compression: {}", compression).into())),
+        };
+
+        let file = File::create(path)
+            .map_err(|e| PolarsError::ComputeError(format!("Could not create file: {}", e).into()))?;
+
+        AvroWriter::new(file)
+            .with_compression(compression)
+            .finish(&mut ctx.df)
+    })
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_dataframe_from_arrow_record_batch(
     c_array_ptr: *mut ffi::ArrowArray, 
@@ -241,38 +326,41 @@ pub extern "C" fn pl_dataframe_from_arrow_record_batch(
                 .map_err(|e| PolarsError::ComputeError(e.to_string().into()))? 
         };
         
-        let df = match array.as_any().downcast_ref::<StructArray>() {
-            Some(struct_arr) => {
-                // [修复] 类型注解改为 Vec<Column>
-                let columns: Vec<Column> = struct_arr
-                    .values()
-                    .iter()
-                    .zip(struct_arr.fields())
-                    .map(|(arr, field)| {
-                        let name = PlSmallStr::from_str(&field.name);
-                        
-                        // Series::from_arrow 返回 PolarsResult<Series>
-                        // 我们需要 map 它，把 Series 转为 Column
-                        Series::from_arrow(name, arr.clone())
-                            .map(|s| Column::from(s)) // [关键] Series -> Column
-                    })
-                    .collect::<PolarsResult<Vec<_>>>()?;
-                
-                DataFrame::new(columns)?
-            },
-            None => {
-                // 单列情况也要改
-                let name = PlSmallStr::from_str(&field.name);
-                let series = Series::from_arrow(name, array)?;
-                
-                // [修复] vec![Column::from(series)]
-                DataFrame::new(vec![Column::from(series)])?
-            }
-        };
+        let df = arrow_to_df(&field, array)?;
 
         Ok(Box::into_raw(Box::new(DataFrameContext { df })))
     })
 }
+
+/// 把导入的 Arrow Array 还原成 DataFrame
+/// StructArray 视为 RecordBatch (每个字段一列)，否则当作单列
+fn arrow_to_df(field: &Field, array: Box<dyn polars_arrow::array::Array>) -> PolarsResult<DataFrame> {
+    match array.as_any().downcast_ref::<StructArray>() {
+        Some(struct_arr) => {
+            let columns: Vec<Column> = struct_arr
+                .values()
+                .iter()
+                .zip(struct_arr.fields())
+                .map(|(arr, field)| {
+                    let name = PlSmallStr::from_str(&field.name);
+                    
+                    // Series::from_arrow 返回 PolarsResult<Series>
+                    // 我们需要 map 它，把 Series 转为 Column
+                    Series::from_arrow(name, arr.clone())
+                        .map(Column::from) // [关键] Series -> Column
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            
+            DataFrame::new(columns)
+        },
+        None => {
+            let name = PlSmallStr::from_str(&field.name);
+            let series = Series::from_arrow(name, array)?;
+            
+            DataFrame::new(vec![Column::from(series)])
+        }
+    }
+}
 // ==========================================
 // 2. 写操作 (Void 返回值)
 // ==========================================
@@ -365,44 +453,51 @@ pub extern "C" fn pl_to_arrow(
         }
         
         let ctx = unsafe { &mut *ctx_ptr };
-        let df = &mut ctx.df;
-
-        let columns = df.get_columns()
-            .iter()
-            .map(|s| s.clone().rechunk_to_arrow(CompatLevel::newest()))
-            .collect::<Vec<_>>();
-
-        let arrow_schema = df.schema().to_arrow(CompatLevel::newest());
-        let fields: Vec<Field> = arrow_schema.iter_values().cloned().collect();
-
-        let struct_array = StructArray::new(
-            ArrowDataType::Struct(fields.clone()), 
-            df.height(),
-            columns,
-            None
-        );
+        let (c_array, c_schema) = export_df_to_c(&ctx.df);
 
         unsafe {
-            *out_chunk = export_array_to_c(Box::new(struct_array));
-            let root_field = Field::new("".into(), ArrowDataType::Struct(fields), false);
-            *out_schema = export_field_to_c(&root_field);
+            *out_chunk = c_array;
+            *out_schema = c_schema;
         }
         
         Ok(())
     })
 }
 
+/// 把 DataFrame 打包成一个 StructArray 并导出为 C Data Interface 结构体
+/// pl_to_arrow 和 batch sink 共用
+fn export_df_to_c(df: &DataFrame) -> (ArrowArray, ArrowSchema) {
+    let columns = df.get_columns()
+        .iter()
+        .map(|s| s.clone().rechunk_to_arrow(CompatLevel::newest()))
+        .collect::<Vec<_>>();
+
+    let arrow_schema = df.schema().to_arrow(CompatLevel::newest());
+    let fields: Vec<Field> = arrow_schema.iter_values().cloned().collect();
+
+    let struct_array = StructArray::new(
+        ArrowDataType::Struct(fields.clone()), 
+        df.height(),
+        columns,
+        None
+    );
+
+    let root_field = Field::new("".into(), ArrowDataType::Struct(fields), false);
+    (export_array_to_c(Box::new(struct_array)), export_field_to_c(&root_field))
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_lazy_sink_parquet(
     lf_ptr: *mut LazyFrameContext,
-    path_ptr: *const c_char
+    path_ptr: *const c_char,
+    cloud_ptr: *mut CloudOptionsContext // 可空
 ) {
     ffi_try_void!({
         let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
         let path_str = ptr_to_str(path_ptr).unwrap();
 
         let pl_path = PlPath::new(path_str);
-        let target = SinkTarget::Path(pl_path.into());
+        let target = SinkTarget::Path(pl_path);
 
         // 4. 配置项
         let write_options = ParquetWriteOptions::default();
@@ -412,7 +507,7 @@ pub extern "C" fn pl_lazy_sink_parquet(
         let _ = lf_ctx.inner.sink_parquet(
             target, 
             write_options, 
-            None, // cloud_options
+            clone_cloud_options(cloud_ptr),
             sink_options
         )?;
 
@@ -423,21 +518,22 @@ pub extern "C" fn pl_lazy_sink_parquet(
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_lazy_sink_json(
     lf_ptr: *mut LazyFrameContext,
-    path_ptr: *const c_char
+    path_ptr: *const c_char,
+    cloud_ptr: *mut CloudOptionsContext // 可空
 ) {
     ffi_try_void!({
         let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
         let path_str = ptr_to_str(path_ptr).unwrap();
         let pl_path = PlPath::new(path_str);
         
-        let target = SinkTarget::Path(pl_path.into());
+        let target = SinkTarget::Path(pl_path);
         let writer_options = JsonWriterOptions::default();
         let sink_options = SinkOptions::default();
 
         let _ = lf_ctx.inner.sink_json(
             target, 
             writer_options, 
-            None, 
+            clone_cloud_options(cloud_ptr), 
             sink_options
         )?;
         Ok(())
@@ -445,3 +541,343 @@ pub extern "C" fn pl_lazy_sink_json(
 }
 
 
+
+// ==========================================
+// Sink 到回调 (Batch-at-a-time)
+// ==========================================
+
+// 回调返回值: 0 = 继续, 非 0 = 提前停止查询
+// Arrow 版本: 每个 batch 以 StructArray 形式导出。两个结构体只在回调期间有效，
+// 回调必须在返回前 import (ArrowArray 由 importer 接管 release 并置空，
+// ArrowSchema 可以只拷贝)。回调返回后 release 仍非空的结构体 (没有接管的 schema，
+// 或者托管端在 import 前就抛了异常) 由 Rust 端释放
+type ArrowBatchCallback = extern "C" fn(
+    *mut ArrowArray,
+    *mut ArrowSchema,
+    *mut c_void // user_data
+) -> i32;
+
+// DataFrame 版本: 回调接管 DataFrameContext 的所有权，C# 端需要调用 pl_dataframe_free
+type DataFrameBatchCallback = extern "C" fn(
+    *mut DataFrameContext,
+    *mut c_void // user_data
+) -> i32;
+
+// Streaming 引擎会在多个线程上并行调用 map，
+// 这里用 Mutex 保证回调是串行进入 C# 的 (batch 之间不保证行序)
+struct BatchSink<F> {
+    callback: Mutex<F>,
+    stopped: AtomicBool,
+}
+
+const SINK_STOPPED_MSG: &str = "Batch sink stopped by callback";
+
+// 只认自己抛出的停止信号，引擎可能会在外面包一层 Context
+fn is_sink_stop(err: &PolarsError) -> bool {
+    match err {
+        PolarsError::Context { error, .. } => is_sink_stop(error),
+        PolarsError::ComputeError(msg) => msg.as_ref() == SINK_STOPPED_MSG,
+        _ => false,
+    }
+}
+
+fn run_batch_sink<F>(lf: LazyFrame, callback: F) -> PolarsResult<()>
+where
+    F: FnMut(DataFrame) -> i32 + Send + 'static,
+{
+    let sink = Arc::new(BatchSink {
+        callback: Mutex::new(callback),
+        stopped: AtomicBool::new(false),
+    });
+    let sink_in_map = sink.clone();
+
+    // 利用 streamable 的 map 节点逐个 morsel 拿到结果，
+    // 交给回调后返回空帧，这样最终 collect 不会再物化整个结果
+    let result = lf
+        .map(
+            move |df: DataFrame| {
+                if sink_in_map.stopped.load(Ordering::Acquire) {
+                    return Err(PolarsError::ComputeError(SINK_STOPPED_MSG.into()));
+                }
+                let empty = df.clear();
+                if df.height() > 0 {
+                    let mut cb = sink_in_map.callback.lock()
+                        .map_err(|_| PolarsError::ComputeError("Batch sink callback poisoned".into()))?;
+                    if (*cb)(df) != 0 {
+                        sink_in_map.stopped.store(true, Ordering::Release);
+                        return Err(PolarsError::ComputeError(SINK_STOPPED_MSG.into()));
+                    }
+                }
+                Ok(empty)
+            },
+            OptFlags::NEW_STREAMING,
+            None,
+            Some("batch_sink"),
+        )
+        .with_new_streaming(true)
+        .collect();
+
+    match result {
+        Ok(_) => Ok(()),
+        // 回调主动停止不算错误
+        Err(e) if sink.stopped.load(Ordering::Acquire) && is_sink_stop(&e) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_sink_batches_arrow(
+    lf_ptr: *mut LazyFrameContext,
+    callback: ArrowBatchCallback,
+    user_data: *mut c_void
+) {
+    ffi_try_void!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        // 裸指针不是 Send，转成 usize 带进闭包
+        let user_data = user_data as usize;
+
+        run_batch_sink(lf_ctx.inner, move |df| {
+            let (mut c_array, mut c_schema) = export_df_to_c(&df);
+            let code = callback(&mut c_array, &mut c_schema, user_data as *mut c_void);
+            // release 仍非空说明回调没有接管，drop 时会调用 release 释放 buffer
+            drop(c_array);
+            drop(c_schema);
+            code
+        })
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_sink_batches(
+    lf_ptr: *mut LazyFrameContext,
+    callback: DataFrameBatchCallback,
+    user_data: *mut c_void
+) {
+    ffi_try_void!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let user_data = user_data as usize;
+
+        run_batch_sink(lf_ctx.inner, move |df| {
+            let batch = Box::into_raw(Box::new(DataFrameContext { df }));
+            callback(batch, user_data as *mut c_void)
+        })
+    })
+}
+
+// ==========================================
+// 自定义数据源 (Anonymous Scan)
+// ==========================================
+
+// C# 端的数据生产者，每次调用产出一个 Arrow batch (StructArray)
+// 参数:
+//   user_data    : GCHandle
+//   batch_idx    : 本次 scan 中的第几次调用，0 表示新一轮 scan 开始 (C# 可以据此重置游标)
+//   with_columns : 投影下推提示 (列名数组)，null 表示需要全部列
+//   n_rows       : 行数上限提示，0 表示不限制
+//   predicate    : 谓词下推提示 (表达式字符串)，null 表示没有
+//   out_array / out_schema : 由 C# 填充
+//   msg_buf      : 1KB 错误缓冲区
+// 返回值: 0 = 产出了一个 batch, 1 = 数据已读完, 其它 = 错误
+type ScanBatchCallback = extern "C" fn(
+    *mut c_void,
+    usize,
+    *const *const c_char,
+    usize,
+    usize,
+    *const c_char,
+    *mut ArrowArray,
+    *mut ArrowSchema,
+    *mut c_char
+) -> i32;
+
+// 检查 C# 产出的 batch 是否与声明的 schema 一致，并按 names 的顺序选出这些列
+// (C# 可以忽略投影提示返回全部列，但不能多出 schema 之外的列，类型也必须一致)
+fn align_batch(batch: DataFrame, schema: &Schema, names: &[PlSmallStr]) -> PolarsResult<DataFrame> {
+    for col in batch.get_columns() {
+        match schema.get(col.name()) {
+            Some(dtype) if dtype == col.dtype() => {},
+            Some(dtype) => polars_bail!(
+                SchemaMismatch: "callback scan returned column '{}' as {}, but the declared schema says {}",
+                col.name(), col.dtype(), dtype
+            ),
+            None => polars_bail!(
+                SchemaMismatch: "callback scan returned column '{}', which is not in the declared schema",
+                col.name()
+            ),
+        }
+    }
+    if let Some(missing) = names.iter().find(|n| batch.column(n).is_err()) {
+        polars_bail!(SchemaMismatch: "callback scan batch is missing column '{}'", missing);
+    }
+    batch.select(names.iter().cloned())
+}
+
+struct CSharpScan {
+    callback: ScanBatchCallback,
+    cleanup: CleanupCallback,
+    user_data: *mut c_void,
+}
+
+unsafe impl Send for CSharpScan {}
+unsafe impl Sync for CSharpScan {}
+
+impl Drop for CSharpScan {
+    fn drop(&mut self) {
+        (self.cleanup)(self.user_data);
+    }
+}
+
+impl AnonymousScan for CSharpScan {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let schema = scan_opts.schema.clone();
+
+        // 1. 准备下推提示
+        let projection: Option<Vec<CString>> = scan_opts.with_columns.as_ref().map(|cols| {
+            cols.iter()
+                .map(|c| CString::new(c.as_str()).unwrap_or_default())
+                .collect()
+        });
+        let projection_ptrs: Vec<*const c_char> = projection.iter()
+            .flatten()
+            .map(|c| c.as_ptr())
+            .collect();
+        let (cols_ptr, cols_len) = match &projection {
+            Some(_) => (projection_ptrs.as_ptr(), projection_ptrs.len()),
+            None => (std::ptr::null(), 0),
+        };
+
+        let predicate_str = scan_opts.predicate.as_ref()
+            .map(|p| CString::new(p.to_string()).unwrap_or_default());
+        let predicate_ptr = predicate_str.as_ref().map_or(std::ptr::null(), |p| p.as_ptr());
+
+        let n_rows = scan_opts.n_rows;
+
+        // 每个 batch 都要对齐到的列: 有投影时只要投影列 + 谓词用到的列，否则是整个 schema
+        let names: Vec<PlSmallStr> = match &scan_opts.with_columns {
+            Some(cols) => {
+                let predicate_cols = scan_opts.predicate.as_ref()
+                    .map(|p| p.clone().meta().root_names())
+                    .unwrap_or_default();
+                schema.iter_names()
+                    .filter(|n| cols.contains(n) || predicate_cols.contains(n))
+                    .cloned()
+                    .collect()
+            },
+            None => schema.iter_names().cloned().collect(),
+        };
+
+        // 2. 循环拉取 batch，直到 C# 报告读完或者行数已够
+        let mut batches: Vec<DataFrame> = Vec::new();
+        let mut total_rows = 0usize;
+        let mut batch_idx = 0usize;
+
+        while n_rows.is_none_or(|n| total_rows < n) {
+            let mut c_array_out = ArrowArray::empty();
+            let mut c_schema_out = ArrowSchema::empty();
+            let mut error_msg_buf = [0u8; 1024];
+            let error_ptr = error_msg_buf.as_mut_ptr() as *mut c_char;
+
+            let status = (self.callback)(
+                self.user_data,
+                batch_idx,
+                cols_ptr,
+                cols_len,
+                n_rows.unwrap_or(0),
+                predicate_ptr,
+                &mut c_array_out,
+                &mut c_schema_out,
+                error_ptr,
+            );
+            batch_idx += 1;
+
+            match status {
+                0 => {},
+                1 => break,
+                _ => {
+                    let msg = unsafe { CStr::from_ptr(error_ptr).to_string_lossy().into_owned() };
+                    return Err(PolarsError::ComputeError(format!("C# scan source failed: {}", msg).into()));
+                }
+            }
+
+            let field = unsafe { ffi::import_field_from_c(&c_schema_out).map_err(|e| PolarsError::ComputeError(e.to_string().into()))? };
+            let array = unsafe { ffi::import_array_from_c(c_array_out, field.dtype.clone()).map_err(|e| PolarsError::ComputeError(e.to_string().into()))? };
+            let batch = align_batch(arrow_to_df(&field, array)?, &schema, &names)?;
+
+            total_rows += batch.height();
+            batches.push(batch);
+        }
+
+        // 3. 合并 (batch 已经对齐过)，提示只是提示，C# 可以忽略它们，所以这里再过滤/截断一次
+        let mut df = if batches.is_empty() {
+            DataFrame::empty_with_schema(&schema).select(names.iter().cloned())?
+        } else {
+            accumulate_dataframes_vertical(batches)?
+        };
+
+        if let Some(predicate) = scan_opts.predicate {
+            df = df.lazy().filter(predicate).collect()?;
+        }
+        if let Some(cols) = &scan_opts.with_columns {
+            df = df.select(cols.iter().cloned())?;
+        }
+        if let Some(n) = n_rows {
+            df = df.head(Some(n));
+        }
+
+        Ok(df)
+    }
+
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_scan_callback(
+    schema_names: *const *const c_char,
+    schema_types: *const *mut DataTypeContext,
+    schema_len: usize,
+    callback: ScanBatchCallback,
+    cleanup: CleanupCallback,
+    user_data: *mut c_void
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        // 先接管 user_data，保证出错时也会调用 cleanup
+        let source = Arc::new(CSharpScan { callback, cleanup, user_data });
+
+        if schema_names.is_null() || schema_len == 0 {
+            return Err(PolarsError::ComputeError("A schema is required for a callback scan".into()));
+        }
+
+        let names_slice = unsafe { std::slice::from_raw_parts(schema_names, schema_len) };
+        let types_slice = unsafe { std::slice::from_raw_parts(schema_types, schema_len) };
+        let mut schema = Schema::with_capacity(schema_len);
+        for (&name_ptr, &type_ptr) in names_slice.iter().zip(types_slice) {
+            let name = ptr_to_str(name_ptr).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+            let ctx = unsafe { &*type_ptr };
+            schema.with_column(name.into(), ctx.dtype.clone());
+        }
+
+        let args = ScanArgsAnonymous {
+            schema: Some(Arc::new(schema)),
+            name: "CALLBACK SCAN",
+            ..Default::default()
+        };
+        let lf = LazyFrame::anonymous_scan(source, args)?;
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: lf })))
+    })
+}
+
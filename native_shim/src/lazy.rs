@@ -1,6 +1,8 @@
 use std::ffi::c_char;
 use polars::prelude::*;
 use crate::types::*;
+use crate::expr::parse_closed_window;
+use crate::selectors::SelectorContext;
 use polars::lazy::dsl::UnpivotArgsDSL;
 
 // ==========================================
@@ -91,28 +93,110 @@ gen_lazy_scalar_op!(pl_lazy_limit, limit, u32);
 // 也可以加个 tail
 gen_lazy_scalar_op!(pl_lazy_tail, tail, u32);
 
+// --- Positional ---
+// 正数向下移动，负数向上移动，空出的位置填 null
+gen_lazy_scalar_op!(pl_lazy_shift, shift, i64);
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_reverse(lf_ptr: *mut LazyFrameContext) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: lf_ctx.inner.reverse() })))
+    })
+}
+
+// offset 为负数时从末尾开始计数
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_slice(
+    lf_ptr: *mut LazyFrameContext,
+    offset: i64,
+    len: u32
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: lf_ctx.inner.slice(offset, len) })))
+    })
+}
+
+// 在最前面插入行号列 (UInt32)，从 offset 开始
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_with_row_index(
+    lf_ptr: *mut LazyFrameContext,
+    name_ptr: *const c_char,
+    offset: u32
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let name = ptr_to_str(name_ptr).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+
+        let new_lf = lf_ctx.inner.with_row_index(name, Some(offset));
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+// 按行号取行，越界时报错
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_gather(
+    lf_ptr: *mut LazyFrameContext,
+    indices_ptr: *const u32,
+    len: usize
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let indices = if len == 0 { &[][..] } else { unsafe { std::slice::from_raw_parts(indices_ptr, len) } };
+        let idx = Series::new("".into(), indices);
+
+        let new_lf = lf_ctx.inner.select([all().as_expr().gather(lit(idx))]);
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+// 每隔 n 行取一行，从 offset 开始
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_gather_every(
+    lf_ptr: *mut LazyFrameContext,
+    n: usize,
+    offset: usize
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        if n == 0 {
+            return Err(PolarsError::ComputeError("gather_every: n must be greater than 0".into()));
+        }
+
+        let new_lf = lf_ctx.inner.select([all().as_expr().gather_every(n, offset)]);
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
 // ==========================================
 // Sort
 // ==========================================
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_lazy_sort(
     lf_ptr: *mut LazyFrameContext,
-    expr_ptr: *mut ExprContext,
-    descending: bool
+    exprs_ptr: *const *mut ExprContext,
+    exprs_len: usize,
+    descending_ptr: *const bool,
+    descending_len: usize,
+    nulls_last: bool,
+    maintain_order: bool,
+    multithreaded: bool
 ) -> *mut LazyFrameContext {
     ffi_try!({
         let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
-        let expr_ctx = unsafe { Box::from_raw(expr_ptr) };
+        let exprs = unsafe { consume_exprs_array(exprs_ptr, exprs_len) };
         
         // 构建排序选项
-        let options = SortMultipleOptions::default()
-            .with_order_descending(descending);
+        let options = unsafe {
+            build_sort_options(descending_ptr, descending_len, nulls_last, maintain_order, multithreaded)
+        };
 
         // Polars 0.50: sort_by_exprs 接受 Vec<Expr>
-        let new_lf = lf_ctx.inner.sort_by_exprs(
-            vec![expr_ctx.inner], 
-            options
-        );
+        let new_lf = lf_ctx.inner.sort_by_exprs(exprs, options);
         
         Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
     })
@@ -120,11 +204,21 @@ pub extern "C" fn pl_lazy_sort(
 // ==========================================
 // GroupBy
 // ==========================================
+// maintain_order: 结果中的分组顺序和输入中第一次出现的顺序一致
+pub(crate) fn lazy_group_by(lf: LazyFrame, keys: Vec<Expr>, maintain_order: bool) -> LazyGroupBy {
+    if maintain_order {
+        lf.group_by_stable(keys)
+    } else {
+        lf.group_by(keys)
+    }
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_lazy_groupby_agg(
     lf_ptr: *mut LazyFrameContext,
     keys_ptr: *const *mut ExprContext, keys_len: usize,
-    aggs_ptr: *const *mut ExprContext, aggs_len: usize
+    aggs_ptr: *const *mut ExprContext, aggs_len: usize,
+    maintain_order: bool
 ) -> *mut LazyFrameContext {
     ffi_try!({
         let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
@@ -132,12 +226,445 @@ pub extern "C" fn pl_lazy_groupby_agg(
         let aggs = unsafe { consume_exprs_array(aggs_ptr, aggs_len) };
 
         // 链式调用
-        let new_lf = lf_ctx.inner.group_by(keys).agg(aggs);
+        let new_lf = lazy_group_by(lf_ctx.inner, keys, maintain_order).agg(aggs);
         
         Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
     })
 }
 
+// 每组取前 n 行
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_groupby_head(
+    lf_ptr: *mut LazyFrameContext,
+    keys_ptr: *const *mut ExprContext, keys_len: usize,
+    n: usize,
+    maintain_order: bool
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let keys = unsafe { consume_exprs_array(keys_ptr, keys_len) };
+
+        let new_lf = lazy_group_by(lf_ctx.inner, keys, maintain_order).head(Some(n));
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+// 每组取后 n 行
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_groupby_tail(
+    lf_ptr: *mut LazyFrameContext,
+    keys_ptr: *const *mut ExprContext, keys_len: usize,
+    n: usize,
+    maintain_order: bool
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let keys = unsafe { consume_exprs_array(keys_ptr, keys_len) };
+
+        let new_lf = lazy_group_by(lf_ctx.inner, keys, maintain_order).tail(Some(n));
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+// ==========================================
+// Dynamic GroupBy (时间窗口)
+// ==========================================
+/// 可空字符串 -> Option<&str> (空字符串也视为未设置)
+pub(crate) fn opt_ptr_str<'a>(ptr: *const c_char) -> PolarsResult<Option<&'a str>> {
+    if ptr.is_null() {
+        return Ok(None);
+    }
+    let s = ptr_to_str(ptr).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+    Ok(if s.is_empty() { None } else { Some(s) })
+}
+
+/// every: 必填，例如 "5m"
+/// period: 可空，默认等于 every
+/// offset: 可空，默认 0
+/// label: "left" / "right" / "datapoint"
+/// closed: "left" / "right" / "both" / "none"
+/// start_by: "window" / "datapoint" / "monday" ... "sunday"
+pub(crate) fn build_dynamic_options(
+    every_ptr: *const c_char,
+    period_ptr: *const c_char,
+    offset_ptr: *const c_char,
+    label_ptr: *const c_char,
+    include_boundaries: bool,
+    closed_ptr: *const c_char,
+    start_by_ptr: *const c_char
+) -> PolarsResult<DynamicGroupOptions> {
+    let every_str = opt_ptr_str(every_ptr)?
+        .ok_or_else(|| PolarsError::ComputeError("group_by_dynamic: 'every' is required".into()))?;
+    let every = Duration::try_parse(every_str)?;
+    let period = match opt_ptr_str(period_ptr)? {
+        Some(s) => Duration::try_parse(s)?,
+        None => every,
+    };
+    // offset 默认 0，单位要和 every 一致 (整数索引用 "0i"，时间索引用 "0ns")
+    let offset = match opt_ptr_str(offset_ptr)? {
+        Some(s) => Duration::try_parse(s)?,
+        None if every.parsed_int => Duration::new(0),
+        None => Duration::try_parse("0ns")?,
+    };
+
+    let label = match opt_ptr_str(label_ptr)?.unwrap_or("left") {
+        "left" => Label::Left,
+        "right" => Label::Right,
+        "datapoint" => Label::DataPoint,
+        other => return Err(PolarsError::ComputeError(format!("Invalid label: {}", other).into())),
+    };
+
+    let start_by = match opt_ptr_str(start_by_ptr)?.unwrap_or("window") {
+        "window" => StartBy::WindowBound,
+        "datapoint" => StartBy::DataPoint,
+        "monday" => StartBy::Monday,
+        "tuesday" => StartBy::Tuesday,
+        "wednesday" => StartBy::Wednesday,
+        "thursday" => StartBy::Thursday,
+        "friday" => StartBy::Friday,
+        "saturday" => StartBy::Saturday,
+        "sunday" => StartBy::Sunday,
+        other => return Err(PolarsError::ComputeError(format!("Invalid start_by: {}", other).into())),
+    };
+
+    Ok(DynamicGroupOptions {
+        every,
+        period,
+        offset,
+        label,
+        include_boundaries,
+        closed_window: parse_closed_window(opt_ptr_str(closed_ptr)?.unwrap_or("left"))?,
+        start_by,
+        ..Default::default()
+    })
+}
+
+// index_ptr: 时间 (或整数) 索引列，需要已排序
+// by_ptr: 额外的分组键 (可以为空)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_group_by_dynamic(
+    lf_ptr: *mut LazyFrameContext,
+    index_ptr: *mut ExprContext,
+    by_ptr: *const *mut ExprContext, by_len: usize,
+    every_ptr: *const c_char,
+    period_ptr: *const c_char,
+    offset_ptr: *const c_char,
+    label_ptr: *const c_char,
+    include_boundaries: bool,
+    closed_ptr: *const c_char,
+    start_by_ptr: *const c_char,
+    aggs_ptr: *const *mut ExprContext, aggs_len: usize
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let index = unsafe { Box::from_raw(index_ptr) };
+        let by = unsafe { consume_exprs_array(by_ptr, by_len) };
+        let aggs = unsafe { consume_exprs_array(aggs_ptr, aggs_len) };
+
+        let options = build_dynamic_options(
+            every_ptr, period_ptr, offset_ptr,
+            label_ptr, include_boundaries, closed_ptr, start_by_ptr
+        )?;
+
+        let new_lf = lf_ctx.inner
+            .group_by_dynamic(index.inner, by, options)
+            .agg(aggs);
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+// ==========================================
+// Unique (去重)
+// ==========================================
+// subset: 参与比较的列，长度为 0 表示所有列
+// keep: 0 = first, 1 = last, 2 = any, 3 = none
+// maintain_order: 保持行的原始顺序
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_unique(
+    lf_ptr: *mut LazyFrameContext,
+    subset_ptr: *const *const c_char, subset_len: usize,
+    keep: i32,
+    maintain_order: bool
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let names = unsafe { ptr_to_names(subset_ptr, subset_len)? };
+        let subset = if names.is_empty() { None } else { Some(cols(names)) };
+        let keep = map_unique_keep(keep)?;
+
+        let new_lf = if maintain_order {
+            lf_ctx.inner.unique_stable(subset, keep)
+        } else {
+            lf_ctx.inner.unique(subset, keep)
+        };
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+// ==========================================
+// Fill Null / Fill NaN / Interpolate
+// ==========================================
+// selector 可空，为空时作用于所有列 (会被消耗)
+pub(crate) fn take_selector(sel_ptr: *mut SelectorContext) -> Selector {
+    if sel_ptr.is_null() {
+        Selector::Wildcard
+    } else {
+        unsafe { Box::from_raw(sel_ptr) }.inner
+    }
+}
+
+// value 非空时用 value 填充，否则按 strategy 填充
+// mean/zero/one 只对数值列有意义，会自动跳过其他列
+pub(crate) fn fill_null_lf(
+    lf: LazyFrame,
+    selector: Selector,
+    value: Option<Expr>,
+    strategy: i32,
+    limit: u32
+) -> PolarsResult<LazyFrame> {
+    let filled = match value {
+        Some(v) => selector.as_expr().fill_null(v),
+        None => {
+            let strategy = map_fill_null_strategy(strategy, limit)?;
+            let target = match strategy {
+                FillNullStrategy::Mean | FillNullStrategy::Zero | FillNullStrategy::One =>
+                    selector & DataTypeSelector::Numeric.as_selector(),
+                _ => selector,
+            };
+            target.as_expr().fill_null_with_strategy(strategy)
+        },
+    };
+    Ok(lf.with_columns([filled]))
+}
+
+// 只作用于浮点列
+pub(crate) fn fill_nan_lf(lf: LazyFrame, selector: Selector, value: Expr) -> LazyFrame {
+    let target = selector & DataTypeSelector::Float.as_selector();
+    lf.with_columns([target.as_expr().fill_nan(value)])
+}
+
+// method: 0 = linear, 1 = nearest；只作用于数值列
+pub(crate) fn interpolate_lf(lf: LazyFrame, selector: Selector, method: i32) -> PolarsResult<LazyFrame> {
+    let method = map_interpolation_method(method)?;
+    let target = selector & DataTypeSelector::Numeric.as_selector();
+    Ok(lf.with_columns([target.as_expr().interpolate(method)]))
+}
+
+// value_ptr: 可空，为空时使用 strategy
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_fill_null(
+    lf_ptr: *mut LazyFrameContext,
+    sel_ptr: *mut SelectorContext,
+    value_ptr: *mut ExprContext,
+    strategy: i32,
+    limit: u32
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let selector = take_selector(sel_ptr);
+        let value = if value_ptr.is_null() { None } else { Some(unsafe { Box::from_raw(value_ptr) }.inner) };
+
+        let new_lf = fill_null_lf(lf_ctx.inner, selector, value, strategy, limit)?;
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_fill_nan(
+    lf_ptr: *mut LazyFrameContext,
+    sel_ptr: *mut SelectorContext,
+    value_ptr: *mut ExprContext
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let selector = take_selector(sel_ptr);
+        let value = unsafe { Box::from_raw(value_ptr) };
+
+        let new_lf = fill_nan_lf(lf_ctx.inner, selector, value.inner);
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_interpolate(
+    lf_ptr: *mut LazyFrameContext,
+    sel_ptr: *mut SelectorContext,
+    method: i32
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let selector = take_selector(sel_ptr);
+
+        let new_lf = interpolate_lf(lf_ctx.inner, selector, method)?;
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
+// ==========================================
+// Describe (汇总统计)
+// ==========================================
+// 输出第一列为 statistic，其余每列对应一个输入列：
+// 数值/布尔列 -> Float64，其他列 -> String
+// mean: 数值/布尔/时间列；std: 数值列；min/max: 数值/布尔/时间/字符串列；百分位数: 数值/时间列
+pub(crate) fn describe_lf(mut lf: LazyFrame, percentiles: &[f64]) -> PolarsResult<DataFrame> {
+    if let Some(p) = percentiles.iter().find(|p| !(0.0..=1.0).contains(*p)) {
+        return Err(PolarsError::ComputeError(format!("describe: percentile {} is not in [0, 1]", p).into()));
+    }
+    // 重复的百分位会产生同名的统计行：保持输入顺序，只保留第一次出现的
+    let mut unique: Vec<f64> = Vec::with_capacity(percentiles.len());
+    for p in percentiles {
+        if !unique.contains(p) {
+            unique.push(*p);
+        }
+    }
+    let percentiles = unique;
+
+    let mut metrics: Vec<String> = vec!["count".into(), "null_count".into(), "mean".into(), "std".into(), "min".into()];
+    for p in &percentiles {
+        metrics.push(format!("{}%", (p * 100.0 * 1e6).round() / 1e6));
+    }
+    metrics.push("max".into());
+
+    let schema = lf.collect_schema()?;
+    let mut exprs: Vec<Expr> = Vec::with_capacity(schema.len() * metrics.len());
+
+    for (i, (name, dtype)) in schema.iter().enumerate() {
+        let is_numeric = dtype.is_primitive_numeric() || dtype.is_decimal();
+        let is_bool = dtype.is_bool();
+        let is_temporal = dtype.is_temporal();
+        let has_min_max = is_numeric || is_bool || is_temporal || dtype.is_string();
+        let out_dtype = if is_numeric || is_bool { DataType::Float64 } else { DataType::String };
+
+        let c = col(name.clone());
+        let null = || lit(Null {});
+        // Duration 不能直接转成 String，先格式化
+        let value = |e: Expr| if matches!(dtype, DataType::Duration(_)) { e.dt().to_string("polars") } else { e };
+
+        let mut stats = vec![
+            c.clone().count(),
+            c.clone().null_count(),
+            if is_numeric || is_bool || is_temporal { value(c.clone().mean()) } else { null() },
+            if is_numeric { c.clone().std(1) } else { null() },
+            if has_min_max { value(c.clone().min()) } else { null() },
+        ];
+        for p in &percentiles {
+            stats.push(if is_numeric {
+                c.clone().quantile(lit(*p), QuantileMethod::Nearest)
+            } else if is_temporal {
+                // 时间类型不直接支持 quantile，在物理类型上计算后再转回来
+                value(c.clone().to_physical()
+                    .quantile(lit(*p), QuantileMethod::Nearest)
+                    .cast(dtype.to_physical())
+                    .cast(dtype.clone()))
+            } else {
+                null()
+            });
+        }
+        stats.push(if has_min_max { value(c.clone().max()) } else { null() });
+
+        for (stat, metric) in stats.into_iter().zip(&metrics) {
+            exprs.push(stat.cast(out_dtype.clone()).alias(format!("{}:{}", i, metric)));
+        }
+    }
+
+    let row = lf.select(exprs).collect()?;
+
+    let mut columns = Vec::with_capacity(schema.len() + 1);
+    columns.push(Column::new("statistic".into(), &metrics));
+    for (i, name) in schema.iter_names().enumerate() {
+        let mut s = row.column(&format!("{}:{}", i, metrics[0]))?.as_materialized_series().clone();
+        for metric in &metrics[1..] {
+            s.append(row.column(&format!("{}:{}", i, metric))?.as_materialized_series())?;
+        }
+        columns.push(s.rechunk().with_name(name.clone()).into_column());
+    }
+
+    DataFrame::new(columns)
+}
+
+// percentiles_ptr: 例如 [0.25, 0.5, 0.75]，长度为 0 表示不输出百分位数
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_describe(
+    lf_ptr: *mut LazyFrameContext,
+    percentiles_ptr: *const f64,
+    percentiles_len: usize
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let percentiles = if percentiles_len == 0 {
+            &[][..]
+        } else {
+            unsafe { std::slice::from_raw_parts(percentiles_ptr, percentiles_len) }
+        };
+
+        let df = describe_lf(lf_ctx.inner, percentiles)?;
+
+        Ok(Box::into_raw(Box::new(DataFrameContext { df })))
+    })
+}
+
+// ==========================================
+// Rolling GroupBy (每行一个回看窗口)
+// ==========================================
+/// period: 必填，例如 "2d"
+/// offset: 可空，默认 -period (窗口为 (t - period, t])
+/// closed: "left" / "right" / "both" / "none"，默认 "right"
+pub(crate) fn build_rolling_options(
+    period_ptr: *const c_char,
+    offset_ptr: *const c_char,
+    closed_ptr: *const c_char
+) -> PolarsResult<RollingGroupOptions> {
+    let period_str = opt_ptr_str(period_ptr)?
+        .ok_or_else(|| PolarsError::ComputeError("rolling: 'period' is required".into()))?;
+    let period = Duration::try_parse(period_str)?;
+    let offset = match opt_ptr_str(offset_ptr)? {
+        Some(s) => Duration::try_parse(s)?,
+        None => -period,
+    };
+
+    Ok(RollingGroupOptions {
+        period,
+        offset,
+        closed_window: parse_closed_window(opt_ptr_str(closed_ptr)?.unwrap_or("right"))?,
+        ..Default::default()
+    })
+}
+
+// index_ptr: 已排序的时间 (或整数) 索引列
+// by_ptr: 额外的分组键 (可以为空)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_rolling(
+    lf_ptr: *mut LazyFrameContext,
+    index_ptr: *mut ExprContext,
+    by_ptr: *const *mut ExprContext, by_len: usize,
+    period_ptr: *const c_char,
+    offset_ptr: *const c_char,
+    closed_ptr: *const c_char,
+    aggs_ptr: *const *mut ExprContext, aggs_len: usize
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let index = unsafe { Box::from_raw(index_ptr) };
+        let by = unsafe { consume_exprs_array(by_ptr, by_len) };
+        let aggs = unsafe { consume_exprs_array(aggs_ptr, aggs_len) };
+
+        let options = build_rolling_options(period_ptr, offset_ptr, closed_ptr)?;
+
+        let new_lf = lf_ctx.inner
+            .rolling(index.inner, by, options)
+            .agg(aggs);
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_lazy_explode(
     lf_ptr: *mut LazyFrameContext,
@@ -206,6 +733,85 @@ pub extern "C" fn pl_lazy_collect_streaming(lf_ptr: *mut LazyFrameContext) -> *m
     })
 }
 // ==========================================
+// Pivot
+// ==========================================
+// agg_ptr 非空时使用该表达式 (通过 col("") 引用 pivot 元素，会被消耗)，否则使用 agg_code
+// 没有任何行的格子 (index × on 的组合不存在) 统一为 null，
+// 否则 sum / count / len 在空组上会得到 0，eager 和 lazy 都是如此
+pub(crate) fn take_pivot_agg(agg_code: i32, agg_ptr: *mut ExprContext) -> Expr {
+    let agg = if agg_ptr.is_null() {
+        map_pivot_agg(agg_code)
+    } else {
+        unsafe { Box::from_raw(agg_ptr) }.inner
+    };
+    when(col("").len().gt(lit(0))).then(agg).otherwise(lit(NULL))
+}
+
+// Lazy 无法提前知道输出的列，所以必须提供 on 列的取值 (on_values)
+// 等价于 group_by(index).agg(每个 value × on_value 的聚合)，列名规则与 eager pivot 一致
+// index / values 其中一个可以为空，为空时取剩余的所有列
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_pivot(
+    lf_ptr: *mut LazyFrameContext,
+    on_ptr: *const c_char,
+    on_values_ptr: *const *const c_char, on_values_len: usize,
+    index_ptr: *const *const c_char, index_len: usize,
+    values_ptr: *const *const c_char, values_len: usize,
+    agg_code: i32,
+    agg_ptr: *mut ExprContext,
+    separator_ptr: *const c_char,
+    maintain_order: bool
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let mut lf_ctx = unsafe { Box::from_raw(lf_ptr) };
+        let agg = take_pivot_agg(agg_code, agg_ptr);
+        let on = PlSmallStr::from_str(
+            ptr_to_str(on_ptr).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?
+        );
+        let on_values = unsafe { ptr_to_names(on_values_ptr, on_values_len)? };
+        let mut index = unsafe { ptr_to_names(index_ptr, index_len)? };
+        let mut values = unsafe { ptr_to_names(values_ptr, values_len)? };
+        let sep = opt_ptr_str(separator_ptr)?.unwrap_or("_");
+
+        if index.is_empty() || values.is_empty() {
+            let schema = lf_ctx.inner.collect_schema()?;
+            let rest: Vec<PlSmallStr> = schema.iter_names()
+                .filter(|n| **n != on && !index.contains(n) && !values.contains(n))
+                .cloned()
+                .collect();
+            match (index.is_empty(), values.is_empty()) {
+                (true, true) => polars_bail!(ComputeError: "pivot: 'index' or 'values' is required"),
+                (true, false) => index = rest,
+                _ => values = rest,
+            }
+        }
+
+        // 按字符串比较，列名与 eager pivot 一样使用值的字符串形式
+        let on_str = col(on).cast(DataType::String);
+        let mut aggs = Vec::with_capacity(values.len() * on_values.len());
+        for value in &values {
+            for v in &on_values {
+                let el = col(value.clone()).filter(on_str.clone().eq(lit(v.as_str())));
+                let e = agg.clone().map_expr(|e| match e {
+                    Expr::Column(ref n) if n.is_empty() => el.clone(),
+                    e => e,
+                });
+                let name = if values.len() > 1 {
+                    format!("{value}{sep}{v}")
+                } else {
+                    v.to_string()
+                };
+                aggs.push(e.alias(name));
+            }
+        }
+
+        let keys = index.into_iter().map(col).collect();
+        let new_lf = lazy_group_by(lf_ctx.inner, keys, maintain_order).agg(aggs);
+
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
+// ==========================================
 // Unpivot
 // ==========================================
 #[unsafe(no_mangle)]
@@ -331,7 +937,12 @@ pub extern "C" fn pl_lazy_join(
     right_ptr: *mut LazyFrameContext,
     left_on_ptr: *const *mut ExprContext, left_on_len: usize,
     right_on_ptr: *const *mut ExprContext, right_on_len: usize,
-    how_code: i32 // 复用 PlJoinType 枚举
+    how_code: i32, // 复用 PlJoinType 枚举
+    suffix_ptr: *const c_char, // 可空
+    validate: i32,
+    coalesce: i32,
+    nulls_equal: bool,
+    maintain_order: i32
 ) -> *mut LazyFrameContext {
     ffi_try!({
         // 1. 消费左右 LazyFrame
@@ -342,9 +953,8 @@ pub extern "C" fn pl_lazy_join(
         let left_on = unsafe { consume_exprs_array(left_on_ptr, left_on_len) };
         let right_on = unsafe { consume_exprs_array(right_on_ptr, right_on_len) };
 
-        // 3. 映射 JoinType
-        let how = map_jointype(how_code);
-        let args = JoinArgs::new(how);
+        // 3. 映射 JoinType 及附加选项
+        let args = build_join_args(how_code, suffix_ptr, validate, coalesce, nulls_equal, maintain_order)?;
 
         // 4. 执行 Lazy Join
         let new_lf = left_ctx.inner.join(right_ctx.inner, left_on, right_on, args);
@@ -352,6 +962,30 @@ pub extern "C" fn pl_lazy_join(
         Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
     })
 }
+// 不等值 / 区间 Join (消耗左右两个 LazyFrame)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_lazy_join_where(
+    left_ptr: *mut LazyFrameContext,
+    right_ptr: *mut LazyFrameContext,
+    predicates_ptr: *const *mut ExprContext, predicates_len: usize,
+    suffix_ptr: *const c_char // 可空
+) -> *mut LazyFrameContext {
+    ffi_try!({
+        let left_ctx = unsafe { Box::from_raw(left_ptr) };
+        let right_ctx = unsafe { Box::from_raw(right_ptr) };
+        let predicates = unsafe { consume_exprs_array(predicates_ptr, predicates_len) };
+
+        let mut builder = left_ctx.inner.join_builder().with(right_ctx.inner);
+        if !suffix_ptr.is_null() {
+            let suffix = ptr_to_str(suffix_ptr)
+                .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+            builder = builder.suffix(suffix);
+        }
+
+        let new_lf = builder.join_where(predicates);
+        Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
+    })
+}
 fn exprs_to_names(exprs: &[Expr]) -> PolarsResult<Vec<PlSmallStr>> {
     let mut names = Vec::new();
     for e in exprs {
@@ -364,6 +998,96 @@ fn exprs_to_names(exprs: &[Expr]) -> PolarsResult<Vec<PlSmallStr>> {
     }
     Ok(names)
 }
+/// As-Of Join 的选项解析 (eager 版本也复用这里)
+/// tolerance_kind: 0 = 无容差, 1 = 整数, 2 = 浮点数, 3 = 时间字符串 (例如 "2h")
+pub(crate) fn build_asof_options(
+    by_left_exprs: &[Expr],
+    by_right_exprs: &[Expr],
+    strategy_ptr: *const c_char,
+    tolerance_kind: i32,
+    tolerance_ptr: *const c_char,
+    allow_eq: bool,
+    check_sortedness: bool
+) -> PolarsResult<AsOfOptions> {
+    // 左右两侧的 by 键可以不同名，但数量必须一致
+    if by_left_exprs.len() != by_right_exprs.len() {
+        return Err(PolarsError::ComputeError(
+            format!("asof join: left_by has {} keys but right_by has {}", by_left_exprs.len(), by_right_exprs.len()).into()
+        ));
+    }
+
+    // 将 Expr 列表转换为列名列表 (PlSmallStr)
+    let left_by_names = if by_left_exprs.is_empty() { None } else { Some(exprs_to_names(by_left_exprs)?) };
+    let right_by_names = if by_right_exprs.is_empty() { None } else { Some(exprs_to_names(by_right_exprs)?) };
+
+    // 策略: 为空时默认 backward，无法识别的策略直接报错
+    let strategy_str = if strategy_ptr.is_null() {
+        "backward"
+    } else {
+        ptr_to_str(strategy_ptr).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?
+    };
+    let strategy = match strategy_str.to_ascii_lowercase().as_str() {
+        "" | "backward" => AsofStrategy::Backward,
+        "forward" => AsofStrategy::Forward,
+        "nearest" => AsofStrategy::Nearest,
+        other => return Err(PolarsError::ComputeError(
+            format!("Invalid asof strategy: '{}' (expected backward, forward or nearest)", other).into()
+        )),
+    };
+
+    // 容差: 由调用方显式指定类型，不再猜测
+    let tol_str = if tolerance_ptr.is_null() {
+        ""
+    } else {
+        ptr_to_str(tolerance_ptr).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?
+    };
+    let invalid_tolerance = || PolarsError::ComputeError(format!("Invalid asof tolerance: '{}'", tol_str).into());
+
+    let (tolerance, tolerance_str_val) = match tolerance_kind {
+        0 => (None, None),
+        1 => {
+            let v = tol_str.trim().parse::<i64>().map_err(|_| invalid_tolerance())?;
+            (Some(Scalar::new(DataType::Int64, AnyValue::Int64(v))), None)
+        },
+        2 => {
+            let v = tol_str.trim().parse::<f64>().map_err(|_| invalid_tolerance())?;
+            (Some(Scalar::new(DataType::Float64, AnyValue::Float64(v))), None)
+        },
+        3 => {
+            if tol_str.is_empty() {
+                return Err(invalid_tolerance());
+            }
+            (None, Some(PlSmallStr::from_str(tol_str)))
+        },
+        _ => return Err(PolarsError::ComputeError(format!("Invalid asof tolerance kind: {}", tolerance_kind).into())),
+    };
+
+    Ok(AsOfOptions {
+        strategy,
+        tolerance,      // Option<Scalar>
+        tolerance_str: tolerance_str_val, // Option<PlSmallStr>
+        left_by: left_by_names,
+        right_by: right_by_names,
+        allow_eq,
+        check_sortedness,
+    })
+}
+
+pub(crate) fn asof_join_lf(
+    left: LazyFrame,
+    right: LazyFrame,
+    left_on: Expr,
+    right_on: Expr,
+    options: AsOfOptions
+) -> LazyFrame {
+    left.join_builder()
+        .with(right)
+        .left_on([left_on])
+        .right_on([right_on])
+        .how(JoinType::AsOf(Box::new(options)))
+        .finish()
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_lazy_join_asof(
     left_ptr: *mut LazyFrameContext,
@@ -373,7 +1097,10 @@ pub extern "C" fn pl_lazy_join_asof(
     by_left_ptr: *const *mut ExprContext, by_left_len: usize,
     by_right_ptr: *const *mut ExprContext, by_right_len: usize,
     strategy_ptr: *const c_char,
-    tolerance_ptr: *const c_char 
+    tolerance_kind: i32,
+    tolerance_ptr: *const c_char,
+    allow_eq: bool,
+    check_sortedness: bool
 ) -> *mut LazyFrameContext {
     ffi_try!({
         let left = unsafe { Box::from_raw(left_ptr) };
@@ -384,51 +1111,12 @@ pub extern "C" fn pl_lazy_join_asof(
         let by_left_exprs = unsafe { consume_exprs_array(by_left_ptr, by_left_len) };
         let by_right_exprs = unsafe { consume_exprs_array(by_right_ptr, by_right_len) };
 
-        // 将 Expr 列表转换为列名列表 (PlSmallStr)
-        let left_by_names = if by_left_exprs.is_empty() { None } else { Some(exprs_to_names(&by_left_exprs)?) };
-        let right_by_names = if by_right_exprs.is_empty() { None } else { Some(exprs_to_names(&by_right_exprs)?) };
-
-        // 策略
-        let strategy_str = ptr_to_str(strategy_ptr).unwrap_or("backward");
-        let strategy = match strategy_str {
-            "forward" => AsofStrategy::Forward,
-            "nearest" => AsofStrategy::Nearest,
-            _ => AsofStrategy::Backward,
-        };
-
-        // [修复] 容差解析: 字符串 -> (Scalar?, String?)
-        let tol_str = if tolerance_ptr.is_null() { "" } else { ptr_to_str(tolerance_ptr).unwrap() };
-        
-        let (tolerance, tolerance_str_val) = if tol_str.is_empty() {
-            (None, None)
-        } else if let Ok(v) = tol_str.parse::<i64>() {
-            // 纯整数 -> Scalar(Int64)
-            (Some(Scalar::new(DataType::Int64, AnyValue::Int64(v))), None)
-        } else if let Ok(v) = tol_str.parse::<f64>() {
-            // 浮点数 -> Scalar(Float64)
-            (Some(Scalar::new(DataType::Float64, AnyValue::Float64(v))), None)
-        } else {
-            // 否则认为是时间字符串 ("2h")，直接传给 Polars
-            (None, Some(PlSmallStr::from_str(tol_str)))
-        };
-
-        // 构建 Options
-        let options = AsOfOptions {
-            strategy,
-            tolerance,      // Option<Scalar>
-            tolerance_str: tolerance_str_val, // Option<PlSmallStr>
-            left_by: left_by_names,
-            right_by: right_by_names,
-            allow_eq: true, // 默认允许相等匹配
-            check_sortedness: true, // 默认检查排序
-        };
-
-        let new_lf = left.inner.join_builder()
-            .with(right.inner)
-            .left_on([left_on.inner])
-            .right_on([right_on.inner])
-            .how(JoinType::AsOf(Box::new(options)))
-            .finish();
+        let options = build_asof_options(
+            &by_left_exprs, &by_right_exprs,
+            strategy_ptr, tolerance_kind, tolerance_ptr,
+            allow_eq, check_sortedness
+        )?;
+        let new_lf = asof_join_lf(left.inner, right.inner, left_on.inner, right_on.inner, options);
 
         Ok(Box::into_raw(Box::new(LazyFrameContext { inner: new_lf })))
     })
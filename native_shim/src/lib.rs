@@ -11,6 +11,11 @@ mod selectors;
 mod sql;
 mod series;
 mod datatypes;
+mod cloud;
+mod delta;
+mod excel;
+mod sqlite;
+mod testing;
 
 
 
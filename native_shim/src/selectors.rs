@@ -1,6 +1,6 @@
 use polars::prelude::*;
 use std::os::raw::c_char;
-use crate::types::{ExprContext, ptr_to_str};
+use crate::types::{ExprContext, ptr_to_names, ptr_to_str};
 // 确保 lib.rs 里有 #[macro_use] mod error; 以便使用 ffi_try!
 
 // 定义 Selector 容器
@@ -28,6 +28,18 @@ pub extern "C" fn pl_selector_all() -> *mut SelectorContext {
     })
 }
 
+// cs.by_name(["a", "b"])
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_selector_cols(
+    names_ptr: *const *const c_char,
+    len: usize
+) -> *mut SelectorContext {
+    ffi_try!({
+        let names = unsafe { ptr_to_names(names_ptr, len)? };
+        Ok(Box::into_raw(Box::new(SelectorContext { inner: cols(names) })))
+    })
+}
+
 // 2. selector.exclude(["a", "b"])
 #[unsafe(no_mangle)]
 pub extern "C" fn pl_selector_exclude(
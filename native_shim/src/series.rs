@@ -3,6 +3,7 @@ use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 use crate::utils::*;
 use crate::datatypes::DataTypeContext;
+use crate::types::{any_value_to_f64, write_datetime};
 
 // 包装结构体
 pub struct SeriesContext {
@@ -271,6 +272,37 @@ pub extern "C" fn pl_series_null_count(s_ptr: *mut SeriesContext) -> usize {
     let ctx = unsafe { &*s_ptr };
     ctx.series.null_count()
 }
+
+// ==========================================
+// Memory (内存估算 / 整理)
+// ==========================================
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_series_estimated_size(s_ptr: *mut SeriesContext) -> usize {
+    let ctx = unsafe { &*s_ptr };
+    ctx.series.estimated_size()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_series_n_chunks(s_ptr: *mut SeriesContext) -> usize {
+    let ctx = unsafe { &*s_ptr };
+    ctx.series.n_chunks()
+}
+
+// 原地合并为单个 chunk
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_series_rechunk(s_ptr: *mut SeriesContext) {
+    let ctx = unsafe { &mut *s_ptr };
+    if ctx.series.n_chunks() > 1 {
+        ctx.series = ctx.series.rechunk();
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_series_shrink_to_fit(s_ptr: *mut SeriesContext) {
+    let ctx = unsafe { &mut *s_ptr };
+    ctx.series.shrink_to_fit();
+}
 // --- Scalar Access ---
 
 #[unsafe(no_mangle)]
@@ -295,10 +327,10 @@ pub extern "C" fn pl_series_get_f64(s_ptr: *mut SeriesContext, idx: usize, out_v
     let ctx = unsafe { &*s_ptr };
     if idx >= ctx.series.len() { return false; }
 
-    match ctx.series.get(idx) {
-        Ok(AnyValue::Float64(v)) => { unsafe { *out_val = v }; true }
-        Ok(AnyValue::Float32(v)) => { unsafe { *out_val = v as f64 }; true }
-        _ => false
+    // 整数 / Decimal 也可以转浮点，与 pl_dataframe_get_f64 一致
+    match ctx.series.get(idx).ok().as_ref().and_then(any_value_to_f64) {
+        Some(v) => { unsafe { *out_val = v }; true }
+        None => false
     }
 }
 
@@ -366,16 +398,20 @@ pub extern "C" fn pl_series_get_time(s_ptr: *mut SeriesContext, idx: usize, out_
     }
 }
 
+// 与 pl_dataframe_get_datetime 一致: 物理值 + 单位 + 时区 (见 write_datetime)
 #[unsafe(no_mangle)]
-pub extern "C" fn pl_series_get_datetime(s_ptr: *mut SeriesContext, idx: usize, out_val: *mut i64) -> bool {
+pub extern "C" fn pl_series_get_datetime(
+    s_ptr: *mut SeriesContext,
+    idx: usize,
+    out_val: *mut i64,
+    out_unit: *mut i32,
+    out_tz: *mut *mut c_char
+) -> bool {
     let ctx = unsafe { &*s_ptr };
     if idx >= ctx.series.len() { return false; }
     match ctx.series.get(idx) {
-        // Datetime(val, unit, timezone)
-        // 我们这里只取 val。通常 Polars 默认是 Microseconds (us)。
-        // 严谨的做法应该转换单位，但这里为了性能直接返回物理值，C# 端按 Microseconds 处理。
-        Ok(AnyValue::Datetime(v, _, _)) => { unsafe { *out_val = v }; true }
-        _ => false
+        Ok(v) => unsafe { write_datetime(v, out_val, out_unit, out_tz) },
+        Err(_) => false
     }
 }
 
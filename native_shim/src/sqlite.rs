@@ -0,0 +1,347 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use polars::prelude::*;
+use rusqlite::types::Value;
+use rusqlite::{Connection, OpenFlags, params_from_iter};
+use std::os::raw::c_char;
+use crate::types::{DataFrameContext, ptr_to_str};
+
+fn sqlite_err(e: impl std::fmt::Display) -> PolarsError {
+    PolarsError::ComputeError(format!("SQLite error: {}", e).into())
+}
+
+fn quote_ident(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+// ==========================================
+// 读取：声明类型 -> Polars 类型
+// ==========================================
+/// 按 SQLite 的类型亲和性规则映射声明类型
+/// 返回 None 表示没有声明类型 (表达式列) 或 NUMERIC 亲和性，需要根据值推断
+fn decl_type_to_dtype(decl: &str) -> Option<DataType> {
+    let t = decl.to_ascii_uppercase();
+
+    if t.contains("BOOL") {
+        Some(DataType::Boolean)
+    } else if t.contains("DATETIME") || t.contains("TIMESTAMP") {
+        Some(DataType::Datetime(TimeUnit::Microseconds, None))
+    } else if t.contains("DATE") {
+        Some(DataType::Date)
+    } else if t.contains("INT") {
+        Some(DataType::Int64)
+    } else if t.contains("CHAR") || t.contains("CLOB") || t.contains("TEXT") {
+        Some(DataType::String)
+    } else if t.contains("BLOB") {
+        Some(DataType::Binary)
+    } else if t.contains("REAL") || t.contains("FLOA") || t.contains("DOUB") {
+        Some(DataType::Float64)
+    } else {
+        None
+    }
+}
+
+/// 没有声明类型时，根据实际存储的值推断
+fn infer_dtype(values: &[Value]) -> DataType {
+    let mut dtype: Option<DataType> = None;
+    for v in values {
+        let this = match v {
+            Value::Null => continue,
+            Value::Integer(_) => DataType::Int64,
+            Value::Real(_) => DataType::Float64,
+            Value::Text(_) => DataType::String,
+            Value::Blob(_) => DataType::Binary,
+        };
+        dtype = Some(match (dtype, this) {
+            (None, t) => t,
+            (Some(a), b) if a == b => a,
+            (Some(DataType::Int64), DataType::Float64) | (Some(DataType::Float64), DataType::Int64) => DataType::Float64,
+            // 混合类型统一按文本处理
+            _ => DataType::String,
+        });
+    }
+    dtype.unwrap_or(DataType::String)
+}
+
+fn parse_date(s: &str) -> Option<i32> {
+    let d = NaiveDate::parse_from_str(s.get(..10)?, "%Y-%m-%d").ok()?;
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1)?;
+    Some((d - epoch).num_days() as i32)
+}
+
+fn parse_datetime_us(s: &str) -> Option<i64> {
+    for fmt in ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M"] {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(s, fmt) {
+            return Some(dt.and_utc().timestamp_micros());
+        }
+    }
+    NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()
+        .map(|d| d.and_time(chrono::NaiveTime::MIN).and_utc().timestamp_micros())
+}
+
+fn value_to_string(v: &Value) -> Option<String> {
+    match v {
+        Value::Null => None,
+        Value::Integer(i) => Some(i.to_string()),
+        Value::Real(f) => Some(f.to_string()),
+        Value::Text(s) => Some(s.clone()),
+        Value::Blob(b) => Some(String::from_utf8_lossy(b).into_owned()),
+    }
+}
+
+/// 按目标类型构建列；只要有一个值无法转换就返回 None，由调用方回退到推断类型
+fn try_build_column(name: PlSmallStr, dtype: &DataType, values: &[Value]) -> Option<Column> {
+    let col = match dtype {
+        DataType::Boolean => {
+            let ca: BooleanChunked = values.iter()
+                .map(|v| match v {
+                    Value::Null => Ok(None),
+                    Value::Integer(i) => Ok(Some(*i != 0)),
+                    _ => Err(()),
+                })
+                .collect::<Result<_, ()>>().ok()?;
+            ca.with_name(name).into_column()
+        },
+        DataType::Int64 => {
+            let ca: Int64Chunked = values.iter()
+                .map(|v| match v {
+                    Value::Null => Ok(None),
+                    Value::Integer(i) => Ok(Some(*i)),
+                    _ => Err(()),
+                })
+                .collect::<Result<_, ()>>().ok()?;
+            ca.with_name(name).into_column()
+        },
+        DataType::Float64 => {
+            let ca: Float64Chunked = values.iter()
+                .map(|v| match v {
+                    Value::Null => Ok(None),
+                    Value::Integer(i) => Ok(Some(*i as f64)),
+                    Value::Real(f) => Ok(Some(*f)),
+                    _ => Err(()),
+                })
+                .collect::<Result<_, ()>>().ok()?;
+            ca.with_name(name).into_column()
+        },
+        DataType::Binary => {
+            let ca: BinaryChunked = values.iter()
+                .map(|v| match v {
+                    Value::Null => Ok(None),
+                    Value::Blob(b) => Ok(Some(b.as_slice())),
+                    Value::Text(s) => Ok(Some(s.as_bytes())),
+                    _ => Err(()),
+                })
+                .collect::<Result<_, ()>>().ok()?;
+            ca.with_name(name).into_column()
+        },
+        // 日期以 ISO 文本存储 (SQLite 的习惯写法)，整数按 unix 秒处理
+        DataType::Date => {
+            let ca: Int32Chunked = values.iter()
+                .map(|v| match v {
+                    Value::Null => Ok(None),
+                    Value::Text(s) => parse_date(s).map(Some).ok_or(()),
+                    Value::Integer(i) => i32::try_from(i.div_euclid(86_400)).map(Some).map_err(|_| ()),
+                    _ => Err(()),
+                })
+                .collect::<Result<_, ()>>().ok()?;
+            ca.with_name(name).into_date().into_column()
+        },
+        DataType::Datetime(_, _) => {
+            let ca: Int64Chunked = values.iter()
+                .map(|v| match v {
+                    Value::Null => Ok(None),
+                    Value::Text(s) => parse_datetime_us(s).map(Some).ok_or(()),
+                    // 超出微秒可表示范围的整数不当作时间，回退到推断类型
+                    Value::Integer(i) => i.checked_mul(1_000_000).map(Some).ok_or(()),
+                    _ => Err(()),
+                })
+                .collect::<Result<_, ()>>().ok()?;
+            ca.with_name(name).into_datetime(TimeUnit::Microseconds, None).into_column()
+        },
+        _ => {
+            let ca: StringChunked = values.iter().map(value_to_string).collect();
+            ca.with_name(name).into_column()
+        },
+    };
+    Some(col)
+}
+
+fn build_column(name: PlSmallStr, decl: Option<&str>, values: &[Value]) -> Column {
+    if let Some(dtype) = decl.and_then(decl_type_to_dtype)
+        && let Some(col) = try_build_column(name.clone(), &dtype, values)
+    {
+        return col;
+    }
+    // 没有声明类型，或者实际存的值和声明不符 (SQLite 是动态类型)
+    let dtype = infer_dtype(values);
+    try_build_column(name.clone(), &dtype, values)
+        .unwrap_or_else(|| {
+            let ca: StringChunked = values.iter().map(value_to_string).collect();
+            ca.with_name(name).into_column()
+        })
+}
+
+// ==========================================
+// 读取 SQLite 查询结果
+// ==========================================
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_read_sqlite(
+    db_path_ptr: *const c_char,
+    query_ptr: *const c_char
+) -> *mut DataFrameContext {
+    ffi_try!({
+        let db_path = ptr_to_str(db_path_ptr)
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        let query = ptr_to_str(query_ptr)
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+
+        let conn = Connection::open_with_flags(
+            db_path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX
+        ).map_err(sqlite_err)?;
+
+        let mut stmt = conn.prepare(query).map_err(sqlite_err)?;
+
+        let meta: Vec<(String, Option<String>)> = stmt.columns()
+            .iter()
+            .map(|c| (c.name().to_string(), c.decl_type().map(|s| s.to_string())))
+            .collect();
+        let n_cols = meta.len();
+
+        // 按列收集
+        let mut values: Vec<Vec<Value>> = vec![Vec::new(); n_cols];
+        let mut rows = stmt.query([]).map_err(sqlite_err)?;
+        while let Some(row) = rows.next().map_err(sqlite_err)? {
+            for (i, col_values) in values.iter_mut().enumerate() {
+                col_values.push(row.get::<_, Value>(i).map_err(sqlite_err)?);
+            }
+        }
+
+        let columns = meta.iter()
+            .zip(values.iter())
+            .map(|((name, decl), vals)| build_column(name.as_str().into(), decl.as_deref(), vals))
+            .collect();
+
+        let df = DataFrame::new(columns)?;
+        Ok(Box::into_raw(Box::new(DataFrameContext { df })))
+    })
+}
+
+// ==========================================
+// 写入 SQLite 表
+// ==========================================
+fn dtype_to_sql(dtype: &DataType) -> &'static str {
+    match dtype {
+        DataType::Boolean => "BOOLEAN",
+        dt if dt.is_integer() => "INTEGER",
+        dt if dt.is_float() => "REAL",
+        DataType::Binary => "BLOB",
+        DataType::Date => "DATE",
+        DataType::Datetime(_, _) => "TIMESTAMP",
+        DataType::Time => "TIME",
+        _ => "TEXT",
+    }
+}
+
+/// 写入前把列统一成 SQLite 能直接存的形式：
+/// 日期/时间 -> ISO 文本，其它复杂类型 -> 文本
+fn prepare_column(col: &Column) -> PolarsResult<Column> {
+    match col.dtype() {
+        DataType::Boolean | DataType::String | DataType::Binary => Ok(col.clone()),
+        dt if dt.is_integer() || dt.is_float() => Ok(col.clone()),
+        DataType::Datetime(_, _) => {
+            // 去掉时区，按 "%Y-%m-%d %H:%M:%S%.f" 存储
+            let s = col.as_materialized_series().datetime()?
+                .to_string("%Y-%m-%d %H:%M:%S%.f")?;
+            Ok(s.into_column())
+        },
+        _ => col.cast(&DataType::String),
+    }
+}
+
+fn any_value_to_sql(av: AnyValue) -> PolarsResult<Value> {
+    Ok(match av {
+        AnyValue::Null => Value::Null,
+        AnyValue::Boolean(b) => Value::Integer(b as i64),
+        AnyValue::Int8(v) => Value::Integer(v as i64),
+        AnyValue::Int16(v) => Value::Integer(v as i64),
+        AnyValue::Int32(v) => Value::Integer(v as i64),
+        AnyValue::Int64(v) => Value::Integer(v),
+        AnyValue::UInt8(v) => Value::Integer(v as i64),
+        AnyValue::UInt16(v) => Value::Integer(v as i64),
+        AnyValue::UInt32(v) => Value::Integer(v as i64),
+        AnyValue::UInt64(v) => Value::Integer(i64::try_from(v)
+            .map_err(|_| PolarsError::ComputeError(format!("UInt64 value {} does not fit in SQLite INTEGER", v).into()))?),
+        AnyValue::Float32(v) => Value::Real(v as f64),
+        AnyValue::Float64(v) => Value::Real(v),
+        AnyValue::String(s) => Value::Text(s.to_string()),
+        AnyValue::StringOwned(s) => Value::Text(s.to_string()),
+        AnyValue::Binary(b) => Value::Blob(b.to_vec()),
+        AnyValue::BinaryOwned(b) => Value::Blob(b),
+        other => Value::Text(other.to_string()),
+    })
+}
+
+// mode: 0 = 新建 (表已存在则报错), 1 = 追加 (表不存在则新建), 2 = 替换 (先删除旧表)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_write_sqlite(
+    df_ptr: *mut DataFrameContext,
+    db_path_ptr: *const c_char,
+    table_ptr: *const c_char,
+    mode: i32
+) {
+    ffi_try_void!({
+        let ctx = unsafe { &*df_ptr };
+        let db_path = ptr_to_str(db_path_ptr)
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        let table = ptr_to_str(table_ptr)
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+
+        if !(0..=2).contains(&mode) {
+            return Err(PolarsError::ComputeError(format!("Invalid sqlite write mode: {}", mode).into()));
+        }
+
+        let df = &ctx.df;
+        if df.width() == 0 {
+            return Err(PolarsError::ComputeError("Cannot write a DataFrame with no columns to SQLite".into()));
+        }
+        let table_ident = quote_ident(table);
+        let col_defs: Vec<String> = df.get_columns().iter()
+            .map(|c| format!("{} {}", quote_ident(c.name()), dtype_to_sql(c.dtype())))
+            .collect();
+
+        let mut conn = Connection::open(db_path).map_err(sqlite_err)?;
+        let tx = conn.transaction().map_err(sqlite_err)?;
+
+        if mode == 2 {
+            tx.execute(&format!("DROP TABLE IF EXISTS {}", table_ident), []).map_err(sqlite_err)?;
+        }
+        let if_not_exists = if mode == 1 { "IF NOT EXISTS " } else { "" };
+        tx.execute(
+            &format!("CREATE TABLE {}{} ({})", if_not_exists, table_ident, col_defs.join(", ")),
+            []
+        ).map_err(sqlite_err)?;
+
+        let columns = df.get_columns().iter()
+            .map(prepare_column)
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        if !columns.is_empty() {
+            let names: Vec<String> = columns.iter().map(|c| quote_ident(c.name())).collect();
+            let placeholders = vec!["?"; columns.len()].join(", ");
+            let sql = format!("INSERT INTO {} ({}) VALUES ({})", table_ident, names.join(", "), placeholders);
+
+            let mut stmt = tx.prepare(&sql).map_err(sqlite_err)?;
+            let mut row: Vec<Value> = Vec::with_capacity(columns.len());
+            for i in 0..df.height() {
+                row.clear();
+                for col in &columns {
+                    row.push(any_value_to_sql(col.get(i)?)?);
+                }
+                stmt.execute(params_from_iter(row.iter())).map_err(sqlite_err)?;
+            }
+        }
+
+        tx.commit().map_err(sqlite_err)?;
+        Ok(())
+    })
+}
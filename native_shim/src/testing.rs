@@ -0,0 +1,391 @@
+use polars::prelude::*;
+use std::ffi::CString;
+use std::os::raw::c_char;
+use serde_json::{json, Map, Value};
+use crate::types::DataFrameContext;
+use crate::series::SeriesContext;
+
+// ==========================================
+// Frame / Series Equality (测试断言用)
+// ==========================================
+// 比较结果以 JSON 报告返回，而不是单纯的 bool：
+// {"equal": false, "kind": "value", "message": "...", "column": "a", "row": 3,
+//  "left": "1.0", "right": "2.0", "mismatch_count": 1, "dtype_mismatches": [...]}
+
+#[derive(Clone, Copy)]
+struct CompareOptions {
+    check_dtypes: bool,
+    check_exact: bool,
+    rtol: f64,
+    atol: f64,
+}
+
+// 与 numpy.isclose 一致: |a - b| <= atol + rtol * |b|
+fn float_close(a: f64, b: f64, opts: &CompareOptions) -> bool {
+    if a.is_nan() || b.is_nan() {
+        return a.is_nan() && b.is_nan();
+    }
+    if a.is_infinite() || b.is_infinite() {
+        return a == b;
+    }
+    if opts.check_exact {
+        return a == b;
+    }
+    (a - b).abs() <= opts.atol + opts.rtol * b.abs()
+}
+
+fn report(kind: &str, message: String) -> Map<String, Value> {
+    let mut map = Map::new();
+    map.insert("equal".into(), json!(false));
+    map.insert("kind".into(), json!(kind));
+    map.insert("message".into(), json!(message));
+    map
+}
+
+fn equal_report() -> Map<String, Value> {
+    let mut map = Map::new();
+    map.insert("equal".into(), json!(true));
+    map
+}
+
+fn into_c_json(map: Map<String, Value>) -> *mut c_char {
+    let json = Value::Object(map).to_string();
+    CString::new(json).map_or(std::ptr::null_mut(), |s| s.into_raw())
+}
+
+// 浮点数用完整精度显示，避免 "1.0 vs 1.0" 这种看不出差异的报告
+fn fmt_value(v: &AnyValue) -> String {
+    match v {
+        AnyValue::Float64(f) => format!("{f:?}"),
+        AnyValue::Float32(f) => format!("{f:?}"),
+        _ => v.to_string(),
+    }
+}
+
+// 逐行比较两个等长 Series 的值，返回每行是否不同
+// List / Struct 逐元素递归比较，内部的浮点同样使用容差；Array 等其他嵌套类型按精确比较
+fn value_mismatches(left: &Series, right: &Series, opts: &CompareOptions) -> PolarsResult<Vec<bool>> {
+    match (left.dtype(), right.dtype()) {
+        (DataType::List(_), DataType::List(_)) => return list_mismatches(left, right, opts),
+        (DataType::Struct(l), DataType::Struct(r)) if l.len() == r.len() => {
+            return struct_mismatches(left, right, opts);
+        },
+        _ => {},
+    }
+    if left.dtype().is_float() || right.dtype().is_float() {
+        // 另一侧无法无损转成浮点 (例如 String) 时按字符串比较，不能让转换失败的值变成 null
+        if let (Ok(l), Ok(r)) = (left.strict_cast(&DataType::Float64), right.strict_cast(&DataType::Float64)) {
+            let mask = l.f64()?.iter().zip(r.f64()?.iter())
+                .map(|pair| match pair {
+                    (None, None) => false,
+                    (Some(a), Some(b)) => !float_close(a, b, opts),
+                    _ => true,
+                })
+                .collect();
+            return Ok(mask);
+        }
+        return string_mismatches(left, right);
+    }
+    if left.dtype() != right.dtype() {
+        // 不检查 dtype 且无法转换到同一类型 (例如 Int64 vs String)
+        return string_mismatches(left, right);
+    }
+    let eq = left.equal_missing(right)?;
+    Ok(eq.iter().map(|v| v != Some(true)).collect())
+}
+
+// 按字符串形式比较；无法整列转成 String 的类型 (例如 List vs Int64) 逐行格式化
+fn string_mismatches(left: &Series, right: &Series) -> PolarsResult<Vec<bool>> {
+    if let (Ok(l), Ok(r)) = (left.cast(&DataType::String), right.cast(&DataType::String)) {
+        let eq = l.equal_missing(&r)?;
+        return Ok(eq.iter().map(|v| v != Some(true)).collect());
+    }
+    (0..left.len())
+        .map(|i| {
+            let (l, r) = (left.get(i)?, right.get(i)?);
+            Ok(match (l.is_null(), r.is_null()) {
+                (true, true) => false,
+                (false, false) => l.to_string() != r.to_string(),
+                _ => true,
+            })
+        })
+        .collect()
+}
+
+// 每行的子列表: 两侧都为 null 相等，长度不同或任一元素不同即为不同
+fn list_mismatches(left: &Series, right: &Series, opts: &CompareOptions) -> PolarsResult<Vec<bool>> {
+    let (l, r) = (left.list()?, right.list()?);
+    (0..left.len())
+        .map(|i| {
+            Ok(match (l.get_as_series(i), r.get_as_series(i)) {
+                (None, None) => false,
+                (Some(a), Some(b)) => a.len() != b.len() || value_mismatches(&a, &b, opts)?.contains(&true),
+                _ => true,
+            })
+        })
+        .collect()
+}
+
+// 字段按位置逐个比较；两侧都为 null 的行不看字段 (null 行的字段值没有意义)
+fn struct_mismatches(left: &Series, right: &Series, opts: &CompareOptions) -> PolarsResult<Vec<bool>> {
+    let (l, r) = (left.struct_()?, right.struct_()?);
+    let l_null = left.is_null();
+    let r_null = right.is_null();
+    let mut mask: Vec<bool> = l_null.iter().zip(r_null.iter())
+        .map(|(a, b)| a != b)
+        .collect();
+    let both_null: Vec<bool> = l_null.iter().zip(r_null.iter())
+        .map(|(a, b)| a == Some(true) && b == Some(true))
+        .collect();
+    for (lf, rf) in l.fields_as_series().iter().zip(r.fields_as_series().iter()) {
+        for (i, m) in value_mismatches(lf, rf, opts)?.into_iter().enumerate() {
+            if m && !both_null[i] {
+                mask[i] = true;
+            }
+        }
+    }
+    Ok(mask)
+}
+
+// 比较两个 Series 的值 (长度、dtype 已由调用者决定是否检查)
+// 返回 None 表示相等
+fn compare_values(
+    left: &Series,
+    right: &Series,
+    opts: &CompareOptions,
+) -> PolarsResult<Option<Map<String, Value>>> {
+    // 不检查 dtype 时，尝试把右侧转换到左侧类型再比
+    let cast_right = if left.dtype() != right.dtype() && !opts.check_dtypes {
+        match right.strict_cast(left.dtype()) {
+            Ok(s) => s,
+            Err(_) => right.clone(),
+        }
+    } else {
+        right.clone()
+    };
+
+    let mask = value_mismatches(left, &cast_right, opts)?;
+    let Some(row) = mask.iter().position(|&m| m) else {
+        return Ok(None);
+    };
+    let count = mask.iter().filter(|&&m| m).count();
+
+    // 报告中显示转换前的原始值
+    let l_val = fmt_value(&left.get(row)?);
+    let r_val = fmt_value(&right.get(row)?);
+    let mut map = report(
+        "value",
+        format!(
+            "values differ in column \"{}\" at row {}: left={}, right={} ({} mismatching row(s))",
+            left.name(), row, l_val, r_val, count
+        ),
+    );
+    map.insert("column".into(), json!(left.name().as_str()));
+    map.insert("row".into(), json!(row));
+    map.insert("left".into(), json!(l_val));
+    map.insert("right".into(), json!(r_val));
+    map.insert("mismatch_count".into(), json!(count));
+    Ok(Some(map))
+}
+
+fn sort_all(df: &DataFrame) -> PolarsResult<DataFrame> {
+    let names: Vec<PlSmallStr> = df.get_column_names_owned();
+    df.sort(names, SortMultipleOptions::default().with_maintain_order(true))
+}
+
+fn compare_frames(
+    left: &DataFrame,
+    right: &DataFrame,
+    check_column_order: bool,
+    check_row_order: bool,
+    opts: CompareOptions,
+) -> PolarsResult<Map<String, Value>> {
+    // 1. 列集合
+    let l_names = left.get_column_names_owned();
+    let r_names = right.get_column_names_owned();
+    let missing_right: Vec<&str> = l_names.iter()
+        .filter(|n| !r_names.contains(n))
+        .map(|n| n.as_str())
+        .collect();
+    let missing_left: Vec<&str> = r_names.iter()
+        .filter(|n| !l_names.contains(n))
+        .map(|n| n.as_str())
+        .collect();
+    if !missing_right.is_empty() || !missing_left.is_empty() {
+        let mut map = report(
+            "columns",
+            format!("column names differ: only in left {:?}, only in right {:?}", missing_right, missing_left),
+        );
+        map.insert("only_in_left".into(), json!(missing_right));
+        map.insert("only_in_right".into(), json!(missing_left));
+        return Ok(map);
+    }
+
+    // 2. 列顺序
+    if check_column_order && l_names != r_names {
+        let mut map = report(
+            "column_order",
+            format!("column order differs: left {:?}, right {:?}", l_names, r_names),
+        );
+        map.insert("left".into(), json!(l_names.iter().map(|n| n.as_str()).collect::<Vec<_>>()));
+        map.insert("right".into(), json!(r_names.iter().map(|n| n.as_str()).collect::<Vec<_>>()));
+        return Ok(map);
+    }
+    let right = right.select(l_names.clone())?;
+
+    // 3. dtype (收集全部差异)
+    if opts.check_dtypes {
+        let diffs: Vec<Value> = left.get_columns().iter().zip(right.get_columns())
+            .filter(|(l, r)| l.dtype() != r.dtype())
+            .map(|(l, r)| json!({
+                "column": l.name().as_str(),
+                "left": l.dtype().to_string(),
+                "right": r.dtype().to_string(),
+            }))
+            .collect();
+        if let Some(first) = diffs.first() {
+            let mut map = report(
+                "dtype",
+                format!(
+                    "dtypes differ in column \"{}\": left={}, right={} ({} column(s) differ)",
+                    first["column"].as_str().unwrap_or_default(),
+                    first["left"].as_str().unwrap_or_default(),
+                    first["right"].as_str().unwrap_or_default(),
+                    diffs.len()
+                ),
+            );
+            map.insert("column".into(), first["column"].clone());
+            map.insert("left_dtype".into(), first["left"].clone());
+            map.insert("right_dtype".into(), first["right"].clone());
+            map.insert("dtype_mismatches".into(), Value::Array(diffs));
+            return Ok(map);
+        }
+    }
+
+    // 4. 行数
+    if left.height() != right.height() {
+        let mut map = report(
+            "shape",
+            format!("height differs: left={}, right={}", left.height(), right.height()),
+        );
+        map.insert("left".into(), json!(left.height()));
+        map.insert("right".into(), json!(right.height()));
+        return Ok(map);
+    }
+
+    // 5. 值 (不检查行顺序时，两侧都按全部列排序)
+    let (left, right) = if check_row_order {
+        (left.clone(), right)
+    } else {
+        // 先统一 dtype，否则排序结果不可比
+        let right = if opts.check_dtypes {
+            right
+        } else {
+            let cols = right.get_columns().iter().zip(left.get_columns())
+                .map(|(r, l)| r.strict_cast(l.dtype()).unwrap_or_else(|_| r.clone()))
+                .collect::<Vec<_>>();
+            DataFrame::new(cols)?
+        };
+        (sort_all(left)?, sort_all(&right)?)
+    };
+    for (l, r) in left.get_columns().iter().zip(right.get_columns()) {
+        if let Some(map) = compare_values(l.as_materialized_series(), r.as_materialized_series(), &opts)? {
+            return Ok(map);
+        }
+    }
+    Ok(equal_report())
+}
+
+fn compare_series(
+    left: &Series,
+    right: &Series,
+    check_names: bool,
+    check_order: bool,
+    opts: CompareOptions,
+) -> PolarsResult<Map<String, Value>> {
+    if check_names && left.name() != right.name() {
+        let mut map = report(
+            "name",
+            format!("names differ: left=\"{}\", right=\"{}\"", left.name(), right.name()),
+        );
+        map.insert("left".into(), json!(left.name().as_str()));
+        map.insert("right".into(), json!(right.name().as_str()));
+        return Ok(map);
+    }
+    if opts.check_dtypes && left.dtype() != right.dtype() {
+        let mut map = report(
+            "dtype",
+            format!("dtypes differ: left={}, right={}", left.dtype(), right.dtype()),
+        );
+        map.insert("column".into(), json!(left.name().as_str()));
+        map.insert("left_dtype".into(), json!(left.dtype().to_string()));
+        map.insert("right_dtype".into(), json!(right.dtype().to_string()));
+        return Ok(map);
+    }
+    if left.len() != right.len() {
+        let mut map = report(
+            "shape",
+            format!("length differs: left={}, right={}", left.len(), right.len()),
+        );
+        map.insert("left".into(), json!(left.len()));
+        map.insert("right".into(), json!(right.len()));
+        return Ok(map);
+    }
+
+    let (left, right) = if check_order {
+        (left.clone(), right.clone())
+    } else {
+        let right = if opts.check_dtypes {
+            right.clone()
+        } else {
+            right.strict_cast(left.dtype()).unwrap_or_else(|_| right.clone())
+        };
+        let sort_opts = SortOptions::default().with_maintain_order(true);
+        (left.sort(sort_opts)?, right.sort(sort_opts)?)
+    };
+    // 名字不参与值比较
+    let right = right.with_name(left.name().clone());
+    Ok(compare_values(&left, &right, &opts)?.unwrap_or_else(equal_report))
+}
+
+/// 比较两个 DataFrame，返回 JSON 报告 (需 pl_free_string 释放)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_dataframe_equals(
+    left_ptr: *mut DataFrameContext,
+    right_ptr: *mut DataFrameContext,
+    check_dtypes: bool,
+    check_column_order: bool,
+    check_row_order: bool,
+    check_exact: bool,
+    rtol: f64,
+    atol: f64,
+) -> *mut c_char {
+    ffi_try!({
+        let left = unsafe { &(*left_ptr).df };
+        let right = unsafe { &(*right_ptr).df };
+        let opts = CompareOptions { check_dtypes, check_exact, rtol, atol };
+        let map = compare_frames(left, right, check_column_order, check_row_order, opts)?;
+        Ok(into_c_json(map))
+    })
+}
+
+/// 比较两个 Series，返回 JSON 报告 (需 pl_free_string 释放)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_series_equals(
+    left_ptr: *mut SeriesContext,
+    right_ptr: *mut SeriesContext,
+    check_dtypes: bool,
+    check_names: bool,
+    check_order: bool,
+    check_exact: bool,
+    rtol: f64,
+    atol: f64,
+) -> *mut c_char {
+    ffi_try!({
+        let left = unsafe { &(*left_ptr).series };
+        let right = unsafe { &(*right_ptr).series };
+        let opts = CompareOptions { check_dtypes, check_exact, rtol, atol };
+        let map = compare_series(left, right, check_names, check_order, opts)?;
+        Ok(into_c_json(map))
+    })
+}
@@ -1,5 +1,5 @@
 use polars::prelude::*;
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 // ==========================================
 // 1. 定义“不透明”容器
@@ -36,6 +36,97 @@ pub(crate) unsafe fn consume_exprs_array(
         .collect()
 }
 
+/// 多列排序选项
+/// descending 长度为 0 表示全部升序，长度为 1 表示所有列共用同一方向，否则需要和排序列一一对应
+pub(crate) unsafe fn build_sort_options(
+    descending_ptr: *const bool,
+    descending_len: usize,
+    nulls_last: bool,
+    maintain_order: bool,
+    multithreaded: bool
+) -> SortMultipleOptions {
+    let descending = if descending_ptr.is_null() || descending_len == 0 {
+        vec![false]
+    } else {
+        unsafe { std::slice::from_raw_parts(descending_ptr, descending_len) }.to_vec()
+    };
+
+    SortMultipleOptions::default()
+        .with_order_descending_multi(descending)
+        .with_nulls_last(nulls_last)
+        .with_maintain_order(maintain_order)
+        .with_multithreaded(multithreaded)
+}
+
+/// 将 C 字符串数组转换为列名列表
+pub(crate) unsafe fn ptr_to_names(
+    ptr: *const *const c_char,
+    len: usize
+) -> PolarsResult<Vec<PlSmallStr>> {
+    if ptr.is_null() || len == 0 {
+        return Ok(Vec::new());
+    }
+    let slice = unsafe { std::slice::from_raw_parts(ptr, len) };
+    slice.iter()
+        .map(|&p| ptr_to_str(p)
+            .map(PlSmallStr::from_str)
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into())))
+        .collect()
+}
+
+/// unique 的保留策略: 0 = first, 1 = last, 2 = any, 3 = none (丢弃所有重复行)
+pub(crate) fn map_unique_keep(code: i32) -> PolarsResult<UniqueKeepStrategy> {
+    match code {
+        0 => Ok(UniqueKeepStrategy::First),
+        1 => Ok(UniqueKeepStrategy::Last),
+        2 => Ok(UniqueKeepStrategy::Any),
+        3 => Ok(UniqueKeepStrategy::None),
+        _ => Err(PolarsError::ComputeError(format!("Unknown unique keep strategy: {}", code).into())),
+    }
+}
+
+/// fill_null 策略: 0 = forward, 1 = backward, 2 = min, 3 = max, 4 = mean, 5 = zero, 6 = one
+/// limit: 只对 forward/backward 生效，0 表示不限制
+pub(crate) fn map_fill_null_strategy(code: i32, limit: u32) -> PolarsResult<FillNullStrategy> {
+    let limit_opt = if limit == 0 { None } else { Some(limit) };
+    match code {
+        0 => Ok(FillNullStrategy::Forward(limit_opt)),
+        1 => Ok(FillNullStrategy::Backward(limit_opt)),
+        2 => Ok(FillNullStrategy::Min),
+        3 => Ok(FillNullStrategy::Max),
+        4 => Ok(FillNullStrategy::Mean),
+        5 => Ok(FillNullStrategy::Zero),
+        6 => Ok(FillNullStrategy::One),
+        _ => Err(PolarsError::ComputeError(format!("Unknown fill_null strategy: {}", code).into())),
+    }
+}
+
+/// interpolate 方法: 0 = linear, 1 = nearest
+pub(crate) fn map_interpolation_method(code: i32) -> PolarsResult<InterpolationMethod> {
+    match code {
+        0 => Ok(InterpolationMethod::Linear),
+        1 => Ok(InterpolationMethod::Nearest),
+        _ => Err(PolarsError::ComputeError(format!("Unknown interpolation method: {}", code).into())),
+    }
+}
+
+/// pivot 的内置聚合，作用于 pivot 元素 col("")
+/// 0 = first, 1 = sum, 2 = min, 3 = max, 4 = mean, 5 = median, 6 = count (非空), 7 = len, 8 = last
+pub(crate) fn map_pivot_agg(code: i32) -> Expr {
+    let el = col("");
+    match code {
+        1 => el.sum(),
+        2 => el.min(),
+        3 => el.max(),
+        4 => el.mean(),
+        5 => el.median(),
+        6 => el.count(),
+        7 => el.len(),
+        8 => el.last(),
+        _ => el.first(), // 默认 first
+    }
+}
+
 pub(crate) fn map_jointype(code: i32) -> JoinType {
     match code {
         0 => JoinType::Inner,
@@ -46,4 +137,115 @@ pub(crate) fn map_jointype(code: i32) -> JoinType {
         5 => JoinType::Anti,
         _ => JoinType::Inner, // 默认
     }
-}
\ No newline at end of file
+}
+
+/// Join 的附加选项
+/// suffix_ptr: 可空，为空时使用默认的 "_right"
+/// validate: 0 = m:m (不检查), 1 = 1:1, 2 = 1:m, 3 = m:1
+/// coalesce: 0 = 按 Join 类型决定, 1 = 合并键列, 2 = 保留两侧键列
+/// maintain_order: 0 = 不保证, 1 = left, 2 = right, 3 = left_right, 4 = right_left
+pub(crate) fn build_join_args(
+    how_code: i32,
+    suffix_ptr: *const c_char,
+    validate: i32,
+    coalesce: i32,
+    nulls_equal: bool,
+    maintain_order: i32
+) -> PolarsResult<JoinArgs> {
+    let suffix = if suffix_ptr.is_null() {
+        None
+    } else {
+        let s = ptr_to_str(suffix_ptr)
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        if s.is_empty() { None } else { Some(PlSmallStr::from_str(s)) }
+    };
+
+    let validation = match validate {
+        0 => JoinValidation::ManyToMany,
+        1 => JoinValidation::OneToOne,
+        2 => JoinValidation::OneToMany,
+        3 => JoinValidation::ManyToOne,
+        _ => return Err(PolarsError::ComputeError(format!("Invalid join validation: {}", validate).into())),
+    };
+
+    let coalesce = match coalesce {
+        0 => JoinCoalesce::JoinSpecific,
+        1 => JoinCoalesce::CoalesceColumns,
+        2 => JoinCoalesce::KeepColumns,
+        _ => return Err(PolarsError::ComputeError(format!("Invalid join coalesce mode: {}", coalesce).into())),
+    };
+
+    let maintain_order = match maintain_order {
+        0 => MaintainOrderJoin::None,
+        1 => MaintainOrderJoin::Left,
+        2 => MaintainOrderJoin::Right,
+        3 => MaintainOrderJoin::LeftRight,
+        4 => MaintainOrderJoin::RightLeft,
+        _ => return Err(PolarsError::ComputeError(format!("Invalid join maintain_order: {}", maintain_order).into())),
+    };
+
+    let mut args = JoinArgs::new(map_jointype(how_code))
+        .with_suffix(suffix)
+        .with_coalesce(coalesce);
+    args.validation = validation;
+    args.nulls_equal = nulls_equal;
+    args.maintain_order = maintain_order;
+    Ok(args)
+}
+
+// ==========================================
+// 标量 getter 共用 (DataFrame / Series)
+// ==========================================
+
+/// 浮点直接返回，整数 / Decimal 转为 f64；其他类型 (含 null) 返回 None
+pub(crate) fn any_value_to_f64(v: &AnyValue) -> Option<f64> {
+    match *v {
+        AnyValue::Float64(v) => Some(v),
+        AnyValue::Float32(v) => Some(v as f64),
+        AnyValue::Int64(v) => Some(v as f64),
+        AnyValue::Int32(v) => Some(v as f64),
+        AnyValue::Int16(v) => Some(v as f64),
+        AnyValue::Int8(v) => Some(v as f64),
+        AnyValue::UInt64(v) => Some(v as f64),
+        AnyValue::UInt32(v) => Some(v as f64),
+        AnyValue::UInt16(v) => Some(v as f64),
+        AnyValue::UInt8(v) => Some(v as f64),
+        AnyValue::Decimal(v, scale) => Some(v as f64 / 10f64.powi(scale as i32)),
+        _ => None,
+    }
+}
+
+// unit 编码: 0 = ns, 1 = us, 2 = ms
+pub(crate) fn time_unit_code(tu: TimeUnit) -> i32 {
+    match tu {
+        TimeUnit::Nanoseconds => 0,
+        TimeUnit::Microseconds => 1,
+        TimeUnit::Milliseconds => 2,
+    }
+}
+
+/// Datetime 写入 out 参数，不是 Datetime (含 null) 时返回 false 且不写入
+/// out_val: 物理值 (按 out_unit 计的 epoch 偏移，带时区时为 UTC)
+/// out_tz: 时区名，没有时区时写入 null，否则需要用 pl_free_string 释放
+pub(crate) unsafe fn write_datetime(
+    v: AnyValue,
+    out_val: *mut i64,
+    out_unit: *mut i32,
+    out_tz: *mut *mut c_char
+) -> bool {
+    let (v, tu, tz) = match v {
+        AnyValue::Datetime(v, tu, tz) => (v, tu, tz.map(|t| t.to_string())),
+        AnyValue::DatetimeOwned(v, tu, tz) => (v, tu, tz.map(|t| t.to_string())),
+        _ => return false,
+    };
+    let tz_ptr = match tz {
+        Some(tz) => CString::new(tz).map_or(std::ptr::null_mut(), |s| s.into_raw()),
+        None => std::ptr::null_mut(),
+    };
+    unsafe {
+        *out_val = v;
+        *out_unit = time_unit_code(tu);
+        *out_tz = tz_ptr;
+    }
+    true
+}
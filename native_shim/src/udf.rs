@@ -8,7 +8,7 @@ use std::ffi::{CStr,c_void};
 
 // 1. 定义清理回调的签名
 // 参数: user_data (这里我们将传入 C# GCHandle 的 IntPtr)
-type CleanupCallback = extern "C" fn(*mut c_void);
+pub(crate) type CleanupCallback = extern "C" fn(*mut c_void);
 
 // 当 Polars 执行完查询，销毁表达式树时，会自动调用 drop
 impl Drop for CSharpUdf {
@@ -70,4 +70,14 @@ pub extern "C" fn pl_free_c_string(c_str: *mut std::os::raw::c_char) {
             let _ = std::ffi::CString::from_raw(c_str);
         }
     }
-}
\ No newline at end of file
+}
+
+// 释放 Rust 端返回的字节数组 (Box<[u8]>)
+#[unsafe(no_mangle)]
+pub extern "C" fn pl_free_bytes(ptr: *mut u8, len: usize) {
+    if !ptr.is_null() {
+        unsafe {
+            let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr, len));
+        }
+    }
+}